@@ -0,0 +1,330 @@
+/* Generated by cbindgen from the capi crate. Do not edit by hand. */
+
+#ifndef CAPI_H
+#define CAPI_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The tag length of `xoodyak_aead_encrypt` and `xoodyak_aead_decrypt`.
+ */
+#define XOODYAK_TAG_LEN 16
+
+/**
+ * The status returned by the functions of this library, as in XKCP.
+ */
+typedef enum HashReturn {
+  HASH_RETURN_SUCCESS = 0,
+  HASH_RETURN_FAIL = 1,
+  HASH_RETURN_BAD_HASH_LEN = 2,
+} HashReturn;
+
+/**
+ * A KangarooTwelve (KT128) hash.
+ */
+typedef struct KangarooTwelve KangarooTwelve;
+
+/**
+ * A SHA3, SHAKE or TurboSHAKE hash.
+ */
+typedef struct KeccakHash KeccakHash;
+
+/**
+ * A Xoodyak instance in keyed mode.
+ */
+typedef struct Xoodyak Xoodyak;
+
+/**
+ * A Xoodyak hash.
+ */
+typedef struct XoodyakHash XoodyakHash;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a KangarooTwelve hash.
+ */
+struct KangarooTwelve *kangaroo_twelve_new(void);
+
+/**
+ * Absorbs `len` bytes from `data`.
+ *
+ * # Safety
+ *
+ * `k12` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+ */
+enum HashReturn kangaroo_twelve_update(struct KangarooTwelve *k12, const uint8_t *data, size_t len);
+
+/**
+ * Ends the input with the customization string of `customization_len` bytes, which may be empty,
+ * and writes the first `len` bytes of output to `out`. The output continues with
+ * `kangaroo_twelve_squeeze`.
+ *
+ * # Safety
+ *
+ * `k12` must be null or a live handle, `customization` must be valid for reads of
+ * `customization_len` bytes and `out` for writes of `len` bytes.
+ */
+enum HashReturn kangaroo_twelve_final(struct KangarooTwelve *k12,
+                                      const uint8_t *customization,
+                                      size_t customization_len,
+                                      uint8_t *out,
+                                      size_t len);
+
+/**
+ * Writes the next `len` bytes of output of a finalized hash to `out`.
+ *
+ * # Safety
+ *
+ * `k12` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn kangaroo_twelve_squeeze(struct KangarooTwelve *k12, uint8_t *out, size_t len);
+
+/**
+ * Releases a handle, which may be null.
+ */
+void kangaroo_twelve_free(struct KangarooTwelve *k12);
+
+/**
+ * Creates a SHA3-224 hash.
+ */
+struct KeccakHash *keccak_hash_new_sha3_224(void);
+
+/**
+ * Creates a SHA3-256 hash.
+ */
+struct KeccakHash *keccak_hash_new_sha3_256(void);
+
+/**
+ * Creates a SHA3-384 hash.
+ */
+struct KeccakHash *keccak_hash_new_sha3_384(void);
+
+/**
+ * Creates a SHA3-512 hash.
+ */
+struct KeccakHash *keccak_hash_new_sha3_512(void);
+
+/**
+ * Creates a SHAKE128 hash.
+ */
+struct KeccakHash *keccak_hash_new_shake128(void);
+
+/**
+ * Creates a SHAKE256 hash.
+ */
+struct KeccakHash *keccak_hash_new_shake256(void);
+
+/**
+ * Creates a TurboSHAKE128 hash with the domain separation byte `domain`, or returns null if it is
+ * not in 0x01..=0x7F. The default domain is 0x1F.
+ */
+struct KeccakHash *keccak_hash_new_turboshake128(uint8_t domain);
+
+/**
+ * Creates a TurboSHAKE256 hash with the domain separation byte `domain`, or returns null if it is
+ * not in 0x01..=0x7F. The default domain is 0x1F.
+ */
+struct KeccakHash *keccak_hash_new_turboshake256(uint8_t domain);
+
+/**
+ * Absorbs `len` bytes from `data`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+ */
+enum HashReturn keccak_hash_update(struct KeccakHash *hash, const uint8_t *data, size_t len);
+
+/**
+ * Ends the input and writes the first `len` bytes of output to `out`. For SHA3, `len` must be the
+ * digest length, and for XOFs, the output continues with `keccak_hash_squeeze`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn keccak_hash_final(struct KeccakHash *hash, uint8_t *out, size_t len);
+
+/**
+ * Writes the next `len` bytes of output of a finalized XOF to `out`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn keccak_hash_squeeze(struct KeccakHash *hash, uint8_t *out, size_t len);
+
+/**
+ * Releases a handle, which may be null.
+ */
+void keccak_hash_free(struct KeccakHash *hash);
+
+/**
+ * Creates a Xoodyak hash.
+ */
+struct XoodyakHash *xoodyak_hash_new(void);
+
+/**
+ * Absorbs `len` bytes from `data`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+ */
+enum HashReturn xoodyak_hash_update(struct XoodyakHash *hash, const uint8_t *data, size_t len);
+
+/**
+ * Ends the input and writes the first `len` bytes of output to `out`, 32 for the standard digest.
+ * The output continues with `xoodyak_hash_squeeze`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_hash_final(struct XoodyakHash *hash, uint8_t *out, size_t len);
+
+/**
+ * Writes the next `len` bytes of output of a finalized hash to `out`.
+ *
+ * # Safety
+ *
+ * `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_hash_squeeze(struct XoodyakHash *hash, uint8_t *out, size_t len);
+
+/**
+ * Releases a handle, which may be null.
+ */
+void xoodyak_hash_free(struct XoodyakHash *hash);
+
+/**
+ * Creates a keyed Xoodyak instance from a key, an ID and a counter, the last two of which may be
+ * empty, or returns null if `key_len + id_len` is not below 44.
+ *
+ * # Safety
+ *
+ * Each of `key`, `id` and `counter` must be valid for reads of its length.
+ */
+struct Xoodyak *xoodyak_new(const uint8_t *key,
+                            size_t key_len,
+                            const uint8_t *id,
+                            size_t id_len,
+                            const uint8_t *counter,
+                            size_t counter_len);
+
+/**
+ * Absorbs `len` bytes from `data`.
+ *
+ * # Safety
+ *
+ * `xoodyak` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+ */
+enum HashReturn xoodyak_absorb(struct Xoodyak *xoodyak, const uint8_t *data, size_t len);
+
+/**
+ * Encrypts `len` bytes from `plaintext` into `ciphertext`, which may be the same buffer.
+ *
+ * # Safety
+ *
+ * `xoodyak` must be null or a live handle, `plaintext` must be valid for reads and `ciphertext`
+ * for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_encrypt(struct Xoodyak *xoodyak,
+                                const uint8_t *plaintext,
+                                uint8_t *ciphertext,
+                                size_t len);
+
+/**
+ * Decrypts `len` bytes from `ciphertext` into `plaintext`, which may be the same buffer.
+ *
+ * # Safety
+ *
+ * `xoodyak` must be null or a live handle, `ciphertext` must be valid for reads and `plaintext`
+ * for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_decrypt(struct Xoodyak *xoodyak,
+                                const uint8_t *ciphertext,
+                                uint8_t *plaintext,
+                                size_t len);
+
+/**
+ * Writes `len` bytes of output, such as a tag, to `out`. `len` must not be 0.
+ *
+ * # Safety
+ *
+ * `xoodyak` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_squeeze(struct Xoodyak *xoodyak, uint8_t *out, size_t len);
+
+/**
+ * Writes `len` bytes of key material to `out`. `len` must not be 0.
+ *
+ * # Safety
+ *
+ * `xoodyak` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+ */
+enum HashReturn xoodyak_squeeze_key(struct Xoodyak *xoodyak, uint8_t *out, size_t len);
+
+/**
+ * Overwrites part of the state so that it cannot be rolled back.
+ */
+enum HashReturn xoodyak_ratchet(struct Xoodyak *xoodyak);
+
+/**
+ * Releases a handle, which may be null.
+ */
+void xoodyak_free(struct Xoodyak *xoodyak);
+
+/**
+ * Encrypts `len` bytes from `plaintext` into `ciphertext`, which may be the same buffer, and
+ * writes the `XOODYAK_TAG_LEN`-byte tag authenticating them and `ad` to `tag`, as the Xoodyak
+ * submission to the NIST lightweight cryptography project does with a 16-byte key and nonce.
+ * Fails if `key_len + nonce_len` is not below 44.
+ *
+ * # Safety
+ *
+ * Each input must be valid for reads and each output for writes of its length.
+ */
+enum HashReturn xoodyak_aead_encrypt(const uint8_t *key,
+                                     size_t key_len,
+                                     const uint8_t *nonce,
+                                     size_t nonce_len,
+                                     const uint8_t *ad,
+                                     size_t ad_len,
+                                     const uint8_t *plaintext,
+                                     uint8_t *ciphertext,
+                                     size_t len,
+                                     uint8_t *tag);
+
+/**
+ * Decrypts `len` bytes from `ciphertext` into `plaintext`, which may be the same buffer, and
+ * verifies them and `ad` against the `XOODYAK_TAG_LEN`-byte `tag`. On failure, `plaintext` is
+ * zeroed.
+ *
+ * # Safety
+ *
+ * Each input must be valid for reads and each output for writes of its length.
+ */
+enum HashReturn xoodyak_aead_decrypt(const uint8_t *key,
+                                     size_t key_len,
+                                     const uint8_t *nonce,
+                                     size_t nonce_len,
+                                     const uint8_t *ad,
+                                     size_t ad_len,
+                                     const uint8_t *ciphertext,
+                                     uint8_t *plaintext,
+                                     size_t len,
+                                     const uint8_t *tag);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CAPI_H */
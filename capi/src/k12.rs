@@ -0,0 +1,103 @@
+use core::fmt::{self, Debug, Formatter};
+
+use keccak::{k12::Kt128, keccakp::Keccak1600};
+use sponge::{sponge::Squeezer, state::Lsbu64};
+
+use crate::{input, into_handle, output, HashReturn, Phase};
+
+/// A KangarooTwelve (KT128) hash.
+pub struct KangarooTwelve(
+	Phase<Kt128<Lsbu64<25>>, Squeezer<Lsbu64<25>, Keccak1600<12>, { 128 * 2 / 8 }>>
+);
+
+impl Debug for KangarooTwelve {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("KangarooTwelve").finish_non_exhaustive()
+	}
+}
+
+/// Creates a KangarooTwelve hash.
+#[no_mangle]
+pub extern "C" fn kangaroo_twelve_new() -> *mut KangarooTwelve {
+	into_handle(KangarooTwelve(Phase::Absorbing(Kt128::default())))
+}
+
+/// Absorbs `len` bytes from `data`.
+///
+/// # Safety
+///
+/// `k12` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kangaroo_twelve_update(
+	k12: Option<&mut KangarooTwelve>,
+	data: *const u8,
+	len: usize
+) -> HashReturn {
+	let Some(hash) = k12.and_then(|k12| k12.0.absorbing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	hash.absorb(unsafe { input(data, len) });
+	HashReturn::Success
+}
+
+/// Ends the input with the customization string of `customization_len` bytes, which may be empty,
+/// and writes the first `len` bytes of output to `out`. The output continues with
+/// `kangaroo_twelve_squeeze`.
+///
+/// # Safety
+///
+/// `k12` must be null or a live handle, `customization` must be valid for reads of
+/// `customization_len` bytes and `out` for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kangaroo_twelve_final(
+	k12: Option<&mut KangarooTwelve>,
+	customization: *const u8,
+	customization_len: usize,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(k12) = k12 else {
+		return HashReturn::Fail;
+	};
+
+	let Some(hash) = k12.0.take_absorbing() else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	let customization = unsafe { input(customization, customization_len) };
+	let mut squeezer = hash.into_squeezer_customized(customization);
+
+	// SAFETY: guaranteed by the caller.
+	squeezer.squeeze_into(unsafe { output(out, len) });
+	k12.0 = Phase::Squeezing(squeezer);
+	HashReturn::Success
+}
+
+/// Writes the next `len` bytes of output of a finalized hash to `out`.
+///
+/// # Safety
+///
+/// `k12` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn kangaroo_twelve_squeeze(
+	k12: Option<&mut KangarooTwelve>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(squeezer) = k12.and_then(|k12| k12.0.squeezing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	squeezer.squeeze_into(unsafe { output(out, len) });
+	HashReturn::Success
+}
+
+/// Releases a handle, which may be null.
+#[no_mangle]
+pub extern "C" fn kangaroo_twelve_free(k12: Option<Box<KangarooTwelve>>) {
+	drop(k12);
+}
@@ -0,0 +1,125 @@
+#![warn(
+	deprecated_in_future,
+	future_incompatible,
+	macro_use_extern_crate,
+	meta_variable_misuse,
+	missing_copy_implementations,
+	missing_debug_implementations,
+	noop_method_call,
+	rust_2018_compatibility,
+	rust_2018_idioms,
+	rust_2021_compatibility,
+	single_use_lifetimes,
+	trivial_casts,
+	trivial_numeric_casts,
+	unreachable_pub,
+	unsafe_op_in_unsafe_fn,
+	unused_crate_dependencies,
+	unused_lifetimes,
+	unused_qualifications,
+	unused_results
+)]
+
+//! C bindings for the hashes in `keccak` and `xoodoo`, in the init/update/final style of XKCP.
+//!
+//! Every algorithm family has an opaque handle, created by a `*_new` function and released by
+//! the matching `*_free`. A hash absorbs input with `*_update` until `*_final`, after which the
+//! extendable-output ones can keep producing output with `*_squeeze`. Functions taking a handle
+//! return [`HashReturn::Fail`] when it is null or in the wrong phase, and buffers may be null when
+//! their length is 0. The header in `include/capi.h` is generated by cbindgen.
+
+mod k12;
+mod sha3;
+mod xoodyak;
+
+use core::{ptr, slice};
+
+pub use crate::{k12::*, sha3::*, xoodyak::*};
+
+/// The status returned by the functions of this library, as in XKCP.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HashReturn {
+	Success = 0,
+	Fail = 1,
+	BadHashLen = 2
+}
+
+/// A hash that absorbs until it is finalized, and then squeezes if it is an XOF.
+enum Phase<A, S> {
+	Absorbing(A),
+	Squeezing(S),
+	Finalized
+}
+
+impl<A, S> Phase<A, S> {
+	fn absorbing(&mut self) -> Option<&mut A> {
+		match self {
+			Self::Absorbing(hash) => Some(hash),
+			_ => None
+		}
+	}
+
+	fn squeezing(&mut self) -> Option<&mut S> {
+		match self {
+			Self::Squeezing(squeezer) => Some(squeezer),
+			_ => None
+		}
+	}
+
+	/// Takes the hash out of the absorbing phase, leaving the handle finalized.
+	fn take_absorbing(&mut self) -> Option<A> {
+		match core::mem::replace(self, Self::Finalized) {
+			Self::Absorbing(hash) => Some(hash),
+			phase => {
+				*self = phase;
+				None
+			}
+		}
+	}
+}
+
+/// Moves `value` to the heap and hands it out as a handle.
+fn into_handle<T>(value: T) -> *mut T {
+	Box::into_raw(Box::new(value))
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for reads of `len` bytes unless `len` is 0.
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+	if len == 0 {
+		&[]
+	} else {
+		// SAFETY: the caller guarantees that `ptr` is valid for `len` bytes.
+		unsafe { slice::from_raw_parts(ptr, len) }
+	}
+}
+
+/// # Safety
+///
+/// `ptr` must be valid for writes of `len` bytes unless `len` is 0.
+unsafe fn output<'a>(ptr: *mut u8, len: usize) -> &'a mut [u8] {
+	if len == 0 {
+		&mut []
+	} else {
+		// SAFETY: the caller guarantees that `ptr` is valid for `len` bytes.
+		unsafe { slice::from_raw_parts_mut(ptr, len) }
+	}
+}
+
+/// Copies `len` bytes from `src` to `dst`, which may overlap, and returns `dst` as a slice so
+/// that it can be processed in place.
+///
+/// # Safety
+///
+/// `src` must be valid for reads and `dst` for writes of `len` bytes unless `len` is 0.
+unsafe fn copy_to_output<'a>(src: *const u8, dst: *mut u8, len: usize) -> &'a mut [u8] {
+	if len != 0 && src != dst.cast_const() {
+		// SAFETY: the caller guarantees that both pointers are valid for `len` bytes.
+		unsafe { ptr::copy(src, dst, len) };
+	}
+
+	// SAFETY: as above.
+	unsafe { output(dst, len) }
+}
@@ -0,0 +1,197 @@
+use core::{
+	fmt::{self, Debug, Formatter},
+	ptr
+};
+
+use keccak::sha3::{TurboShake128DynDomain, TurboShake256DynDomain};
+use registry::{Algorithm, DynHasher, DynXof, DynXofReader};
+use sponge::state::Lsbu64;
+
+use crate::{input, into_handle, output, HashReturn, Phase};
+
+/// A SHA3, SHAKE or TurboSHAKE hash.
+pub struct KeccakHash(Phase<Absorber, Box<dyn DynXofReader>>);
+
+enum Absorber {
+	Fixed(Box<dyn DynHasher>),
+	Xof(Box<dyn DynXof>),
+	TurboShake128(TurboShake128DynDomain<Lsbu64<25>>),
+	TurboShake256(TurboShake256DynDomain<Lsbu64<25>>)
+}
+
+impl KeccakHash {
+	fn fixed(algorithm: Algorithm) -> *mut Self {
+		into_handle(Self(Phase::Absorbing(Absorber::Fixed(algorithm.hasher()))))
+	}
+
+	fn xof(algorithm: Algorithm) -> *mut Self {
+		let xof = algorithm.xof().expect("algorithm is an XOF");
+		into_handle(Self(Phase::Absorbing(Absorber::Xof(xof))))
+	}
+
+	fn turbo_shake(domain: u8, absorber: fn(u8) -> Absorber) -> *mut Self {
+		if !(0x01..=0x7F).contains(&domain) {
+			return ptr::null_mut();
+		}
+
+		into_handle(Self(Phase::Absorbing(absorber(domain))))
+	}
+}
+
+impl Debug for KeccakHash {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("KeccakHash").finish_non_exhaustive()
+	}
+}
+
+/// Creates a SHA3-224 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_sha3_224() -> *mut KeccakHash {
+	KeccakHash::fixed(Algorithm::Sha3_224)
+}
+
+/// Creates a SHA3-256 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_sha3_256() -> *mut KeccakHash {
+	KeccakHash::fixed(Algorithm::Sha3_256)
+}
+
+/// Creates a SHA3-384 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_sha3_384() -> *mut KeccakHash {
+	KeccakHash::fixed(Algorithm::Sha3_384)
+}
+
+/// Creates a SHA3-512 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_sha3_512() -> *mut KeccakHash {
+	KeccakHash::fixed(Algorithm::Sha3_512)
+}
+
+/// Creates a SHAKE128 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_shake128() -> *mut KeccakHash {
+	KeccakHash::xof(Algorithm::Shake128)
+}
+
+/// Creates a SHAKE256 hash.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_shake256() -> *mut KeccakHash {
+	KeccakHash::xof(Algorithm::Shake256)
+}
+
+/// Creates a TurboSHAKE128 hash with the domain separation byte `domain`, or returns null if it is
+/// not in 0x01..=0x7F. The default domain is 0x1F.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_turboshake128(domain: u8) -> *mut KeccakHash {
+	KeccakHash::turbo_shake(domain, |domain| {
+		Absorber::TurboShake128(TurboShake128DynDomain::new(domain))
+	})
+}
+
+/// Creates a TurboSHAKE256 hash with the domain separation byte `domain`, or returns null if it is
+/// not in 0x01..=0x7F. The default domain is 0x1F.
+#[no_mangle]
+pub extern "C" fn keccak_hash_new_turboshake256(domain: u8) -> *mut KeccakHash {
+	KeccakHash::turbo_shake(domain, |domain| {
+		Absorber::TurboShake256(TurboShake256DynDomain::new(domain))
+	})
+}
+
+/// Absorbs `len` bytes from `data`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn keccak_hash_update(
+	hash: Option<&mut KeccakHash>,
+	data: *const u8,
+	len: usize
+) -> HashReturn {
+	let Some(absorber) = hash.and_then(|hash| hash.0.absorbing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	let data = unsafe { input(data, len) };
+
+	match absorber {
+		Absorber::Fixed(hasher) => hasher.update(data),
+		Absorber::Xof(xof) => xof.update(data),
+		Absorber::TurboShake128(hash) => hash.absorb(data),
+		Absorber::TurboShake256(hash) => hash.absorb(data)
+	}
+
+	HashReturn::Success
+}
+
+/// Ends the input and writes the first `len` bytes of output to `out`. For SHA3, `len` must be the
+/// digest length, and for XOFs, the output continues with `keccak_hash_squeeze`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn keccak_hash_final(
+	hash: Option<&mut KeccakHash>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(hash) = hash else {
+		return HashReturn::Fail;
+	};
+
+	if let Some(Absorber::Fixed(hasher)) = hash.0.absorbing() {
+		if len != hasher.algorithm().output_len() {
+			return HashReturn::BadHashLen;
+		}
+	}
+
+	let Some(absorber) = hash.0.take_absorbing() else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	let out = unsafe { output(out, len) };
+
+	let mut squeezer: Box<dyn DynXofReader> = match absorber {
+		Absorber::Fixed(hasher) => {
+			hasher.finalize_into(out);
+			return HashReturn::Success;
+		}
+		Absorber::Xof(xof) => xof.finalize_xof(),
+		Absorber::TurboShake128(hash) => Box::new(hash.into_squeezer()),
+		Absorber::TurboShake256(hash) => Box::new(hash.into_squeezer())
+	};
+
+	squeezer.read(out);
+	hash.0 = Phase::Squeezing(squeezer);
+	HashReturn::Success
+}
+
+/// Writes the next `len` bytes of output of a finalized XOF to `out`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn keccak_hash_squeeze(
+	hash: Option<&mut KeccakHash>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(squeezer) = hash.and_then(|hash| hash.0.squeezing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	squeezer.read(unsafe { output(out, len) });
+	HashReturn::Success
+}
+
+/// Releases a handle, which may be null.
+#[no_mangle]
+pub extern "C" fn keccak_hash_free(hash: Option<Box<KeccakHash>>) {
+	drop(hash);
+}
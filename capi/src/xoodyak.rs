@@ -0,0 +1,355 @@
+use core::{
+	fmt::{self, Debug, Formatter},
+	ptr
+};
+
+use sponge::{cyclist::HashReader, state::Lsbu32};
+use xoodoo::{Xoodoo, XoodyakHasher, XoodyakKeyed};
+
+use crate::{copy_to_output, input, into_handle, output, HashReturn, Phase};
+
+/// The tag length of `xoodyak_aead_encrypt` and `xoodyak_aead_decrypt`.
+pub const XOODYAK_TAG_LEN: usize = 16;
+
+/// A Xoodyak hash.
+pub struct XoodyakHash(Phase<XoodyakHasher<Lsbu32<12>>, HashReader<Lsbu32<12>, Xoodoo, 16>>);
+
+/// A Xoodyak instance in keyed mode.
+#[derive(Clone, Copy)]
+pub struct Xoodyak(XoodyakKeyed<Lsbu32<12>>);
+
+impl Xoodyak {
+	/// Returns `None` if the key and ID do not fit in a block together.
+	fn new(key: &[u8], id: &[u8], counter: &[u8]) -> Option<Self> {
+		(key.len() + id.len() < 44)
+			.then(|| Self(XoodyakKeyed::with_key_id_counter(key, id, counter)))
+	}
+}
+
+impl Debug for XoodyakHash {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("XoodyakHash").finish_non_exhaustive()
+	}
+}
+
+impl Debug for Xoodyak {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Xoodyak").finish_non_exhaustive()
+	}
+}
+
+/// Creates a Xoodyak hash.
+#[no_mangle]
+pub extern "C" fn xoodyak_hash_new() -> *mut XoodyakHash {
+	into_handle(XoodyakHash(Phase::Absorbing(XoodyakHasher::default())))
+}
+
+/// Absorbs `len` bytes from `data`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_hash_update(
+	hash: Option<&mut XoodyakHash>,
+	data: *const u8,
+	len: usize
+) -> HashReturn {
+	let Some(hasher) = hash.and_then(|hash| hash.0.absorbing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	hasher.absorb(unsafe { input(data, len) });
+	HashReturn::Success
+}
+
+/// Ends the input and writes the first `len` bytes of output to `out`, 32 for the standard digest.
+/// The output continues with `xoodyak_hash_squeeze`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_hash_final(
+	hash: Option<&mut XoodyakHash>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(hash) = hash else {
+		return HashReturn::Fail;
+	};
+
+	let Some(hasher) = hash.0.take_absorbing() else {
+		return HashReturn::Fail;
+	};
+
+	let mut reader = hasher.into_reader();
+	// SAFETY: guaranteed by the caller.
+	reader.squeeze_into(unsafe { output(out, len) });
+	hash.0 = Phase::Squeezing(reader);
+	HashReturn::Success
+}
+
+/// Writes the next `len` bytes of output of a finalized hash to `out`.
+///
+/// # Safety
+///
+/// `hash` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_hash_squeeze(
+	hash: Option<&mut XoodyakHash>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(reader) = hash.and_then(|hash| hash.0.squeezing()) else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	reader.squeeze_into(unsafe { output(out, len) });
+	HashReturn::Success
+}
+
+/// Releases a handle, which may be null.
+#[no_mangle]
+pub extern "C" fn xoodyak_hash_free(hash: Option<Box<XoodyakHash>>) {
+	drop(hash);
+}
+
+/// Creates a keyed Xoodyak instance from a key, an ID and a counter, the last two of which may be
+/// empty, or returns null if `key_len + id_len` is not below 44.
+///
+/// # Safety
+///
+/// Each of `key`, `id` and `counter` must be valid for reads of its length.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_new(
+	key: *const u8,
+	key_len: usize,
+	id: *const u8,
+	id_len: usize,
+	counter: *const u8,
+	counter_len: usize
+) -> *mut Xoodyak {
+	// SAFETY: guaranteed by the caller.
+	let (key, id, counter) =
+		unsafe { (input(key, key_len), input(id, id_len), input(counter, counter_len)) };
+
+	Xoodyak::new(key, id, counter).map_or(ptr::null_mut(), into_handle)
+}
+
+/// Absorbs `len` bytes from `data`.
+///
+/// # Safety
+///
+/// `xoodyak` must be null or a live handle, and `data` must be valid for reads of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_absorb(
+	xoodyak: Option<&mut Xoodyak>,
+	data: *const u8,
+	len: usize
+) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	xoodyak.0.absorb(unsafe { input(data, len) });
+	HashReturn::Success
+}
+
+/// Encrypts `len` bytes from `plaintext` into `ciphertext`, which may be the same buffer.
+///
+/// # Safety
+///
+/// `xoodyak` must be null or a live handle, `plaintext` must be valid for reads and `ciphertext`
+/// for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_encrypt(
+	xoodyak: Option<&mut Xoodyak>,
+	plaintext: *const u8,
+	ciphertext: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	xoodyak.0.encrypt(unsafe { copy_to_output(plaintext, ciphertext, len) });
+	HashReturn::Success
+}
+
+/// Decrypts `len` bytes from `ciphertext` into `plaintext`, which may be the same buffer.
+///
+/// # Safety
+///
+/// `xoodyak` must be null or a live handle, `ciphertext` must be valid for reads and `plaintext`
+/// for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_decrypt(
+	xoodyak: Option<&mut Xoodyak>,
+	ciphertext: *const u8,
+	plaintext: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	// SAFETY: guaranteed by the caller.
+	xoodyak.0.decrypt(unsafe { copy_to_output(ciphertext, plaintext, len) });
+	HashReturn::Success
+}
+
+/// Writes `len` bytes of output, such as a tag, to `out`. `len` must not be 0.
+///
+/// # Safety
+///
+/// `xoodyak` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_squeeze(
+	xoodyak: Option<&mut Xoodyak>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	if len == 0 {
+		return HashReturn::BadHashLen;
+	}
+
+	// SAFETY: guaranteed by the caller.
+	xoodyak.0.squeeze_into(unsafe { output(out, len) });
+	HashReturn::Success
+}
+
+/// Writes `len` bytes of key material to `out`. `len` must not be 0.
+///
+/// # Safety
+///
+/// `xoodyak` must be null or a live handle, and `out` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_squeeze_key(
+	xoodyak: Option<&mut Xoodyak>,
+	out: *mut u8,
+	len: usize
+) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	if len == 0 {
+		return HashReturn::BadHashLen;
+	}
+
+	// SAFETY: guaranteed by the caller.
+	xoodyak.0.squeeze_key_into(unsafe { output(out, len) });
+	HashReturn::Success
+}
+
+/// Overwrites part of the state so that it cannot be rolled back.
+#[no_mangle]
+pub extern "C" fn xoodyak_ratchet(xoodyak: Option<&mut Xoodyak>) -> HashReturn {
+	let Some(xoodyak) = xoodyak else {
+		return HashReturn::Fail;
+	};
+
+	xoodyak.0.ratchet();
+	HashReturn::Success
+}
+
+/// Releases a handle, which may be null.
+#[no_mangle]
+pub extern "C" fn xoodyak_free(xoodyak: Option<Box<Xoodyak>>) {
+	drop(xoodyak);
+}
+
+/// Encrypts `len` bytes from `plaintext` into `ciphertext`, which may be the same buffer, and
+/// writes the `XOODYAK_TAG_LEN`-byte tag authenticating them and `ad` to `tag`, as the Xoodyak
+/// submission to the NIST lightweight cryptography project does with a 16-byte key and nonce.
+/// Fails if `key_len + nonce_len` is not below 44.
+///
+/// # Safety
+///
+/// Each input must be valid for reads and each output for writes of its length.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_aead_encrypt(
+	key: *const u8,
+	key_len: usize,
+	nonce: *const u8,
+	nonce_len: usize,
+	ad: *const u8,
+	ad_len: usize,
+	plaintext: *const u8,
+	ciphertext: *mut u8,
+	len: usize,
+	tag: *mut u8
+) -> HashReturn {
+	// SAFETY: guaranteed by the caller.
+	let (key, nonce, ad) =
+		unsafe { (input(key, key_len), input(nonce, nonce_len), input(ad, ad_len)) };
+
+	let Some(Xoodyak(mut xoodyak)) = Xoodyak::new(key, nonce, &[]) else {
+		return HashReturn::Fail;
+	};
+
+	xoodyak.absorb(ad);
+	// SAFETY: guaranteed by the caller.
+	xoodyak.encrypt(unsafe { copy_to_output(plaintext, ciphertext, len) });
+	// SAFETY: guaranteed by the caller.
+	xoodyak.squeeze_into(unsafe { output(tag, XOODYAK_TAG_LEN) });
+	HashReturn::Success
+}
+
+/// Decrypts `len` bytes from `ciphertext` into `plaintext`, which may be the same buffer, and
+/// verifies them and `ad` against the `XOODYAK_TAG_LEN`-byte `tag`. On failure, `plaintext` is
+/// zeroed.
+///
+/// # Safety
+///
+/// Each input must be valid for reads and each output for writes of its length.
+#[no_mangle]
+pub unsafe extern "C" fn xoodyak_aead_decrypt(
+	key: *const u8,
+	key_len: usize,
+	nonce: *const u8,
+	nonce_len: usize,
+	ad: *const u8,
+	ad_len: usize,
+	ciphertext: *const u8,
+	plaintext: *mut u8,
+	len: usize,
+	tag: *const u8
+) -> HashReturn {
+	// SAFETY: guaranteed by the caller.
+	let (key, nonce, ad, tag) = unsafe {
+		(
+			input(key, key_len),
+			input(nonce, nonce_len),
+			input(ad, ad_len),
+			input(tag, XOODYAK_TAG_LEN)
+		)
+	};
+
+	let Some(Xoodyak(mut xoodyak)) = Xoodyak::new(key, nonce, &[]) else {
+		return HashReturn::Fail;
+	};
+
+	xoodyak.absorb(ad);
+	// SAFETY: guaranteed by the caller.
+	let plaintext = unsafe { copy_to_output(ciphertext, plaintext, len) };
+	xoodyak.decrypt(plaintext);
+
+	let expected = xoodyak.squeeze::<XOODYAK_TAG_LEN>();
+	let diff = expected.iter().zip(tag).fold(0, |acc, (a, b)| acc | (a ^ b));
+	if diff != 0 {
+		plaintext.fill(0);
+		return HashReturn::Fail;
+	}
+
+	HashReturn::Success
+}
@@ -0,0 +1,363 @@
+/*
+ * Checks the known-answer tests of keccak and xoodoo through the C API.
+ *
+ * Usage: kat KECCAK_KAT_DIR XOODOO_KAT_DIR
+ *
+ * This file is also compiled as C++ to check the header there.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "capi.h"
+
+#define LINE_CAP 4096
+#define BUF_CAP 2048
+
+static int checks = 0;
+static int failures = 0;
+
+static void check(int ok, const char *what, const char *vector)
+{
+	checks++;
+	if (!ok) {
+		failures++;
+		fprintf(stderr, "FAILED: %s, %s\n", what, vector);
+	}
+}
+
+static FILE *open_kat(const char *dir, const char *name)
+{
+	char path[LINE_CAP];
+	FILE *file;
+
+	snprintf(path, sizeof path, "%s/%s", dir, name);
+	file = fopen(path, "r");
+	if (file == NULL) {
+		perror(path);
+		exit(2);
+	}
+
+	return file;
+}
+
+/* Reads the next `name = value` line, skipping blank lines and comments, into `value`. Returns 0 at
+ * the end of the file. */
+static int next_field(FILE *file, const char *name, char *value)
+{
+	char line[LINE_CAP];
+	size_t name_len = strlen(name);
+
+	for (;;) {
+		if (fgets(line, sizeof line, file) == NULL)
+			return 0;
+
+		line[strcspn(line, "\r\n")] = '\0';
+		if (line[0] != '\0' && line[0] != '#')
+			break;
+	}
+
+	if (strncmp(line, name, name_len) != 0 || strncmp(line + name_len, " = ", 3) != 0) {
+		fprintf(stderr, "expected %s, found: %s\n", name, line);
+		exit(2);
+	}
+
+	strcpy(value, line + name_len + 3);
+	return 1;
+}
+
+static size_t unhex(const char *hex, uint8_t *out)
+{
+	size_t len = strlen(hex) / 2;
+	size_t i;
+
+	for (i = 0; i < len; i++) {
+		unsigned int byte;
+		sscanf(hex + 2 * i, "%2x", &byte);
+		out[i] = (uint8_t)byte;
+	}
+
+	return len;
+}
+
+static void pattern(uint8_t *out, size_t len)
+{
+	size_t i;
+
+	for (i = 0; i < len; i++)
+		out[i] = (uint8_t)(i % 251);
+}
+
+/* A ShortMsgKAT file, of which only the whole-byte messages are checked. */
+static void keccak_kat(const char *dir, const char *name, KeccakHash *(*new_hash)(void), int xof)
+{
+	FILE *file = open_kat(dir, name);
+	char line[LINE_CAP], vector[LINE_CAP];
+	static uint8_t msg[BUF_CAP], expected[BUF_CAP], out[BUF_CAP];
+
+	while (next_field(file, "Len", line)) {
+		long bits = strtol(line, NULL, 10);
+		size_t len, half;
+		KeccakHash *hash;
+
+		snprintf(vector, sizeof vector, "Len = %ld", bits);
+
+		next_field(file, "Msg", line);
+		unhex(line, msg);
+		next_field(file, xof ? "Squeezed" : "MD", line);
+		len = unhex(line, expected);
+
+		if (bits % 8 != 0)
+			continue;
+
+		half = (size_t)bits / 16;
+		hash = new_hash();
+		check(keccak_hash_update(hash, msg, half) == HASH_RETURN_SUCCESS, name, vector);
+		check(keccak_hash_update(hash, msg + half, (size_t)bits / 8 - half) == HASH_RETURN_SUCCESS,
+		      name, vector);
+
+		memset(out, 0, sizeof out);
+		if (xof) {
+			/* The output may be read in parts. */
+			check(keccak_hash_final(hash, out, 1) == HASH_RETURN_SUCCESS, name, vector);
+			check(keccak_hash_squeeze(hash, out + 1, len - 1) == HASH_RETURN_SUCCESS, name, vector);
+		} else {
+			check(keccak_hash_final(hash, out, len) == HASH_RETURN_SUCCESS, name, vector);
+		}
+
+		check(memcmp(out, expected, len) == 0, name, vector);
+		keccak_hash_free(hash);
+	}
+
+	fclose(file);
+}
+
+/* Vectors from RFC 9861, over a message that is either the 0x00..=0xFA pattern or repeated `fill`
+ * bytes, and for KangarooTwelve a customization string with the pattern. */
+struct test_vector {
+	const char *name;
+	uint8_t domain;
+	size_t msg_len;
+	int fill;
+	size_t customization_len;
+	size_t skip;
+	const char *expected;
+};
+
+static const struct test_vector turbo_shake_vectors[] = {
+	{"TurboSHAKE128", 0x1F, 0, -1, 0, 0,
+	 "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c"},
+	{"TurboSHAKE128", 0x1F, 0, -1, 0, 10000,
+	 "a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607"},
+	{"TurboSHAKE128", 0x1F, 17, -1, 0, 0,
+	 "9c97d036a3bac819db70ede0ca554ec6e4c2a1a4ffbfd9ec269ca6a111161233"},
+	{"TurboSHAKE128", 0x01, 3, 0xFF, 0, 0,
+	 "bf323f940494e88ee1c540fe660be8a0c93f43d15ec006998462fa994eed5dab"},
+	{"TurboSHAKE128", 0x06, 1, 0xFF, 0, 0,
+	 "8ec9c66465ed0d4a6c35d13506718d687a25cb05c74cca1e42501abd83874a67"},
+	{"TurboSHAKE256", 0x1F, 0, -1, 0, 0,
+	 "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db11edc0e12e91ea60eb6b32df06dd7f"
+	 "002fbafabb6e13ec1cc20d995547600db0"},
+	{"TurboSHAKE256", 0x1F, 17, -1, 0, 0,
+	 "b3bab0300e6a191fbe6137939835923578794ea54843f5011090fa2f3780a9e5cb22c59d78b40a0fbff9e672c0fbe0"
+	 "970bd2c845091c6044d687054da5d8e9c7"}
+};
+
+static const struct test_vector k12_vectors[] = {
+	{"KT128", 0, 0, -1, 0, 0, "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5"},
+	{"KT128", 0, 0, -1, 0, 0,
+	 "1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e54269c056b8c82e48276038b6d29296"
+	 "6cc07a3d4645272e31ff38508139eb0a71"},
+	{"KT128", 0, 0, -1, 0, 10000, "e8dc563642f7228c84684c898405d3a834799158c079b12880277a1d28e2ff6d"},
+	{"KT128", 0, 17, -1, 0, 0, "6bf75fa2239198db4772e36478f8e19b0f371205f6a9a93a273f51df37122888"},
+	{"KT128", 0, 17 * 17, -1, 0, 0,
+	 "0c315ebcdedbf61426de7dcf8fb725d1e74675d7f5327a5067f367b108ecb67c"},
+	{"KT128", 0, 0, -1, 1, 0, "fab658db63e94a246188bf7af69a133045f46ee984c56e3c3328caaf1aa1a583"},
+	{"KT128", 0, 1, 0xFF, 41, 0, "d848c5068ced736f4462159b9867fd4c20b808acc3d5bc48e0b06ba0a3762ec4"},
+	{"KT128", 0, 3, 0xFF, 41 * 41, 0,
+	 "c389e5009ae57120854c2e8c64670ac01358cf4c1baf89447a724234dc7ced74"}
+};
+
+static void message(const struct test_vector *v, uint8_t *msg)
+{
+	if (v->fill < 0)
+		pattern(msg, v->msg_len);
+	else
+		memset(msg, v->fill, v->msg_len);
+}
+
+static void turbo_shake_kat(void)
+{
+	static uint8_t msg[BUF_CAP], expected[BUF_CAP], out[BUF_CAP], skip[10000];
+	size_t i;
+
+	for (i = 0; i < sizeof turbo_shake_vectors / sizeof turbo_shake_vectors[0]; i++) {
+		const struct test_vector *v = &turbo_shake_vectors[i];
+		size_t len = unhex(v->expected, expected);
+		KeccakHash *hash = strcmp(v->name, "TurboSHAKE128") == 0
+		                       ? keccak_hash_new_turboshake128(v->domain)
+		                       : keccak_hash_new_turboshake256(v->domain);
+
+		message(v, msg);
+		check(keccak_hash_update(hash, msg, v->msg_len) == HASH_RETURN_SUCCESS, v->name, v->expected);
+		check(keccak_hash_final(hash, skip, v->skip) == HASH_RETURN_SUCCESS, v->name, v->expected);
+		check(keccak_hash_squeeze(hash, out, len) == HASH_RETURN_SUCCESS, v->name, v->expected);
+		check(memcmp(out, expected, len) == 0, v->name, v->expected);
+		keccak_hash_free(hash);
+	}
+}
+
+static void k12_kat(void)
+{
+	static uint8_t msg[BUF_CAP], customization[BUF_CAP], expected[BUF_CAP], out[BUF_CAP],
+		skip[10000];
+	size_t i;
+
+	for (i = 0; i < sizeof k12_vectors / sizeof k12_vectors[0]; i++) {
+		const struct test_vector *v = &k12_vectors[i];
+		size_t len = unhex(v->expected, expected);
+		KangarooTwelve *k12 = kangaroo_twelve_new();
+
+		message(v, msg);
+		pattern(customization, v->customization_len);
+		check(kangaroo_twelve_update(k12, msg, v->msg_len) == HASH_RETURN_SUCCESS, v->name,
+		      v->expected);
+		check(kangaroo_twelve_final(k12, customization, v->customization_len, skip, v->skip) ==
+		          HASH_RETURN_SUCCESS,
+		      v->name, v->expected);
+		check(kangaroo_twelve_squeeze(k12, out, len) == HASH_RETURN_SUCCESS, v->name, v->expected);
+		check(memcmp(out, expected, len) == 0, v->name, v->expected);
+		kangaroo_twelve_free(k12);
+	}
+}
+
+static void xoodyak_hash_kat(const char *dir)
+{
+	FILE *file = open_kat(dir, "LWC_HASH_KAT_256.txt");
+	char line[LINE_CAP], vector[LINE_CAP];
+	static uint8_t msg[BUF_CAP], expected[BUF_CAP], out[BUF_CAP];
+
+	while (next_field(file, "Count", line)) {
+		size_t msg_len, len;
+		XoodyakHash *hash = xoodyak_hash_new();
+
+		snprintf(vector, sizeof vector, "Count = %ld", strtol(line, NULL, 10));
+
+		next_field(file, "Msg", line);
+		msg_len = unhex(line, msg);
+		next_field(file, "MD", line);
+		len = unhex(line, expected);
+
+		check(xoodyak_hash_update(hash, msg, msg_len) == HASH_RETURN_SUCCESS, "Xoodyak", vector);
+		check(xoodyak_hash_final(hash, out, len) == HASH_RETURN_SUCCESS, "Xoodyak", vector);
+		check(memcmp(out, expected, len) == 0, "Xoodyak", vector);
+		xoodyak_hash_free(hash);
+	}
+
+	fclose(file);
+}
+
+static void xoodyak_aead_kat(const char *dir)
+{
+	FILE *file = open_kat(dir, "LWC_AEAD_KAT_128_128.txt");
+	char line[LINE_CAP], vector[LINE_CAP];
+	static uint8_t key[BUF_CAP], nonce[BUF_CAP], pt[BUF_CAP], ad[BUF_CAP], expected[BUF_CAP],
+		ct[BUF_CAP], tag[XOODYAK_TAG_LEN], out[BUF_CAP];
+
+	while (next_field(file, "Count", line)) {
+		size_t key_len, nonce_len, pt_len, ad_len;
+		Xoodyak *xoodyak;
+
+		snprintf(vector, sizeof vector, "Count = %ld", strtol(line, NULL, 10));
+
+		next_field(file, "Key", line);
+		key_len = unhex(line, key);
+		next_field(file, "Nonce", line);
+		nonce_len = unhex(line, nonce);
+		next_field(file, "PT", line);
+		pt_len = unhex(line, pt);
+		next_field(file, "AD", line);
+		ad_len = unhex(line, ad);
+		next_field(file, "CT", line);
+		unhex(line, expected);
+
+		check(xoodyak_aead_encrypt(key, key_len, nonce, nonce_len, ad, ad_len, pt, ct, pt_len, tag) ==
+		          HASH_RETURN_SUCCESS,
+		      "Xoodyak AEAD encrypt", vector);
+		check(memcmp(ct, expected, pt_len) == 0 &&
+		          memcmp(tag, expected + pt_len, XOODYAK_TAG_LEN) == 0,
+		      "Xoodyak AEAD encrypt", vector);
+
+		check(xoodyak_aead_decrypt(key, key_len, nonce, nonce_len, ad, ad_len, ct, out, pt_len, tag) ==
+		          HASH_RETURN_SUCCESS,
+		      "Xoodyak AEAD decrypt", vector);
+		check(memcmp(out, pt, pt_len) == 0, "Xoodyak AEAD decrypt", vector);
+
+		tag[0] ^= 1;
+		check(xoodyak_aead_decrypt(key, key_len, nonce, nonce_len, ad, ad_len, ct, out, pt_len, tag) ==
+		          HASH_RETURN_FAIL,
+		      "Xoodyak AEAD forgery", vector);
+
+		/* The same through the keyed instance, in place. */
+		xoodyak = xoodyak_new(key, key_len, nonce, nonce_len, NULL, 0);
+		memcpy(out, pt, pt_len);
+		check(xoodyak_absorb(xoodyak, ad, ad_len) == HASH_RETURN_SUCCESS, "Xoodyak keyed", vector);
+		check(xoodyak_encrypt(xoodyak, out, out, pt_len) == HASH_RETURN_SUCCESS, "Xoodyak keyed",
+		      vector);
+		check(xoodyak_squeeze(xoodyak, out + pt_len, XOODYAK_TAG_LEN) == HASH_RETURN_SUCCESS,
+		      "Xoodyak keyed", vector);
+		check(memcmp(out, expected, pt_len + XOODYAK_TAG_LEN) == 0, "Xoodyak keyed", vector);
+		xoodyak_free(xoodyak);
+	}
+
+	fclose(file);
+}
+
+static void misuse(void)
+{
+	static const uint8_t key[44] = {0};
+	uint8_t out[64];
+	KeccakHash *hash;
+
+	check(keccak_hash_new_turboshake128(0x00) == NULL, "TurboSHAKE domain", "0x00");
+	check(keccak_hash_new_turboshake256(0x80) == NULL, "TurboSHAKE domain", "0x80");
+	check(xoodyak_new(key, 32, key, 12, NULL, 0) == NULL, "Xoodyak key length", "44");
+
+	hash = keccak_hash_new_sha3_256();
+	check(keccak_hash_final(hash, out, 31) == HASH_RETURN_BAD_HASH_LEN, "SHA3-256", "short digest");
+	check(keccak_hash_final(hash, out, 32) == HASH_RETURN_SUCCESS, "SHA3-256", "digest");
+	check(keccak_hash_update(hash, out, 1) == HASH_RETURN_FAIL, "SHA3-256", "update after final");
+	check(keccak_hash_squeeze(hash, out, 1) == HASH_RETURN_FAIL, "SHA3-256", "squeeze");
+	keccak_hash_free(hash);
+
+	check(keccak_hash_update(NULL, out, 1) == HASH_RETURN_FAIL, "null handle", "update");
+	check(xoodyak_ratchet(NULL) == HASH_RETURN_FAIL, "null handle", "ratchet");
+	keccak_hash_free(NULL);
+	kangaroo_twelve_free(NULL);
+	xoodyak_hash_free(NULL);
+	xoodyak_free(NULL);
+}
+
+int main(int argc, char **argv)
+{
+	if (argc != 3) {
+		fprintf(stderr, "usage: %s KECCAK_KAT_DIR XOODOO_KAT_DIR\n", argv[0]);
+		return 2;
+	}
+
+	keccak_kat(argv[1], "ShortMsgKAT_SHA3-224.txt", keccak_hash_new_sha3_224, 0);
+	keccak_kat(argv[1], "ShortMsgKAT_SHA3-256.txt", keccak_hash_new_sha3_256, 0);
+	keccak_kat(argv[1], "ShortMsgKAT_SHA3-384.txt", keccak_hash_new_sha3_384, 0);
+	keccak_kat(argv[1], "ShortMsgKAT_SHA3-512.txt", keccak_hash_new_sha3_512, 0);
+	keccak_kat(argv[1], "ShortMsgKAT_SHAKE128.txt", keccak_hash_new_shake128, 1);
+	keccak_kat(argv[1], "ShortMsgKAT_SHAKE256.txt", keccak_hash_new_shake256, 1);
+	turbo_shake_kat();
+	k12_kat();
+	xoodyak_hash_kat(argv[2]);
+	xoodyak_aead_kat(argv[2]);
+	misuse();
+
+	printf("%d checks, %d failures\n", checks, failures);
+	return failures != 0;
+}
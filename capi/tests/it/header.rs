@@ -0,0 +1,29 @@
+use std::{env, fs, io, path::Path, process::Command};
+
+/// Regenerates the header with cbindgen, which is skipped if it is not installed, and checks it
+/// against `include/capi.h`.
+#[test]
+fn up_to_date() {
+	let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+	let cbindgen = env::var("CBINDGEN").unwrap_or_else(|_| "cbindgen".into());
+
+	let output = match Command::new(&cbindgen)
+		.args(["--quiet", "--config", "cbindgen.toml"])
+		.current_dir(manifest_dir)
+		.output()
+	{
+		Err(err) if err.kind() == io::ErrorKind::NotFound => {
+			eprintln!("skipped: {cbindgen} not found");
+			return;
+		}
+		output => output.unwrap()
+	};
+
+	assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+	let header = fs::read_to_string(manifest_dir.join("include/capi.h")).unwrap();
+	assert!(
+		String::from_utf8_lossy(&output.stdout) == header,
+		"include/capi.h is out of date, regenerate it as described in cbindgen.toml"
+	);
+}
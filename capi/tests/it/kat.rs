@@ -0,0 +1,80 @@
+use std::{
+	env,
+	path::{Path, PathBuf},
+	process::Command
+};
+
+const MANIFEST_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Builds `libcapi.a` and `libcapi.so`, which `cargo test` does not, and returns their directory.
+fn lib_dir() -> PathBuf {
+	let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("target");
+	let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+		.args(["build", "--quiet", "--lib", "--manifest-path"])
+		.arg(Path::new(MANIFEST_DIR).join("Cargo.toml"))
+		.arg("--target-dir")
+		.arg(&target_dir)
+		.status()
+		.unwrap();
+	assert!(status.success(), "cargo build failed");
+
+	target_dir.join("debug")
+}
+
+/// Compiles `tests/c/kat.c` with `compiler` and runs it on the KAT files of keccak and xoodoo.
+fn compile_and_run(compiler: &str, args: &[&str], link: &[String], name: &str) {
+	let manifest_dir = Path::new(MANIFEST_DIR);
+	let exe = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+
+	let status = Command::new(compiler)
+		.args(["-Wall", "-Wextra", "-Werror", "-pedantic"])
+		.args(args)
+		.arg("-I")
+		.arg(manifest_dir.join("include"))
+		.arg(manifest_dir.join("tests/c/kat.c"))
+		.args(link)
+		.arg("-o")
+		.arg(&exe)
+		.status()
+		.unwrap_or_else(|err| panic!("failed to run {compiler}: {err}"));
+	assert!(status.success(), "{compiler} failed");
+
+	let output = Command::new(&exe)
+		.arg(manifest_dir.join("../keccak/tests/it"))
+		.arg(manifest_dir.join("../xoodoo/tests/it"))
+		.output()
+		.unwrap();
+
+	assert!(
+		output.status.success(),
+		"{}{}",
+		String::from_utf8_lossy(&output.stdout),
+		String::from_utf8_lossy(&output.stderr)
+	);
+}
+
+#[test]
+#[cfg(unix)]
+fn c_static() {
+	let compiler = env::var("CC").unwrap_or_else(|_| "cc".into());
+	let lib = lib_dir().join("libcapi.a");
+	let link = [lib.display().to_string(), "-lpthread".into(), "-ldl".into(), "-lm".into()];
+
+	compile_and_run(&compiler, &["-std=c99"], &link, "kat-c");
+}
+
+#[test]
+#[cfg(unix)]
+fn cpp_dynamic() {
+	let compiler = env::var("CXX").unwrap_or_else(|_| "c++".into());
+	let dir = lib_dir().display().to_string();
+	let link = [
+		"-x".into(),
+		"none".into(),
+		format!("-L{dir}"),
+		format!("-Wl,-rpath,{dir}"),
+		"-lcapi".into()
+	];
+
+	compile_and_run(&compiler, &["-x", "c++", "-std=c++11"], &link, "kat-cpp");
+}
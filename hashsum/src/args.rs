@@ -0,0 +1,177 @@
+use std::{ffi::OsString, path::PathBuf};
+
+use crate::Program;
+
+#[derive(Debug, Default)]
+pub(crate) struct Args {
+	pub(crate) algorithm: Option<String>,
+	/// The output length in bits.
+	pub(crate) length: Option<usize>,
+	pub(crate) customization: Option<Vec<u8>>,
+	pub(crate) check: bool,
+	pub(crate) tag: bool,
+	pub(crate) recursive: bool,
+	pub(crate) quiet: bool,
+	pub(crate) status: bool,
+	pub(crate) strict: bool,
+	pub(crate) help: bool,
+	files: Vec<PathBuf>
+}
+
+impl Args {
+	pub(crate) fn parse(args: impl IntoIterator<Item = OsString>) -> Result<Self, String> {
+		let mut parsed = Self::default();
+		let mut args = args.into_iter();
+
+		while let Some(arg) = args.next() {
+			let Some(s) = arg.to_str() else {
+				parsed.files.push(arg.into());
+				continue;
+			};
+
+			if s == "--" {
+				parsed.files.extend(args.by_ref().map(PathBuf::from));
+				break;
+			}
+
+			if let Some(long) = s.strip_prefix("--") {
+				let (name, value) = match long.split_once('=') {
+					Some((name, value)) => (name, Some(OsString::from(value))),
+					None => (long, None)
+				};
+
+				parsed.long(name, value, &mut args)?;
+			} else if s.len() > 1 && s.starts_with('-') {
+				for (i, c) in s[1..].char_indices() {
+					let rest = &s[1 + i + c.len_utf8()..];
+
+					if Self::takes_value(c) {
+						let value = if rest.is_empty() { None } else { Some(rest.into()) };
+						parsed.short(c, value, &mut args)?;
+						break;
+					}
+
+					parsed.short(c, None, &mut args)?;
+				}
+			} else {
+				parsed.files.push(arg.into());
+			}
+		}
+
+		Ok(parsed)
+	}
+
+	/// The files to read, with `-` standing for standard input.
+	pub(crate) fn files(&self) -> Vec<PathBuf> {
+		if self.files.is_empty() {
+			vec![PathBuf::from("-")]
+		} else {
+			self.files.clone()
+		}
+	}
+
+	const fn takes_value(short: char) -> bool {
+		matches!(short, 'a' | 'l' | 'C')
+	}
+
+	fn short(
+		&mut self,
+		short: char,
+		value: Option<OsString>,
+		args: &mut dyn Iterator<Item = OsString>
+	) -> Result<(), String> {
+		let long = match short {
+			'a' => "algorithm",
+			'l' => "length",
+			'C' => "customization",
+			'c' => "check",
+			'r' => "recursive",
+			'h' => "help",
+			// Text and binary modes are the same on every supported platform.
+			'b' | 't' => return Ok(()),
+			_ => return Err(format!("invalid option -- '{short}'"))
+		};
+
+		self.long(long, value, args)
+	}
+
+	fn long(
+		&mut self,
+		long: &str,
+		value: Option<OsString>,
+		args: &mut dyn Iterator<Item = OsString>
+	) -> Result<(), String> {
+		let value = || {
+			value
+				.or_else(|| args.next())
+				.ok_or_else(|| format!("option '--{long}' requires a value"))
+		};
+
+		match long {
+			"algorithm" => {
+				let value = value()?;
+				let value = value.to_str().ok_or("algorithm name is not valid UTF-8")?;
+				self.algorithm = Some(value.into());
+			}
+			"length" => {
+				let value = value()?;
+				let bits = value.to_str().and_then(|bits| bits.parse().ok());
+				self.length = Some(
+					bits.ok_or_else(|| format!("invalid length: {}", value.to_string_lossy()))?
+				);
+			}
+			"customization" => self.customization = Some(value()?.into_encoded_bytes()),
+			"check" => self.check = true,
+			"tag" => self.tag = true,
+			"recursive" => self.recursive = true,
+			"quiet" => self.quiet = true,
+			"status" => self.status = true,
+			"strict" => self.strict = true,
+			"help" => self.help = true,
+			"binary" | "text" => {}
+			_ => return Err(format!("unrecognized option '--{long}'"))
+		}
+
+		Ok(())
+	}
+}
+
+pub(crate) fn usage(program: &Program) -> String {
+	format!(
+		"\
+Usage: {name} [OPTION]... [FILE]...
+Print or check {algorithm} checksums.
+
+With no FILE, or when FILE is -, read standard input.
+
+  -a, --algorithm NAME      use the named algorithm instead of {algorithm}{prefix}
+  -l, --length BITS         output length in bits of an extendable-output algorithm
+  -C, --customization STR   customization string of KT128, KT256 or MarsupilamiFourteen
+  -r, --recursive           hash the files in directories recursively
+      --tag                 create a BSD-style checksum
+  -c, --check               read checksums from the FILEs and check them
+  -b, --binary, -t, --text  ignored, for compatibility
+
+The following options are useful only when verifying checksums:
+      --quiet               don't print OK for each successfully verified file
+      --status              don't output anything, status code shows success
+      --strict              exit non-zero for improperly formatted checksum lines
+
+  -h, --help                display this help and exit
+
+Algorithms: {algorithms}
+",
+		name = program.name,
+		algorithm = program.algorithm,
+		prefix = if program.prefix.is_empty() {
+			String::new()
+		} else {
+			format!(";\n                            a bare bit size N means {}N", program.prefix)
+		},
+		algorithms = registry::Algorithm::ALL
+			.iter()
+			.map(|algorithm| algorithm.name())
+			.collect::<Vec<_>>()
+			.join(", ")
+	)
+}
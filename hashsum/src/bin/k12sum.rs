@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+	hashsum::main(&hashsum::K12SUM)
+}
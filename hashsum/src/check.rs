@@ -0,0 +1,145 @@
+use std::{
+	fs,
+	io::{self, BufRead, BufReader, Write},
+	path::{Path, PathBuf}
+};
+
+use crate::{args::Args, digest, hash_file, parse_hex, spec::Spec, unescape, Program};
+
+/// A checksum line: the file, its expected digest and the algorithm producing it.
+#[derive(Debug)]
+struct Line {
+	path: PathBuf,
+	digest: Vec<u8>,
+	spec: Spec
+}
+
+/// Checks the checksums listed in every file, returning whether all of them matched.
+pub(crate) fn run(program: &Program, args: &Args, spec: &Spec) -> bool {
+	let mut stdout = io::stdout().lock();
+	let mut ok = true;
+
+	for list in args.files() {
+		let result = if list == Path::new("-") {
+			check_list(program, args, spec, &list, io::stdin().lock(), &mut stdout)
+		} else {
+			fs::File::open(&list).and_then(|file| {
+				check_list(program, args, spec, &list, BufReader::new(file), &mut stdout)
+			})
+		};
+
+		match result {
+			Ok(list_ok) => ok &= list_ok,
+			Err(err) => {
+				eprintln!("{}: {}: {err}", program.name, list.display());
+				ok = false;
+			}
+		}
+	}
+
+	ok
+}
+
+fn check_list(
+	program: &Program,
+	args: &Args,
+	spec: &Spec,
+	list: &Path,
+	reader: impl BufRead,
+	stdout: &mut dyn Write
+) -> io::Result<bool> {
+	let customization = args.customization.as_deref().unwrap_or_default();
+
+	let (mut lines, mut improper, mut mismatched, mut unreadable) = (0, 0, 0, 0);
+
+	for line in reader.lines() {
+		let line = line?;
+
+		let Some(line) = parse_line(&line, spec, customization) else {
+			improper += 1;
+			continue;
+		};
+
+		lines += 1;
+
+		let mut hasher = line.spec.hasher();
+		let result = if line.path == Path::new("-") {
+			digest(&mut hasher, io::stdin().lock())
+		} else {
+			hash_file(&mut hasher, &line.path)
+		};
+
+		let status = match result {
+			Ok(digest) if digest == line.digest => "OK",
+			Ok(_) => {
+				mismatched += 1;
+				"FAILED"
+			}
+			Err(err) => {
+				eprintln!("{}: {}: {err}", program.name, line.path.display());
+				unreadable += 1;
+				"FAILED open or read"
+			}
+		};
+
+		let quiet = args.quiet && status == "OK";
+		if !args.status && !quiet {
+			writeln!(stdout, "{}: {status}", line.path.display())?;
+		}
+	}
+
+	if lines == 0 {
+		eprintln!(
+			"{}: {}: no properly formatted checksum lines found",
+			program.name,
+			list.display()
+		);
+		return Ok(false);
+	}
+
+	if !args.status {
+		let warn = |count: usize, one: &str, many: &str| {
+			if count != 0 {
+				let what = if count == 1 { one } else { many };
+				eprintln!("{}: WARNING: {count} {what}", program.name);
+			}
+		};
+
+		warn(improper, "line is improperly formatted", "lines are improperly formatted");
+		warn(unreadable, "listed file could not be read", "listed files could not be read");
+		warn(mismatched, "computed checksum did NOT match", "computed checksums did NOT match");
+	}
+
+	Ok(mismatched == 0 && unreadable == 0 && !(args.strict && improper != 0))
+}
+
+/// Parses a BSD-style line, `TAG (name) = hex`, or a coreutils one, `hex  name` or `hex *name`,
+/// either of which starts with a backslash if the name is escaped.
+fn parse_line(line: &str, spec: &Spec, customization: &[u8]) -> Option<Line> {
+	let (escaped, line) = match line.strip_prefix('\\') {
+		Some(line) => (true, line),
+		None => (false, line)
+	};
+
+	let (spec, name, digest) = match line.split_once(" (") {
+		Some((tag, rest)) if !tag.contains(' ') && rest.contains(") = ") => {
+			let (name, hex) = rest.rsplit_once(") = ")?;
+			let digest = parse_hex(hex)?;
+			// The tag gives the output length, which the digest must have.
+			let spec =
+				Spec::from_tag(tag, customization).filter(|spec| spec.len == digest.len())?;
+
+			(spec, name, digest)
+		}
+		_ => {
+			let (hex, name) = line.split_once(' ')?;
+			let name = name.strip_prefix([' ', '*'])?;
+			let digest = parse_hex(hex)?;
+
+			(spec.with_len_of(&digest)?, name, digest)
+		}
+	};
+
+	let path = if escaped { unescape(name)? } else { PathBuf::from(name) };
+	(!name.is_empty()).then_some(Line { path, digest, spec })
+}
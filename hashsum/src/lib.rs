@@ -0,0 +1,264 @@
+#![warn(
+	deprecated_in_future,
+	future_incompatible,
+	macro_use_extern_crate,
+	meta_variable_misuse,
+	missing_copy_implementations,
+	missing_debug_implementations,
+	noop_method_call,
+	rust_2018_compatibility,
+	rust_2018_idioms,
+	rust_2021_compatibility,
+	single_use_lifetimes,
+	trivial_casts,
+	trivial_numeric_casts,
+	unreachable_pub,
+	unsafe_op_in_unsafe_fn,
+	unused_crate_dependencies,
+	unused_lifetimes,
+	unused_qualifications,
+	unused_results
+)]
+
+//! The shared implementation of the `sha3sum`, `k12sum` and `xoodyaksum` commands, which print
+//! and check checksums in the formats of GNU coreutils.
+
+use std::{
+	fs,
+	io::{self, Read, Write},
+	path::{Path, PathBuf},
+	process::ExitCode
+};
+
+use registry::Algorithm;
+
+use crate::{
+	args::Args,
+	spec::{Hasher, Spec}
+};
+
+mod args;
+mod check;
+mod spec;
+
+/// A command and its default algorithm.
+#[derive(Clone, Copy, Debug)]
+pub struct Program {
+	name: &'static str,
+	algorithm: Algorithm,
+	/// The prefix completing a bare bit size given to `--algorithm`, e.g. `SHA3-` for `-a 384`.
+	prefix: &'static str
+}
+
+pub const SHA3SUM: Program =
+	Program { name: "sha3sum", algorithm: Algorithm::Sha3_256, prefix: "SHA3-" };
+
+pub const K12SUM: Program = Program { name: "k12sum", algorithm: Algorithm::Kt128, prefix: "KT" };
+
+pub const XOODYAKSUM: Program =
+	Program { name: "xoodyaksum", algorithm: Algorithm::XoodyakHash, prefix: "" };
+
+/// Runs `program` on the command line arguments.
+pub fn main(program: &Program) -> ExitCode {
+	let args = match Args::parse(std::env::args_os().skip(1)) {
+		Ok(args) => args,
+		Err(err) => {
+			eprintln!("{}: {err}", program.name);
+			eprintln!("Try '{} --help' for more information.", program.name);
+			return ExitCode::FAILURE;
+		}
+	};
+
+	if args.help {
+		print!("{}", args::usage(program));
+		return ExitCode::SUCCESS;
+	}
+
+	let spec = match Spec::from_args(program, &args) {
+		Ok(spec) => spec,
+		Err(err) => {
+			eprintln!("{}: {err}", program.name);
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let ok = if args.check {
+		check::run(program, &args, &spec)
+	} else if args.status || args.quiet || args.strict {
+		eprintln!(
+			"{}: the --quiet, --status and --strict options only apply when verifying checksums",
+			program.name
+		);
+		false
+	} else {
+		run(program, &args, &spec)
+	};
+
+	if ok {
+		ExitCode::SUCCESS
+	} else {
+		ExitCode::FAILURE
+	}
+}
+
+/// Prints the checksum of every file, returning whether all of them could be read.
+fn run(program: &Program, args: &Args, spec: &Spec) -> bool {
+	let mut hasher = spec.hasher();
+	let mut stdout = io::stdout().lock();
+	let mut ok = true;
+
+	let mut print = |name: &Path, digest: Vec<u8>| {
+		let line = if args.tag {
+			format_tagged(&spec.tag(), name, &digest)
+		} else {
+			format_untagged(name, &digest)
+		};
+
+		stdout.write_all(line.as_bytes()).expect("failed to write to stdout");
+	};
+
+	for path in args.files() {
+		if path == Path::new("-") {
+			match digest(&mut hasher, io::stdin().lock()) {
+				Ok(digest) => print(&path, digest),
+				Err(err) => {
+					eprintln!("{}: -: {err}", program.name);
+					ok = false;
+				}
+			}
+
+			continue;
+		}
+
+		let result = if args.recursive {
+			walk(&path, &mut |path| {
+				match fs::File::open(path).and_then(|file| digest(&mut hasher, file)) {
+					Ok(digest) => print(path, digest),
+					Err(err) => {
+						eprintln!("{}: {}: {err}", program.name, path.display());
+						ok = false;
+					}
+				}
+			})
+		} else {
+			hash_file(&mut hasher, &path).map(|digest| print(&path, digest))
+		};
+
+		if let Err(err) = result {
+			eprintln!("{}: {}: {err}", program.name, path.display());
+			ok = false;
+		}
+	}
+
+	ok
+}
+
+/// Hashes the file at `path`, which must not be a directory.
+fn hash_file(hasher: &mut Hasher, path: &Path) -> io::Result<Vec<u8>> {
+	if fs::metadata(path)?.is_dir() {
+		return Err(io::Error::other("Is a directory"));
+	}
+
+	digest(hasher, fs::File::open(path)?)
+}
+
+/// Calls `f` with `path` if it is a file, or else with every file below it in sorted order.
+/// Symbolic links to directories below `path` are not followed.
+fn walk(path: &Path, f: &mut dyn FnMut(&Path)) -> io::Result<()> {
+	if !fs::metadata(path)?.is_dir() {
+		f(path);
+		return Ok(());
+	}
+
+	let mut entries = fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+	entries.sort_by_key(fs::DirEntry::file_name);
+
+	for entry in entries {
+		let path = entry.path();
+		let file_type = entry.file_type()?;
+
+		if file_type.is_dir() {
+			walk(&path, f)?;
+		} else if !file_type.is_symlink() || !fs::metadata(&path).is_ok_and(|m| m.is_dir()) {
+			f(&path);
+		}
+	}
+
+	Ok(())
+}
+
+fn digest(hasher: &mut Hasher, mut reader: impl Read) -> io::Result<Vec<u8>> {
+	let mut buf = vec![0; 64 << 10];
+
+	loop {
+		match reader.read(&mut buf) {
+			Ok(0) => break,
+			Ok(len) => hasher.update(&buf[..len]),
+			Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+			Err(err) => {
+				hasher.reset();
+				return Err(err);
+			}
+		}
+	}
+
+	Ok(hasher.finalize_reset())
+}
+
+/// `hex  name`, with a leading backslash if the name had to be escaped.
+fn format_untagged(name: &Path, digest: &[u8]) -> String {
+	let (escaped, name) = escape(name);
+	format!("{}{}  {name}\n", if escaped { "\\" } else { "" }, hex(digest))
+}
+
+/// `TAG (name) = hex`, with a leading backslash if the name had to be escaped.
+fn format_tagged(tag: &str, name: &Path, digest: &[u8]) -> String {
+	let (escaped, name) = escape(name);
+	format!("{}{tag} ({name}) = {}\n", if escaped { "\\" } else { "" }, hex(digest))
+}
+
+/// Escapes backslashes and line breaks in `name` as coreutils does, returning whether any were
+/// found.
+fn escape(name: &Path) -> (bool, String) {
+	let name = name.to_string_lossy();
+
+	if !name.contains(['\\', '\n', '\r']) {
+		return (false, name.into_owned());
+	}
+
+	let escaped = name.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r");
+	(true, escaped)
+}
+
+fn unescape(name: &str) -> Option<PathBuf> {
+	let mut unescaped = String::with_capacity(name.len());
+	let mut chars = name.chars();
+
+	while let Some(c) = chars.next() {
+		if c != '\\' {
+			unescaped.push(c);
+			continue;
+		}
+
+		unescaped.push(match chars.next()? {
+			'\\' => '\\',
+			'n' => '\n',
+			'r' => '\r',
+			_ => return None
+		});
+	}
+
+	Some(unescaped.into())
+}
+
+fn hex(buf: &[u8]) -> String {
+	buf.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn parse_hex(s: &str) -> Option<Vec<u8>> {
+	if s.is_empty() || !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+		return None;
+	}
+
+	(0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok()).collect()
+}
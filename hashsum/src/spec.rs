@@ -0,0 +1,196 @@
+use keccak::{k12::TreeHash, keccakp::Keccak1600};
+use registry::{Algorithm, DynHasher, DynXof, DynXofReader};
+use sponge::{state::Lsbu64, Permutation};
+
+use crate::{args::Args, Program};
+
+/// An algorithm with its output length and, for the KangarooTwelve family, customization string.
+#[derive(Clone, Debug)]
+pub(crate) struct Spec {
+	pub(crate) algorithm: Algorithm,
+	/// The output length in bytes.
+	pub(crate) len: usize,
+	customization: Vec<u8>
+}
+
+impl Spec {
+	pub(crate) fn from_args(program: &Program, args: &Args) -> Result<Self, String> {
+		let algorithm = match &args.algorithm {
+			None => program.algorithm,
+			Some(name) => {
+				let full_name = if name.bytes().all(|b| b.is_ascii_digit()) {
+					format!("{}{name}", program.prefix)
+				} else {
+					name.clone()
+				};
+
+				Algorithm::from_name(&full_name)
+					.ok_or_else(|| format!("unknown algorithm: {name}"))?
+			}
+		};
+
+		let len = match args.length {
+			None => algorithm.output_len(),
+			Some(_) if !algorithm.is_xof() => {
+				return Err(format!("{algorithm} does not support --length"));
+			}
+			Some(bits) if bits == 0 || !bits.is_multiple_of(8) => {
+				return Err(format!("invalid length: {bits}, must be a positive multiple of 8"));
+			}
+			Some(bits) => bits / 8
+		};
+
+		let customization = args.customization.clone().unwrap_or_default();
+		if !customization.is_empty() && !Self::is_customizable(algorithm) {
+			return Err(format!("{algorithm} does not support --customization"));
+		}
+
+		Ok(Self { algorithm, len, customization })
+	}
+
+	/// Parses the algorithm of a BSD-style line, which is followed by `-BITS` if the output length
+	/// of an XOF is not the default one.
+	pub(crate) fn from_tag(tag: &str, customization: &[u8]) -> Option<Self> {
+		let (algorithm, len) = match Algorithm::from_name(tag) {
+			Some(algorithm) => (algorithm, algorithm.output_len()),
+			None => {
+				let (name, bits) = tag.rsplit_once('-')?;
+				let algorithm =
+					Algorithm::from_name(name).filter(|algorithm| algorithm.is_xof())?;
+				let bits = bits
+					.parse::<usize>()
+					.ok()
+					.filter(|bits| bits.is_multiple_of(8) && *bits != 0)?;
+
+				(algorithm, bits / 8)
+			}
+		};
+
+		let customization =
+			if Self::is_customizable(algorithm) { customization.to_vec() } else { Vec::new() };
+
+		Some(Self { algorithm, len, customization })
+	}
+
+	/// The same algorithm with the output length of `digest`, which for algorithms with a fixed
+	/// output length must match it.
+	pub(crate) fn with_len_of(&self, digest: &[u8]) -> Option<Self> {
+		if !self.algorithm.is_xof() && digest.len() != self.len {
+			return None;
+		}
+
+		Some(Self { len: digest.len(), ..self.clone() })
+	}
+
+	/// The algorithm name in BSD-style lines.
+	pub(crate) fn tag(&self) -> String {
+		if self.len == self.algorithm.output_len() {
+			self.algorithm.name().into()
+		} else {
+			format!("{}-{}", self.algorithm.name(), self.len * 8)
+		}
+	}
+
+	pub(crate) fn hasher(&self) -> Hasher {
+		if !self.algorithm.is_xof() {
+			return Hasher::Fixed(self.algorithm.hasher());
+		}
+
+		let xof = match self.algorithm {
+			_ if self.customization.is_empty() => None,
+			Algorithm::Kt128 => Some(Customized::<Keccak1600<12>, 32, 32>::boxed(self)),
+			Algorithm::Kt256 => Some(Customized::<Keccak1600<12>, 64, 64>::boxed(self)),
+			Algorithm::MarsupilamiFourteen => {
+				Some(Customized::<Keccak1600<14>, 64, 64>::boxed(self))
+			}
+			_ => None
+		};
+
+		let xof = xof.or_else(|| self.algorithm.xof()).expect("algorithm is an XOF");
+		Hasher::Xof(xof, self.len)
+	}
+
+	const fn is_customizable(algorithm: Algorithm) -> bool {
+		matches!(algorithm, Algorithm::Kt128 | Algorithm::Kt256 | Algorithm::MarsupilamiFourteen)
+	}
+}
+
+/// A reusable hash producing digests of a fixed length.
+pub(crate) enum Hasher {
+	Fixed(Box<dyn DynHasher>),
+	Xof(Box<dyn DynXof>, usize)
+}
+
+impl Hasher {
+	pub(crate) fn update(&mut self, buf: &[u8]) {
+		match self {
+			Self::Fixed(hasher) => hasher.update(buf),
+			Self::Xof(xof, _) => xof.update(buf)
+		}
+	}
+
+	pub(crate) fn reset(&mut self) {
+		match self {
+			Self::Fixed(hasher) => hasher.reset(),
+			Self::Xof(xof, _) => xof.reset()
+		}
+	}
+
+	pub(crate) fn finalize_reset(&mut self) -> Vec<u8> {
+		match self {
+			Self::Fixed(hasher) => {
+				let mut digest = vec![0; hasher.algorithm().output_len()];
+				hasher.finalize_into_reset(&mut digest);
+				digest
+			}
+			Self::Xof(xof, len) => xof.finalize_xof_reset().read_boxed(*len).into_vec()
+		}
+	}
+}
+
+/// A KangarooTwelve-family hash with a customization string, which the registry does not cover.
+#[derive(Clone)]
+struct Customized<P, const CAPACITY: usize, const CV_LEN: usize> {
+	hash: TreeHash<Lsbu64<25>, P, CAPACITY, CV_LEN>,
+	customization: Vec<u8>,
+	algorithm: Algorithm
+}
+
+impl<P, const CAPACITY: usize, const CV_LEN: usize> Customized<P, CAPACITY, CV_LEN>
+where
+	P: Permutation<[u64; 25]> + Clone + 'static
+{
+	fn boxed(spec: &Spec) -> Box<dyn DynXof> {
+		Box::new(Self {
+			hash: TreeHash::default(),
+			customization: spec.customization.clone(),
+			algorithm: spec.algorithm
+		})
+	}
+}
+
+impl<P, const CAPACITY: usize, const CV_LEN: usize> DynXof for Customized<P, CAPACITY, CV_LEN>
+where
+	P: Permutation<[u64; 25]> + Clone + 'static
+{
+	fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	fn update(&mut self, buf: &[u8]) {
+		self.hash.absorb(buf);
+	}
+
+	fn reset(&mut self) {
+		self.hash = TreeHash::default();
+	}
+
+	fn finalize_xof_reset(&mut self) -> Box<dyn DynXofReader> {
+		let hash = std::mem::take(&mut self.hash);
+		Box::new(hash.into_squeezer_customized(&self.customization))
+	}
+
+	fn box_clone(&self) -> Box<dyn DynXof> {
+		Box::new(self.clone())
+	}
+}
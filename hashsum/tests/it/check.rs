@@ -0,0 +1,142 @@
+use std::fs;
+
+use crate::util::{run, TempDir, K12SUM, SHA3SUM};
+
+/// Writes two files and a checksum list for them made by `args`.
+fn setup(bin: &str, args: &[&str]) -> TempDir {
+	let dir = TempDir::new();
+	let _ = dir.write("hello", "hello\n");
+	let _ = dir.write("world", "world\n");
+
+	let output = run(bin, dir.path(), &[args, &["hello", "world"]].concat(), b"");
+	assert!(output.success);
+	let _ = dir.write("SUMS", output.stdout);
+
+	dir
+}
+
+#[test]
+fn ok() {
+	for args in [&[][..], &["--tag"], &["-a", "SHAKE256", "-l", "200"], &["--tag", "-a", "KT256"]] {
+		let dir = setup(SHA3SUM, args);
+
+		// Untagged lines are checked with the algorithm given on the command line.
+		let output = run(SHA3SUM, dir.path(), &[args, &["-c", "SUMS"]].concat(), b"");
+		assert!(output.success, "{args:?}: {}", output.stderr);
+		assert_eq!(output.stdout, "hello: OK\nworld: OK\n", "{args:?}");
+		assert_eq!(output.stderr, "", "{args:?}");
+	}
+}
+
+#[test]
+fn tagged_algorithm_overrides_default() {
+	let dir = setup(SHA3SUM, &["--tag", "-a", "SHA3-384"]);
+
+	let output = run(K12SUM, dir.path(), &["--check", "SUMS"], b"");
+	assert!(output.success);
+	assert_eq!(output.stdout, "hello: OK\nworld: OK\n");
+}
+
+#[test]
+fn stdin() {
+	let dir = setup(SHA3SUM, &[]);
+	let sums = fs::read(dir.path().join("SUMS")).unwrap();
+
+	let output = run(SHA3SUM, dir.path(), &["-c"], &sums);
+	assert!(output.success);
+	assert_eq!(output.stdout, "hello: OK\nworld: OK\n");
+}
+
+#[test]
+fn mismatch() {
+	let dir = setup(SHA3SUM, &[]);
+	let _ = dir.write("world", "World\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stdout, "hello: OK\nworld: FAILED\n");
+	assert_eq!(output.stderr, "sha3sum: WARNING: 1 computed checksum did NOT match\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "--quiet", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stdout, "world: FAILED\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "--status", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stdout, "");
+	assert_eq!(output.stderr, "");
+}
+
+#[test]
+fn missing() {
+	let dir = setup(SHA3SUM, &[]);
+	fs::remove_file(dir.path().join("hello")).unwrap();
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stdout, "hello: FAILED open or read\nworld: OK\n");
+	assert!(output.stderr.ends_with("sha3sum: WARNING: 1 listed file could not be read\n"));
+}
+
+#[test]
+fn customization() {
+	let dir = setup(K12SUM, &["-C", "cust"]);
+
+	let output = run(K12SUM, dir.path(), &["-c", "-C", "cust", "SUMS"], b"");
+	assert!(output.success);
+
+	let output = run(K12SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stdout, "hello: FAILED\nworld: FAILED\n");
+}
+
+#[test]
+fn improperly_formatted() {
+	let dir = setup(SHA3SUM, &[]);
+	let mut sums = fs::read_to_string(dir.path().join("SUMS")).unwrap();
+	sums.push_str("garbage\nabcd  short\n");
+	let _ = dir.write("SUMS", sums);
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(output.success);
+	assert_eq!(output.stderr, "sha3sum: WARNING: 2 lines are improperly formatted\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "--strict", "SUMS"], b"");
+	assert!(!output.success);
+
+	let _ = dir.write("SUMS", "garbage\n");
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stderr, "sha3sum: SUMS: no properly formatted checksum lines found\n");
+}
+
+#[test]
+fn tagged_length_mismatch() {
+	let dir = setup(SHA3SUM, &["--tag", "-a", "SHAKE128", "-l", "512"]);
+	let sums = fs::read_to_string(dir.path().join("SUMS")).unwrap();
+	let _ = dir.write("SUMS", sums.replacen("SHAKE128-512 (hello)", "SHAKE128-256 (hello)", 1));
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(output.success);
+	assert_eq!(output.stdout, "world: OK\n");
+	assert_eq!(output.stderr, "sha3sum: WARNING: 1 line is improperly formatted\n");
+}
+
+#[test]
+fn escaped_name() {
+	let dir = TempDir::new();
+	let _ = dir.write("a\\b\nc", "abc");
+
+	let output = run(SHA3SUM, dir.path(), &["--tag", "a\\b\nc"], b"");
+	let _ = dir.write("SUMS", output.stdout);
+
+	let output = run(SHA3SUM, dir.path(), &["-c", "SUMS"], b"");
+	assert!(output.success, "{}", output.stderr);
+	assert_eq!(output.stdout, "a\\b\nc: OK\n");
+}
+
+#[test]
+fn options_without_check() {
+	let output = run(SHA3SUM, &std::env::temp_dir(), &["--status"], b"");
+	assert!(!output.success);
+}
@@ -0,0 +1,172 @@
+use crate::util::{run, TempDir, K12SUM, SHA3SUM, XOODYAKSUM};
+
+const SHA3_256_ABC: &str = "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532";
+
+#[test]
+fn file() {
+	let dir = TempDir::new();
+	let _ = dir.write("abc", "abc");
+
+	let output = run(SHA3SUM, dir.path(), &["abc"], b"");
+	assert!(output.success);
+	assert_eq!(output.stdout, format!("{SHA3_256_ABC}  abc\n"));
+}
+
+#[test]
+fn stdin() {
+	let dir = TempDir::new();
+
+	for args in [&[][..], &["-"]] {
+		let output = run(SHA3SUM, dir.path(), args, b"abc");
+		assert!(output.success);
+		assert_eq!(output.stdout, format!("{SHA3_256_ABC}  -\n"));
+	}
+}
+
+#[test]
+fn tag() {
+	let dir = TempDir::new();
+	let _ = dir.write("abc", "abc");
+
+	let output = run(SHA3SUM, dir.path(), &["--tag", "abc"], b"");
+	assert_eq!(output.stdout, format!("SHA3-256 (abc) = {SHA3_256_ABC}\n"));
+
+	let output = run(SHA3SUM, dir.path(), &["--tag", "-a", "shake128", "-l", "512", "abc"], b"");
+	assert_eq!(
+		output.stdout,
+		"SHAKE128-512 (abc) = 5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca578378\n"
+	);
+}
+
+#[test]
+fn algorithm() {
+	let dir = TempDir::new();
+	let _ = dir.write("abc", "abc");
+
+	let sha3_512 = "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0";
+	for args in [&["-a", "512", "abc"][..], &["-a", "SHA3-512", "abc"], &["-a512", "abc"]] {
+		let output = run(SHA3SUM, dir.path(), args, b"");
+		assert_eq!(output.stdout, format!("{sha3_512}  abc\n"), "{args:?}");
+	}
+
+	let output = run(K12SUM, dir.path(), &["--algorithm=256", "abc"], b"");
+	assert_eq!(
+		output.stdout,
+		"1b0f960f43e0384827a362330d724052a89f075c13cf496a51888a23b0075d6457071c7ea6f6f8c2274eef3804f623e4e0543cd2e2e7574de79a4fef735367de  abc\n"
+	);
+
+	let output = run(SHA3SUM, dir.path(), &["-a", "MD5", "abc"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stderr, "sha3sum: unknown algorithm: MD5\n");
+}
+
+#[test]
+fn length() {
+	let dir = TempDir::new();
+	let _ = dir.write("abc", "abc");
+
+	let output = run(SHA3SUM, dir.path(), &["-a", "SHAKE128", "-l", "64", "abc"], b"");
+	assert_eq!(output.stdout, "5881092dd818bf5c  abc\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-l", "128", "abc"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stderr, "sha3sum: SHA3-256 does not support --length\n");
+
+	let output = run(SHA3SUM, dir.path(), &["-a", "SHAKE256", "-l", "12", "abc"], b"");
+	assert!(!output.success);
+}
+
+#[test]
+fn customization() {
+	let dir = TempDir::new();
+	let _ = dir.write("abc", "abc");
+
+	let output = run(K12SUM, dir.path(), &["-C", "cust", "abc"], b"");
+	assert_eq!(
+		output.stdout,
+		"3dc2e57bbddce3e107ff6567b7cf7a2734ff7b43e948d674d47e286bdf6d16a2  abc\n"
+	);
+
+	let output = run(K12SUM, dir.path(), &["--customization", "cust", "-l", "384", "-"], b"abc");
+	assert_eq!(
+		output.stdout,
+		"3dc2e57bbddce3e107ff6567b7cf7a2734ff7b43e948d674d47e286bdf6d16a212c628680df56691a32a34c0bba0a7c8  -\n"
+	);
+
+	let output = run(SHA3SUM, dir.path(), &["-C", "cust", "abc"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stderr, "sha3sum: SHA3-256 does not support --customization\n");
+}
+
+#[test]
+fn xoodyak() {
+	let dir = TempDir::new();
+	let _ = dir.write("empty", "");
+
+	let output = run(XOODYAKSUM, dir.path(), &["--tag", "empty"], b"");
+	assert_eq!(
+		output.stdout,
+		"Xoodyak-Hash (empty) = ea152f2b47bce24efb66c479d4adf17bd324d806e85ff75ee369ee50dc8f8bd1\n"
+	);
+}
+
+#[test]
+fn recursive() {
+	let dir = TempDir::new();
+	let _ = dir.write("tree/b", "world\n");
+	let _ = dir.write("tree/a/c", "hello\n");
+	let _ = dir.write("tree/a/b", "");
+
+	let output = run(SHA3SUM, dir.path(), &["-r", "tree"], b"");
+	assert!(output.success);
+	assert_eq!(
+		output.stdout,
+		"a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a  tree/a/b\n\
+		 b314e28493eae9dab57ac4f0c6d887bddbbeb810e900d818395ace558e96516d  tree/a/c\n\
+		 67c0306a1b75607a37d4e276fb77e220578dea43eb5dc3c92d9b2499b1dfd2af  tree/b\n"
+	);
+
+	let output = run(SHA3SUM, dir.path(), &["tree", "tree/b"], b"");
+	assert!(!output.success);
+	assert_eq!(output.stderr, "sha3sum: tree: Is a directory\n");
+	assert_eq!(
+		output.stdout,
+		"67c0306a1b75607a37d4e276fb77e220578dea43eb5dc3c92d9b2499b1dfd2af  tree/b\n"
+	);
+}
+
+#[test]
+fn missing_file() {
+	let dir = TempDir::new();
+
+	let output = run(SHA3SUM, dir.path(), &["missing"], b"");
+	assert!(!output.success);
+	assert!(output.stderr.starts_with("sha3sum: missing: "));
+	assert_eq!(output.stdout, "");
+}
+
+#[test]
+fn escaped_name() {
+	let dir = TempDir::new();
+	let _ = dir.write("a\\b\nc", "abc");
+
+	let output = run(SHA3SUM, dir.path(), &["--", "a\\b\nc"], b"");
+	assert_eq!(output.stdout, format!("\\{SHA3_256_ABC}  a\\\\b\\nc\n"));
+
+	let output = run(SHA3SUM, dir.path(), &["--tag", "a\\b\nc"], b"");
+	assert_eq!(output.stdout, format!("\\SHA3-256 (a\\\\b\\nc) = {SHA3_256_ABC}\n"));
+}
+
+#[test]
+fn invalid_option() {
+	let output = run(SHA3SUM, &std::env::temp_dir(), &["-x"], b"");
+	assert!(!output.success);
+	assert_eq!(
+		output.stderr,
+		"sha3sum: invalid option -- 'x'\nTry 'sha3sum --help' for more information.\n"
+	);
+
+	let output = run(K12SUM, &std::env::temp_dir(), &["--help"], b"");
+	assert!(output.success);
+	assert!(output.stdout.starts_with("Usage: k12sum [OPTION]... [FILE]...\n"));
+}
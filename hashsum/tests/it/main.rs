@@ -0,0 +1,3 @@
+mod check;
+mod hash;
+mod util;
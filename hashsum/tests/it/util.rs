@@ -0,0 +1,70 @@
+use std::{
+	fs,
+	io::Write,
+	path::{Path, PathBuf},
+	process::{Command, Stdio},
+	sync::atomic::{AtomicUsize, Ordering}
+};
+
+/// A directory under the system temporary directory, removed on drop.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+	pub fn new() -> Self {
+		static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+		let name =
+			format!("hashsum-{}-{}", std::process::id(), COUNT.fetch_add(1, Ordering::Relaxed));
+		let path = std::env::temp_dir().join(name);
+		fs::create_dir_all(&path).unwrap();
+		Self(path)
+	}
+
+	pub fn write(&self, name: &str, contents: impl AsRef<[u8]>) -> PathBuf {
+		let path = self.0.join(name);
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(&path, contents).unwrap();
+		path
+	}
+
+	pub fn path(&self) -> &Path {
+		&self.0
+	}
+}
+
+impl Drop for TempDir {
+	fn drop(&mut self) {
+		let _ = fs::remove_dir_all(&self.0);
+	}
+}
+
+pub struct Output {
+	pub stdout: String,
+	pub stderr: String,
+	pub success: bool
+}
+
+/// Runs `bin` in `dir` with `args`, feeding it `stdin`.
+pub fn run(bin: &str, dir: &Path, args: &[&str], stdin: &[u8]) -> Output {
+	let mut child = Command::new(bin)
+		.args(args)
+		.current_dir(dir)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.unwrap();
+
+	child.stdin.take().unwrap().write_all(stdin).unwrap();
+	let output = child.wait_with_output().unwrap();
+
+	Output {
+		stdout: String::from_utf8(output.stdout).unwrap(),
+		stderr: String::from_utf8(output.stderr).unwrap(),
+		success: output.status.success()
+	}
+}
+
+pub const SHA3SUM: &str = env!("CARGO_BIN_EXE_sha3sum");
+pub const K12SUM: &str = env!("CARGO_BIN_EXE_k12sum");
+pub const XOODYAKSUM: &str = env!("CARGO_BIN_EXE_xoodyaksum");
@@ -0,0 +1,52 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use keccak::{
+	k12::KangarooTwelve,
+	keccakp::{keccakp_1600, keccakp_1600_unrolled, Keccak1600},
+	sha3::Sha3_256
+};
+
+fn keccakp(c: &mut Criterion) {
+	let mut group = c.benchmark_group("keccak-p[1600]");
+
+	for rounds in [12, 24] {
+		let rcs = &Keccak1600::<24>::RCS[24 - rounds..];
+
+		group.bench_function(BenchmarkId::new("generic", rounds), |b| {
+			let mut state = [0; 25];
+			b.iter(|| keccakp_1600(&mut state, rcs));
+		});
+
+		group.bench_function(BenchmarkId::new("unrolled", rounds), |b| {
+			let mut state = [0; 25];
+			b.iter(|| keccakp_1600_unrolled(&mut state, rcs));
+		});
+	}
+}
+
+fn hashes(c: &mut Criterion) {
+	for len in [64, 8 << 10, 1 << 20] {
+		let buf = vec![0xA5; len];
+
+		let mut group = c.benchmark_group(format!("{len} bytes"));
+		group.throughput(Throughput::Bytes(len as u64));
+
+		group.bench_function("sha3-256", |b| {
+			b.iter(|| {
+				let mut hash = Sha3_256::new();
+				hash.absorb(&buf);
+				hash.squeeze()
+			});
+		});
+
+		group.bench_function("k12", |b| {
+			b.iter(|| {
+				let mut hash = KangarooTwelve::new();
+				hash.absorb(&buf);
+				hash.squeeze::<32>()
+			});
+		});
+	}
+}
+
+criterion_group!(benches, keccakp, hashes);
+criterion_main!(benches);
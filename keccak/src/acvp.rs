@@ -0,0 +1,494 @@
+//! A runner for NIST ACVP vector sets of SHA3 and SHAKE.
+//!
+//! [`respond`] takes the JSON of a request vector set, as downloaded from the ACVP server, and
+//! returns the JSON of the response to upload. It covers the algorithm functional tests (`AFT`),
+//! the Monte Carlo tests (`MCT`, in both the standard and alternate versions for SHA3 and with
+//! variable output lengths for SHAKE), the large data tests (`LDT`) and the SHAKE variable output
+//! tests (`VOT`). Messages and outputs must be whole bytes, so the `inBit` and `outBit`
+//! capabilities must not be registered. ACVP defines no KangarooTwelve vectors.
+//!
+//! ```
+//! let request = r#"[
+//!     { "acvVersion": "1.0" },
+//!     {
+//!         "vsId": 1, "algorithm": "SHA3-256", "revision": "2.0",
+//!         "testGroups": [
+//!             { "tgId": 1, "testType": "AFT", "tests": [{ "tcId": 1, "msg": "", "len": 0 }] }
+//!         ]
+//!     }
+//! ]"#;
+//!
+//! let response: serde_json::Value = serde_json::from_str(&keccak::acvp::respond(request)?)?;
+//! assert_eq!(
+//!     response[1]["testGroups"][0]["tests"][0]["md"],
+//!     "A7FFC6F8BF1ED76651C14756A061D662F580FF4DE43B49FA82D80A4B80F8434A"
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+use std::{string::String, vec, vec::Vec};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sponge::{state::Lsbu64, Absorb, Squeeze};
+
+use crate::sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
+
+/// A request vector set.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSet {
+	pub vs_id: u64,
+	pub algorithm: String,
+	pub revision: String,
+	#[serde(default)]
+	pub is_sample: bool,
+	pub test_groups: Vec<TestGroup>
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestGroup {
+	pub tg_id: u64,
+	pub test_type: TestType,
+	#[serde(default)]
+	pub mct_version: MctVersion,
+	/// The shortest SHAKE output of the Monte Carlo tests in bits.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub min_out_len: Option<usize>,
+	/// The longest SHAKE output of the Monte Carlo tests in bits.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub max_out_len: Option<usize>,
+	pub tests: Vec<TestCase>
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum TestType {
+	Aft,
+	Mct,
+	Ldt,
+	Vot
+}
+
+/// How the SHA3 Monte Carlo test feeds each digest back: as is in the standard version, or cut or
+/// padded with zeros to the length of the seed in the alternate one.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MctVersion {
+	#[default]
+	Standard,
+	Alternate
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestCase {
+	pub tc_id: u64,
+	#[serde(default)]
+	pub msg: Hex,
+	/// The message length in bits.
+	#[serde(default)]
+	pub len: u64,
+	/// The SHAKE output length in bits.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub out_len: Option<usize>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub large_msg: Option<LargeMessage>
+}
+
+/// The message of a large data test: `content` repeated up to `full_length` bits.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LargeMessage {
+	pub content: Hex,
+	/// The length of `content` in bits.
+	pub content_length: u64,
+	pub full_length: u64,
+	pub expansion_technique: String
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Response {
+	pub vs_id: u64,
+	pub algorithm: String,
+	pub revision: String,
+	pub test_groups: Vec<GroupResponse>
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResponse {
+	pub tg_id: u64,
+	pub tests: Vec<CaseResponse>
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", untagged)]
+pub enum CaseResponse {
+	#[serde(rename_all = "camelCase")]
+	Digest {
+		tc_id: u64,
+		md: Hex,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		out_len: Option<usize>
+	},
+	#[serde(rename_all = "camelCase")]
+	MonteCarlo { tc_id: u64, results_array: Vec<MctResult> }
+}
+
+/// The output of one of the 100 outer iterations of a Monte Carlo test.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MctResult {
+	pub md: Hex,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub out_len: Option<usize>
+}
+
+/// Bytes written in uppercase hexadecimal, as ACVP does.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Hex(pub Vec<u8>);
+
+/// The protocol version in front of the vector set in the files exchanged with the server.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Version {
+	pub acv_version: String
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+enum File<T> {
+	Versioned((Version, T)),
+	Bare(T)
+}
+
+#[derive(Debug)]
+pub enum Error {
+	Json(serde_json::Error),
+	UnsupportedAlgorithm(String),
+	/// A test case that cannot be run, such as one with a message that is not whole bytes.
+	InvalidTest {
+		tg_id: u64,
+		tc_id: u64,
+		reason: &'static str
+	}
+}
+
+impl Display for Error {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Json(err) => write!(f, "invalid vector set: {err}"),
+			Self::UnsupportedAlgorithm(algorithm) => {
+				write!(f, "unsupported algorithm: {algorithm}")
+			}
+			Self::InvalidTest { tg_id, tc_id, reason } => {
+				write!(f, "test group {tg_id}, test case {tc_id}: {reason}")
+			}
+		}
+	}
+}
+
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Json(err) => Some(err),
+			_ => None
+		}
+	}
+}
+
+impl From<serde_json::Error> for Error {
+	fn from(err: serde_json::Error) -> Self {
+		Self::Json(err)
+	}
+}
+
+/// Runs the request vector set in `json` and returns the response, preceded by the protocol
+/// version if the request is.
+pub fn respond(json: &str) -> Result<String, Error> {
+	let response = match serde_json::from_str::<File<VectorSet>>(json)? {
+		File::Versioned((version, vector_set)) => {
+			File::Versioned((version, VectorSet::run(&vector_set)?))
+		}
+		File::Bare(vector_set) => File::Bare(VectorSet::run(&vector_set)?)
+	};
+
+	Ok(serde_json::to_string_pretty(&response)?)
+}
+
+impl VectorSet {
+	pub fn run(&self) -> Result<Response, Error> {
+		let algorithm = Algorithm::from_name(&self.algorithm)
+			.ok_or_else(|| Error::UnsupportedAlgorithm(self.algorithm.clone()))?;
+
+		let test_groups = self
+			.test_groups
+			.iter()
+			.map(|group| {
+				let tests = group
+					.tests
+					.iter()
+					.map(|test| {
+						let invalid = |reason| {
+							Error::InvalidTest { tg_id: group.tg_id, tc_id: test.tc_id, reason }
+						};
+
+						algorithm.run(group, test).map_err(invalid)
+					})
+					.collect::<Result<_, _>>()?;
+
+				Ok(GroupResponse { tg_id: group.tg_id, tests })
+			})
+			.collect::<Result<_, Error>>()?;
+
+		Ok(Response {
+			vs_id: self.vs_id,
+			algorithm: self.algorithm.clone(),
+			revision: self.revision.clone(),
+			test_groups
+		})
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Algorithm {
+	Sha3_224,
+	Sha3_256,
+	Sha3_384,
+	Sha3_512,
+	Shake128,
+	Shake256
+}
+
+impl Algorithm {
+	fn from_name(name: &str) -> Option<Self> {
+		Some(match name {
+			"SHA3-224" => Self::Sha3_224,
+			"SHA3-256" => Self::Sha3_256,
+			"SHA3-384" => Self::Sha3_384,
+			"SHA3-512" => Self::Sha3_512,
+			"SHAKE-128" => Self::Shake128,
+			"SHAKE-256" => Self::Shake256,
+			_ => return None
+		})
+	}
+
+	/// The digest length in bytes, or `None` for SHAKE.
+	const fn digest_len(self) -> Option<usize> {
+		match self {
+			Self::Sha3_224 => Some(224 / 8),
+			Self::Sha3_256 => Some(256 / 8),
+			Self::Sha3_384 => Some(384 / 8),
+			Self::Sha3_512 => Some(512 / 8),
+			Self::Shake128 | Self::Shake256 => None
+		}
+	}
+
+	/// The output length in bytes of a test that is not a SHAKE Monte Carlo test.
+	fn out_len(self, test: &TestCase) -> Result<usize, &'static str> {
+		match (self.digest_len(), test.out_len) {
+			(Some(len), _) => Ok(len),
+			(None, Some(bits)) if bits.is_multiple_of(8) => Ok(bits / 8),
+			(None, Some(_)) => Err("output is not whole bytes"),
+			(None, None) => Err("missing output length")
+		}
+	}
+
+	fn run(self, group: &TestGroup, test: &TestCase) -> Result<CaseResponse, &'static str> {
+		let out_len = self.digest_len().is_none().then_some(test.out_len).flatten();
+
+		let md = match group.test_type {
+			TestType::Aft | TestType::Vot => {
+				let mut md = vec![0; self.out_len(test)?];
+				self.hash(&[(message(test)?, 1)], &mut md);
+				md
+			}
+			TestType::Ldt => {
+				let mut md = vec![0; self.out_len(test)?];
+				self.hash_large(test.large_msg.as_ref().ok_or("missing large message")?, &mut md)?;
+				md
+			}
+			TestType::Mct => {
+				let results_array = match self.digest_len() {
+					Some(len) => sha3_mct(self, message(test)?, len, group.mct_version),
+					None => {
+						let bytes = |bits: Option<usize>| {
+							bits.filter(|bits| bits.is_multiple_of(8))
+								.map(|bits| bits / 8)
+								.ok_or("output lengths are missing or not whole bytes")
+						};
+
+						let (min, max) = (bytes(group.min_out_len)?, bytes(group.max_out_len)?);
+						if min < 2 || min > max {
+							return Err("invalid output lengths");
+						}
+
+						shake_mct(self, message(test)?, min, max)
+					}
+				};
+
+				return Ok(CaseResponse::MonteCarlo { tc_id: test.tc_id, results_array });
+			}
+		};
+
+		Ok(CaseResponse::Digest { tc_id: test.tc_id, md: Hex(md), out_len })
+	}
+
+	fn hash_large(self, msg: &LargeMessage, out: &mut [u8]) -> Result<(), &'static str> {
+		if msg.expansion_technique != "repeating" {
+			return Err("unsupported expansion technique");
+		}
+
+		let content = msg.content.0.get(..(msg.content_length / 8) as usize);
+		let content = content
+			.filter(|content| !content.is_empty() && msg.content_length.is_multiple_of(8))
+			.ok_or("content is empty or not whole bytes")?;
+
+		if !msg.full_length.is_multiple_of(msg.content_length) {
+			return Err("full length is not a multiple of the content length");
+		}
+
+		// Absorbing a few bytes at a time would be slow, so repeat the content into a larger block.
+		let count = msg.full_length / msg.content_length;
+		let per_block = (count as usize).clamp(1, (1 << 16) / content.len() + 1);
+		let block = content.repeat(per_block);
+
+		self.hash(&[(&block, count / per_block as u64), (content, count % per_block as u64)], out);
+		Ok(())
+	}
+
+	/// Hashes the concatenation of `count` repetitions of each part into `out`, which for SHA3 must
+	/// be the digest length.
+	fn hash(self, parts: &[(&[u8], u64)], out: &mut [u8]) {
+		fn hash<H>(parts: &[(&[u8], u64)], out: &mut [u8])
+		where
+			H: Absorb + Squeeze + Default
+		{
+			let mut hash = H::default();
+			for &(part, count) in parts {
+				for _ in 0..count {
+					hash.absorb(part);
+				}
+			}
+			hash.squeeze_into(out);
+		}
+
+		match self {
+			Self::Sha3_224 => hash::<Sha3_224<Lsbu64<25>>>(parts, out),
+			Self::Sha3_256 => hash::<Sha3_256<Lsbu64<25>>>(parts, out),
+			Self::Sha3_384 => hash::<Sha3_384<Lsbu64<25>>>(parts, out),
+			Self::Sha3_512 => hash::<Sha3_512<Lsbu64<25>>>(parts, out),
+			Self::Shake128 => hash::<Shake128<Lsbu64<25>>>(parts, out),
+			Self::Shake256 => hash::<Shake256<Lsbu64<25>>>(parts, out)
+		}
+	}
+}
+
+/// The message of a test, which must be whole bytes.
+fn message(test: &TestCase) -> Result<&[u8], &'static str> {
+	if !test.len.is_multiple_of(8) {
+		return Err("message is not whole bytes");
+	}
+
+	test.msg.0.get(..(test.len / 8) as usize).ok_or("message is shorter than its length")
+}
+
+/// The SHA3 Monte Carlo test: 100 times, hash the seed 1000 times over, feeding each digest back
+/// as the next message, and output the last digest as the next seed.
+fn sha3_mct(
+	algorithm: Algorithm,
+	seed: &[u8],
+	digest_len: usize,
+	version: MctVersion
+) -> Vec<MctResult> {
+	let mut md = seed.to_vec();
+	let mut msg = Vec::new();
+
+	(0..100)
+		.map(|_| {
+			for _ in 0..1000 {
+				msg.clone_from(&md);
+				if version == MctVersion::Alternate {
+					msg.resize(seed.len(), 0);
+				}
+
+				md.resize(digest_len, 0);
+				algorithm.hash(&[(&msg, 1)], &mut md);
+			}
+
+			MctResult { md: Hex(md.clone()), out_len: None }
+		})
+		.collect()
+}
+
+/// The SHAKE Monte Carlo test, where each message is the output before it cut or padded with
+/// zeros to the length of the seed, and each output length is drawn between `min` and `max`
+/// bytes from the last 16 bits of the output before it.
+fn shake_mct(algorithm: Algorithm, seed: &[u8], min: usize, max: usize) -> Vec<MctResult> {
+	let range = max - min + 1;
+	let mut len = max;
+	let mut output = seed.to_vec();
+	let mut msg = Vec::new();
+
+	(0..100)
+		.map(|_| {
+			for _ in 0..1000 {
+				msg.clone_from(&output);
+				msg.resize(seed.len(), 0);
+
+				output.resize(len, 0);
+				algorithm.hash(&[(&msg, 1)], &mut output);
+
+				let rightmost = u16::from_be_bytes([output[len - 2], output[len - 1]]);
+				len = min + rightmost as usize % range;
+			}
+
+			MctResult { md: Hex(output.clone()), out_len: Some(output.len() * 8) }
+		})
+		.collect()
+}
+
+impl Serialize for Hex {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer
+	{
+		const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+		let hex: String = self
+			.0
+			.iter()
+			.flat_map(|&b| [DIGITS[usize::from(b >> 4)], DIGITS[usize::from(b & 0xF)]])
+			.map(char::from)
+			.collect();
+
+		serializer.serialize_str(&hex)
+	}
+}
+
+impl<'de> Deserialize<'de> for Hex {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>
+	{
+		let hex = String::deserialize(deserializer)?;
+		if !hex.len().is_multiple_of(2) {
+			return Err(serde::de::Error::custom("odd number of hex digits"));
+		}
+
+		hex.as_bytes()
+			.chunks(2)
+			.map(|pair| {
+				let digit = |c: u8| char::from(c).to_digit(16);
+				match (digit(pair[0]), digit(pair[1])) {
+					(Some(hi), Some(lo)) => Ok((hi << 4 | lo) as u8),
+					_ => Err(serde::de::Error::custom("invalid hex digit"))
+				}
+			})
+			.collect::<Result<_, _>>()
+			.map(Self)
+	}
+}
@@ -0,0 +1,212 @@
+//! `const fn` versions of the SHA-3, SHAKE, TurboSHAKE and KangarooTwelve hashes, for digests
+//! computed at compile time.
+//!
+//! These run the generic Keccak-p[1600] backend without runtime backend selection, so they are
+//! much slower than the sponge types and are meant for short inputs in constant contexts:
+//!
+//! ```
+//! const ID: [u8; 32] = keccak::const_hash::sha3_256_const(b"protocol v1");
+//! ```
+
+use crate::keccakp::{keccakp_1600, Keccak1600};
+
+macro_rules! sha3_const {
+	($name:ident, $capacity:expr, $hash_len:expr, $doc:literal) => {
+		#[doc = concat!("Hashes `buf` with ", $doc, ".")]
+		pub const fn $name(buf: &[u8]) -> [u8; $hash_len] {
+			let mut sponge = Sponge::new($capacity, 24);
+			sponge.absorb(buf);
+			sponge.squeeze(0x06)
+		}
+	};
+}
+
+sha3_const!(sha3_224_const, 224 * 2 / 8, 224 / 8, "SHA3-224");
+sha3_const!(sha3_256_const, 256 * 2 / 8, 256 / 8, "SHA3-256");
+sha3_const!(sha3_384_const, 384 * 2 / 8, 384 / 8, "SHA3-384");
+sha3_const!(sha3_512_const, 512 * 2 / 8, 512 / 8, "SHA3-512");
+
+macro_rules! shake_const {
+	($shake:ident, $turbo_shake:ident, $capacity:expr, $doc:literal) => {
+		#[doc = concat!("Hashes `buf` with SHAKE", $doc, " into `LEN` bytes.")]
+		pub const fn $shake<const LEN: usize>(buf: &[u8]) -> [u8; LEN] {
+			let mut sponge = Sponge::new($capacity, 24);
+			sponge.absorb(buf);
+			sponge.squeeze(0x1F)
+		}
+
+		#[doc = concat!(
+			"Hashes `buf` with TurboSHAKE", $doc, " and the domain separation byte `domain` into `LEN` ",
+			"bytes."
+		)]
+		#[track_caller]
+		pub const fn $turbo_shake<const LEN: usize>(buf: &[u8], domain: u8) -> [u8; LEN] {
+			assert!(
+				domain >= 0x01 && domain <= 0x7F,
+				"domain separation byte must be in 0x01..=0x7F"
+			);
+
+			let mut sponge = Sponge::new($capacity, 12);
+			sponge.absorb(buf);
+			sponge.squeeze(domain)
+		}
+	};
+}
+
+shake_const!(shake128_const, turbo_shake128_const, 128 * 2 / 8, "128");
+shake_const!(shake256_const, turbo_shake256_const, 256 * 2 / 8, "256");
+
+/// Hashes `buf` with KangarooTwelve (KT128) and the customization string `customization` into
+/// `LEN` bytes.
+pub const fn k12_const<const LEN: usize>(buf: &[u8], customization: &[u8]) -> [u8; LEN] {
+	tree_hash(128 * 2 / 8, 32, buf, customization)
+}
+
+/// Hashes `buf` with KT256 and the customization string `customization` into `LEN` bytes.
+pub const fn kt256_const<const LEN: usize>(buf: &[u8], customization: &[u8]) -> [u8; LEN] {
+	tree_hash(256 * 2 / 8, 64, buf, customization)
+}
+
+const CHUNK_LEN: usize = 8 << 10;
+
+/// The KangarooTwelve tree hash over TurboSHAKE with the given capacity and chaining value length.
+const fn tree_hash<const LEN: usize>(
+	capacity: usize,
+	cv_len: usize,
+	buf: &[u8],
+	customization: &[u8]
+) -> [u8; LEN] {
+	let (encoded, encoded_len) = length_encode(customization.len() as u64);
+	let input = [buf, customization, encoded.split_at(encoded_len).0];
+	let len = buf.len() + customization.len() + encoded_len;
+
+	let mut root = Sponge::new(capacity, 12);
+
+	if len <= CHUNK_LEN {
+		root.absorb_parts(&input, 0, len);
+		return root.squeeze(0x07);
+	}
+
+	root.absorb_parts(&input, 0, CHUNK_LEN);
+	root.absorb(&[0x03, 0, 0, 0, 0, 0, 0, 0]);
+
+	let mut offset = CHUNK_LEN;
+	while offset < len {
+		let end = if len - offset < CHUNK_LEN { len } else { offset + CHUNK_LEN };
+
+		let mut leaf = Sponge::new(capacity, 12);
+		leaf.absorb_parts(&input, offset, end);
+		let cv: [u8; 64] = leaf.squeeze(0x0B);
+		root.absorb(cv.split_at(cv_len).0);
+
+		offset = end;
+	}
+
+	let (encoded, encoded_len) = length_encode(((len - 1) / CHUNK_LEN) as u64);
+	root.absorb(encoded.split_at(encoded_len).0);
+	root.absorb(&[0xFF; 2]);
+	root.squeeze(0x06)
+}
+
+/// `n` in big-endian without leading zeroes, followed by its length in bytes.
+const fn length_encode(n: u64) -> ([u8; 9], usize) {
+	let len = 8 - n.leading_zeros() as usize / 8;
+	let bytes = n.to_be_bytes();
+
+	let mut encoded = [0; 9];
+	let mut i = 0;
+	while i < len {
+		encoded[i] = bytes[8 - len + i];
+		i += 1;
+	}
+	encoded[len] = len as u8;
+
+	(encoded, len + 1)
+}
+
+/// A Keccak-p[1600] sponge on little-endian lanes, with the padding of FIPS 202 and RFC 9861
+/// applied by [`Self::squeeze`] from a delimited suffix byte.
+struct Sponge {
+	state: [u64; 25],
+	rate: usize,
+	rounds: usize,
+	index: usize
+}
+
+impl Sponge {
+	const fn new(capacity: usize, rounds: usize) -> Self {
+		Self { state: [0; 25], rate: 200 - capacity, rounds, index: 0 }
+	}
+
+	const fn absorb(&mut self, buf: &[u8]) {
+		let mut i = 0;
+		while i < buf.len() {
+			self.absorb_u8(buf[i]);
+			i += 1;
+		}
+	}
+
+	/// Absorbs the bytes from `start` to `end` of the concatenation of `parts`.
+	const fn absorb_parts(&mut self, parts: &[&[u8]], mut start: usize, mut end: usize) {
+		let mut i = 0;
+		while i < parts.len() {
+			let part = parts[i];
+
+			while start < end && start < part.len() {
+				self.absorb_u8(part[start]);
+				start += 1;
+			}
+
+			if end <= part.len() {
+				return;
+			}
+
+			start -= part.len();
+			end -= part.len();
+			i += 1;
+		}
+	}
+
+	const fn absorb_u8(&mut self, b: u8) {
+		if self.index == self.rate {
+			self.permute();
+		}
+
+		self.xor_in_u8(self.index, b);
+		self.index += 1;
+	}
+
+	const fn squeeze<const LEN: usize>(mut self, suffix: u8) -> [u8; LEN] {
+		if self.index == self.rate {
+			self.permute();
+		}
+
+		self.xor_in_u8(self.index, suffix);
+		self.xor_in_u8(self.rate - 1, 0x80);
+		self.index = self.rate;
+
+		let mut out = [0; LEN];
+		let mut i = 0;
+		while i < LEN {
+			if self.index == self.rate {
+				self.permute();
+			}
+
+			out[i] = (self.state[self.index / 8] >> (self.index % 8 * 8)) as u8;
+			self.index += 1;
+			i += 1;
+		}
+
+		out
+	}
+
+	const fn xor_in_u8(&mut self, index: usize, b: u8) {
+		self.state[index / 8] ^= (b as u64) << (index % 8 * 8);
+	}
+
+	const fn permute(&mut self) {
+		// The generic backend, with the last `rounds` rounds of Keccak-f[1600].
+		keccakp_1600(&mut self.state, Keccak1600::<24>::RCS.split_at(24 - self.rounds).1);
+		self.index = 0;
+	}
+}
@@ -1,37 +1,119 @@
 use core::{
+	convert::TryFrom,
 	fmt::{self, Debug, Formatter},
 	mem::size_of,
 	ops::Deref
 };
 
-#[derive(Clone, Copy)]
-pub struct RightEncoded<const LEN: usize> {
-	buf: [u8; LEN],
-	offset: u8
+use sponge::Absorb;
+
+macro_rules! encoded {
+	($name:ident) => {
+		#[derive(Clone, Copy)]
+		pub struct $name<const LEN: usize> {
+			buf: [u8; LEN],
+			offset: u8
+		}
+
+		impl<const LEN: usize> Debug for $name<LEN> {
+			fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+				Debug::fmt(&**self, fmt)
+			}
+		}
+
+		impl<const LEN: usize> Deref for $name<LEN> {
+			type Target = [u8];
+
+			fn deref(&self) -> &Self::Target {
+				&self.buf[usize::from(self.offset)..]
+			}
+		}
+
+		impl<const LEN: usize> Eq for $name<LEN> {}
+
+		impl<const LEN: usize> PartialEq for $name<LEN> {
+			fn eq(&self, rhs: &Self) -> bool {
+				**self == **rhs
+			}
+		}
+	};
 }
 
-impl<const LEN: usize> Debug for RightEncoded<LEN> {
-	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-		Debug::fmt(&**self, fmt)
+encoded!(LeftEncoded);
+encoded!(RightEncoded);
+
+impl<const LEN: usize> LeftEncoded<LEN> {
+	/// Encodes an integer given as big-endian bytes, such as a bit length too large for `u128`. The
+	/// value must fit in 255 bytes, i.e. be at most 2^2040 - 1.
+	#[track_caller]
+	pub fn from_be_bytes(bytes: &[u8]) -> Self {
+		let bytes = trim_leading_zeros(bytes);
+		let bytes = if bytes.is_empty() { &[0][..] } else { bytes };
+
+		assert!(bytes.len() <= usize::from(u8::MAX), "value must be less than 2^2040");
+		assert!(bytes.len() < LEN && LEN <= 256, "value does not fit the encoding buffer");
+
+		let offset = LEN - bytes.len() - 1;
+
+		let mut buf = [0; LEN];
+		buf[offset] = bytes.len() as u8;
+		buf[offset + 1..].copy_from_slice(bytes);
+
+		Self { buf, offset: offset as u8 }
 	}
 }
 
-impl<const LEN: usize> Deref for RightEncoded<LEN> {
-	type Target = [u8];
+impl<const LEN: usize> RightEncoded<LEN> {
+	/// Encodes an integer given as big-endian bytes, such as a bit length too large for `u128`. The
+	/// value must fit in 255 bytes, i.e. be at most 2^2040 - 1.
+	#[track_caller]
+	pub fn from_be_bytes(bytes: &[u8]) -> Self {
+		let bytes = trim_leading_zeros(bytes);
+
+		assert!(bytes.len() <= usize::from(u8::MAX), "value must be less than 2^2040");
+		assert!(bytes.len() < LEN && LEN <= 256, "value does not fit the encoding buffer");
+
+		let offset = LEN - bytes.len() - 1;
 
-	fn deref(&self) -> &Self::Target {
-		&self.buf[usize::from(self.offset)..]
+		let mut buf = [0; LEN];
+		buf[offset..LEN - 1].copy_from_slice(bytes);
+		buf[LEN - 1] = bytes.len() as u8;
+
+		Self { buf, offset: offset as u8 }
 	}
-}
 
-impl<const LEN: usize> Eq for RightEncoded<LEN> {}
+	/// Encodes zero as `00 01` like SP 800-185, rather than the bare `00` used by KangarooTwelve.
+	pub fn sp800_185(mut self) -> Self {
+		if usize::from(self.offset) == LEN - 1 {
+			self.buf[LEN - 1] = 1;
+			self.offset -= 1;
+		}
 
-impl<const LEN: usize> PartialEq for RightEncoded<LEN> {
-	fn eq(&self, rhs: &Self) -> bool {
-		**self == **rhs
+		self
 	}
 }
 
+macro_rules! left_encode {
+	($($ty:ident),*) => { $(
+		impl From<$ty> for LeftEncoded<{ size_of::<$ty>() + 1 }> {
+			fn from(i: $ty) -> Self {
+				let mut buf = [0; size_of::<$ty>() + 1];
+				buf[1..].copy_from_slice(&i.to_be_bytes());
+
+				let zeros = (i.leading_zeros() as usize / 8).min(size_of::<$ty>() - 1);
+				buf[zeros] = (size_of::<$ty>() - zeros) as u8;
+
+				debug_assert!(buf[zeros] != 0 && buf[zeros + 1..].len() == usize::from(buf[zeros]));
+				debug_assert!(buf[..zeros].iter().all(|&i| i == 0));
+
+				Self { buf, offset: zeros as u8 }
+			}
+		}
+	)* };
+}
+
+left_encode!(u8, u16, u32, u64, u128, usize);
+
 macro_rules! right_encode {
 	($($ty:ident),*) => { $(
 		impl From<$ty> for RightEncoded<{ size_of::<$ty>() + 1 }> {
@@ -54,11 +136,232 @@ macro_rules! right_encode {
 
 right_encode!(u8, u16, u32, u64, u128, usize);
 
+/// Absorbs `encode_string(buf)` from SP 800-185: the bit length of `buf` followed by `buf` itself.
+pub fn encode_string<A>(absorb: &mut A, buf: &[u8])
+where
+	A: Absorb + ?Sized
+{
+	absorb.absorb(&LeftEncoded::from(buf.len() as u128 * 8));
+	absorb.absorb(buf);
+}
+
+/// Absorbs `bytepad(X, w)` from SP 800-185, where `X` is whatever is absorbed into the `Bytepad`
+/// before calling [`Bytepad::finish`].
+#[derive(Debug)]
+#[must_use = "the padding is only absorbed by `finish`"]
+pub struct Bytepad<'a, A>
+where
+	A: ?Sized
+{
+	absorb: &'a mut A,
+	width: usize,
+	len: usize
+}
+
+impl<'a, A> Bytepad<'a, A>
+where
+	A: Absorb + ?Sized
+{
+	#[track_caller]
+	#[must_use = "the padding is only absorbed by `finish`"]
+	pub fn new(absorb: &'a mut A, width: usize) -> Self {
+		assert!(width != 0, "width must be non-zero");
+
+		let prefix = LeftEncoded::from(width);
+		absorb.absorb(&prefix);
+
+		Self { absorb, width, len: prefix.len() }
+	}
+
+	/// Pads what has been absorbed so far with zeroes to a multiple of the width.
+	pub fn finish(self) {
+		self.absorb.absorb_zeroes((self.width - self.len % self.width) % self.width);
+	}
+}
+
+impl<A> Absorb for Bytepad<'_, A>
+where
+	A: Absorb + ?Sized
+{
+	fn absorb(&mut self, buf: &[u8]) {
+		self.absorb.absorb(buf);
+		self.len = self.len.wrapping_add(buf.len());
+	}
+
+	fn absorb_u8(&mut self, b: u8) {
+		self.absorb.absorb_u8(b);
+		self.len = self.len.wrapping_add(1);
+	}
+
+	fn absorb_zeroes(&mut self, len: usize) {
+		self.absorb.absorb_zeroes(len);
+		self.len = self.len.wrapping_add(len);
+	}
+}
+
+/// Splits a `left_encode`d integer off the front of `buf`, returning its big-endian bytes and the
+/// remainder. Non-minimal encodings are rejected.
+pub fn left_decode_bytes(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+	let (&len, buf) = buf.split_first()?;
+	let len = usize::from(len);
+
+	if len == 0 || buf.len() < len || (len > 1 && buf[0] == 0) {
+		return None;
+	}
+
+	Some(buf.split_at(len))
+}
+
+/// Splits a `right_encode`d integer off the back of `buf`, returning the preceding bytes and the
+/// integer's big-endian bytes. Both the KangarooTwelve encoding of zero, `00`, and the SP 800-185
+/// one, `00 01`, are accepted. Non-minimal encodings are rejected.
+pub fn right_decode_bytes(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+	let (&len, buf) = buf.split_last()?;
+	let len = usize::from(len);
+
+	if buf.len() < len {
+		return None;
+	}
+
+	let (buf, bytes) = buf.split_at(buf.len() - len);
+
+	if len > 1 && bytes[0] == 0 {
+		return None;
+	}
+
+	Some((buf, bytes))
+}
+
+/// Splits a `left_encode`d integer off the front of `buf`, returning it and the remainder.
+pub fn left_decode<T>(buf: &[u8]) -> Option<(T, &[u8])>
+where
+	T: TryFrom<u128>
+{
+	let (bytes, buf) = left_decode_bytes(buf)?;
+	Some((from_be_bytes(bytes)?, buf))
+}
+
+/// Splits a `right_encode`d integer off the back of `buf`, returning the preceding bytes and it.
+pub fn right_decode<T>(buf: &[u8]) -> Option<(&[u8], T)>
+where
+	T: TryFrom<u128>
+{
+	let (buf, bytes) = right_decode_bytes(buf)?;
+	Some((buf, from_be_bytes(bytes)?))
+}
+
+/// Splits an `encode_string`d byte string off the front of `buf`, returning it and the remainder.
+/// Strings whose bit length is not a multiple of eight are rejected.
+pub fn decode_string(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+	let (bits, buf) = left_decode::<u128>(buf)?;
+
+	if bits % 8 != 0 {
+		return None;
+	}
+
+	let len = usize::try_from(bits / 8).ok()?;
+
+	if buf.len() < len {
+		return None;
+	}
+
+	Some(buf.split_at(len))
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+	&bytes[bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len())..]
+}
+
+fn from_be_bytes<T>(bytes: &[u8]) -> Option<T>
+where
+	T: TryFrom<u128>
+{
+	if bytes.len() > size_of::<u128>() {
+		return None;
+	}
+
+	T::try_from(bytes.iter().fold(0, |i, &b| i << 8 | u128::from(b))).ok()
+}
+
 #[cfg(test)]
 mod tests {
 	use core::convert::TryFrom;
 
-	use super::RightEncoded;
+	use sponge::Absorb;
+
+	use super::*;
+
+	struct Buf {
+		buf: [u8; 512],
+		len: usize
+	}
+
+	impl Buf {
+		fn new() -> Self {
+			Self { buf: [0; 512], len: 0 }
+		}
+	}
+
+	impl Deref for Buf {
+		type Target = [u8];
+
+		fn deref(&self) -> &[u8] {
+			&self.buf[..self.len]
+		}
+	}
+
+	impl Absorb for Buf {
+		fn absorb(&mut self, buf: &[u8]) {
+			self.buf[self.len..][..buf.len()].copy_from_slice(buf);
+			self.len += buf.len();
+		}
+	}
+
+	#[test]
+	fn left_encode() {
+		const TESTS: &[(u128, &[u8])] = &[
+			(0, &[1, 0]),
+			(1, &[1, 1]),
+			(12, &[0x01, 0x0c]),
+			(255, &[1, 255]),
+			(256, &[2, 1, 0]),
+			(65536, &[0x03, 0x01, 0x00, 0x00]),
+			(65538, &[0x03, 0x01, 0x00, 0x02]),
+			(u32::MAX as u128, &[4, 0xFF, 0xFF, 0xFF, 0xFF]),
+			(u64::MAX as u128, &[8, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF])
+		];
+
+		for &(i, left_encoded) in TESTS {
+			if let Ok(i) = u8::try_from(i) {
+				assert_eq!(&*LeftEncoded::from(i), left_encoded);
+			}
+
+			if let Ok(i) = u16::try_from(i) {
+				assert_eq!(&*LeftEncoded::from(i), left_encoded);
+			}
+
+			if let Ok(i) = u32::try_from(i) {
+				assert_eq!(&*LeftEncoded::from(i), left_encoded);
+			}
+
+			if let Ok(i) = u64::try_from(i) {
+				assert_eq!(&*LeftEncoded::from(i), left_encoded);
+			}
+
+			if let Ok(i) = usize::try_from(i) {
+				assert_eq!(&*LeftEncoded::from(i), left_encoded);
+			}
+
+			assert_eq!(&*LeftEncoded::from(i), left_encoded);
+		}
+	}
+
+	#[test]
+	fn right_encode_sp800_185() {
+		assert_eq!(&*RightEncoded::from(0_u8).sp800_185(), &[0, 1]);
+		assert_eq!(&*RightEncoded::from(0_u64).sp800_185(), &[0, 1]);
+		assert_eq!(&*RightEncoded::from(256_u64).sp800_185(), &[1, 0, 2]);
+	}
 
 	#[test]
 	fn right_encode() {
@@ -106,4 +409,103 @@ mod tests {
 			assert_eq!(&*RightEncoded::from(i), right_encoded);
 		}
 	}
+
+	#[test]
+	fn from_be_bytes() {
+		assert_eq!(&*LeftEncoded::<4>::from_be_bytes(&[]), &[1, 0]);
+		assert_eq!(&*LeftEncoded::<4>::from_be_bytes(&[0, 0, 1]), &[1, 1]);
+		assert_eq!(&*RightEncoded::<4>::from_be_bytes(&[0, 0]), &[0]);
+		assert_eq!(&*RightEncoded::<4>::from_be_bytes(&[0, 0]).sp800_185(), &[0, 1]);
+		assert_eq!(&*RightEncoded::<4>::from_be_bytes(&[1, 0, 0]), &[1, 0, 0, 3]);
+		assert_eq!(LeftEncoded::<17>::from_be_bytes(&u128::MAX.to_be_bytes()), u128::MAX.into());
+		assert_eq!(RightEncoded::<9>::from_be_bytes(&12345_u64.to_be_bytes()), 12345_u64.into());
+
+		let max = [0xFF; 255];
+
+		let left = LeftEncoded::<256>::from_be_bytes(&max);
+		assert_eq!(left[0], 255);
+		assert_eq!(left_decode_bytes(&left), Some((&max[..], &[][..])));
+
+		let right = RightEncoded::<256>::from_be_bytes(&max);
+		assert_eq!(right[255], 255);
+		assert_eq!(right_decode_bytes(&right), Some((&[][..], &max[..])));
+	}
+
+	#[test]
+	#[should_panic]
+	fn from_be_bytes_too_long() {
+		let _ = LeftEncoded::<257>::from_be_bytes(&[1; 256]);
+	}
+
+	#[test]
+	fn decode() {
+		for i in [0, 1, 12, 255, 256, 65537, u64::MAX as u128, u128::MAX] {
+			let mut buf = Buf::new();
+			buf.absorb(&LeftEncoded::from(i));
+			buf.absorb(b"rest");
+			assert_eq!(left_decode::<u128>(&buf), Some((i, &b"rest"[..])));
+
+			let mut buf = Buf::new();
+			buf.absorb(b"rest");
+			buf.absorb(&RightEncoded::from(i));
+			assert_eq!(right_decode::<u128>(&buf), Some((&b"rest"[..], i)));
+
+			let mut buf = Buf::new();
+			buf.absorb(&RightEncoded::from(i).sp800_185());
+			assert_eq!(right_decode::<u128>(&buf), Some((&[][..], i)));
+		}
+
+		assert_eq!(left_decode::<u8>(&LeftEncoded::from(256_u16)), None);
+		assert_eq!(left_decode::<u16>(&LeftEncoded::from(256_u16)), Some((256, &[][..])));
+
+		assert_eq!(left_decode_bytes(&[]), None);
+		assert_eq!(left_decode_bytes(&[0]), None);
+		assert_eq!(left_decode_bytes(&[2, 1]), None);
+		assert_eq!(left_decode_bytes(&[2, 0, 1]), None);
+		assert_eq!(right_decode_bytes(&[]), None);
+		assert_eq!(right_decode_bytes(&[1, 2]), None);
+		assert_eq!(right_decode_bytes(&[0, 1, 2]), None);
+	}
+
+	#[test]
+	fn encode_string() {
+		let mut buf = Buf::new();
+		super::encode_string(&mut buf, b"");
+		assert_eq!(&*buf, &[1, 0]);
+
+		let mut buf = Buf::new();
+		super::encode_string(&mut buf, b"KMAC");
+		assert_eq!(&*buf, &[1, 32, b'K', b'M', b'A', b'C']);
+
+		buf.absorb(b"rest");
+		assert_eq!(decode_string(&buf), Some((&b"KMAC"[..], &b"rest"[..])));
+
+		assert_eq!(decode_string(&[1, 9, 0, 0]), None);
+		assert_eq!(decode_string(&[1, 32, 0, 0]), None);
+	}
+
+	#[test]
+	fn bytepad() {
+		let mut buf = Buf::new();
+		let mut bytepad = Bytepad::new(&mut buf, 168);
+		super::encode_string(&mut bytepad, b"");
+		super::encode_string(&mut bytepad, b"Email Signature");
+		bytepad.finish();
+
+		assert_eq!(buf.len(), 168);
+		assert_eq!(&buf[..4], &[1, 168, 1, 0]);
+		assert_eq!(&buf[4..6], &[1, 120]);
+		assert_eq!(&buf[6..21], b"Email Signature");
+		assert!(buf[21..].iter().all(|&b| b == 0));
+
+		for len in [0, 1, 5, 6, 7, 100] {
+			let mut buf = Buf::new();
+			let mut bytepad = Bytepad::new(&mut buf, 6);
+			bytepad.absorb(&[0xAA; 100][..len]);
+			bytepad.finish();
+
+			assert_eq!(buf.len() % 6, 0);
+			assert_eq!(buf.len(), (len + 2).div_ceil(6) * 6);
+		}
+	}
 }
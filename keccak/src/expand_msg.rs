@@ -0,0 +1,127 @@
+//! `expand_message_xof` from RFC 9380 section 5.3.2, over SHAKE128 and SHAKE256.
+//!
+//! With the `hash2curve` feature, `ExpandMsgShake128` and `ExpandMsgShake256` implement
+//! `elliptic_curve::hash2curve::ExpandMsg` for use with `hash_to_field`.
+
+use sponge::{state::Lsbu64, Permutation, State};
+
+use crate::{
+	keccakp::Keccak1600,
+	sha3::{Shake128, Shake128Squeezer, Shake256, Shake256Squeezer}
+};
+
+pub const MAX_OUTPUT_LEN: usize = u16::MAX as usize;
+
+/// The longest DST used as is; longer ones are hashed first as in RFC 9380 section 5.3.3.
+pub const MAX_DST_LEN: usize = u8::MAX as usize;
+
+macro_rules! expand_message_xof {
+	(
+		$fn:ident,
+		$with_state:ident,
+		$expand:ident,
+		$expand_msg:ident,
+		$hash:ident,
+		$squeezer:ident,
+		$dst_len:expr
+	) => {
+		/// Fills `out` with `expand_message_xof(msg, dst, out.len())`.
+		#[track_caller]
+		pub fn $fn(msg: &[u8], dst: &[u8], out: &mut [u8]) {
+			$with_state::<Lsbu64<25>>(msg, dst, out);
+		}
+
+		#[doc = concat!("Like [`", stringify!($fn), "`], over any Keccak state.")]
+		#[track_caller]
+		pub fn $with_state<S>(msg: &[u8], dst: &[u8], out: &mut [u8])
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			assert!(out.len() <= MAX_OUTPUT_LEN, "requested output is too long");
+			assert!(!dst.is_empty(), "DST must not be empty");
+
+			$expand::<S>(&[msg], &[dst], out.len() as u16).squeeze_into(out);
+		}
+
+		/// `expand_message_xof` of the concatenation of `msgs`, with the concatenation of `dsts`
+		/// as the DST, returning the squeezer of its `len`-byte output.
+		fn $expand<S>(msgs: &[&[u8]], dsts: &[&[u8]], len: u16) -> $squeezer<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			let dst_len: usize = dsts.iter().map(|dst| dst.len()).sum();
+
+			let mut hash = $hash::<S>::default();
+			msgs.iter().for_each(|msg| hash.absorb(msg));
+			hash.absorb(&len.to_be_bytes());
+
+			if dst_len > MAX_DST_LEN {
+				let mut oversize = $hash::<S>::default();
+				oversize.absorb(b"H2C-OVERSIZE-DST-");
+				dsts.iter().for_each(|dst| oversize.absorb(dst));
+
+				let mut dst = [0; $dst_len];
+				oversize.squeeze_into(&mut dst);
+				hash.absorb(&dst);
+				hash.absorb(&[dst.len() as u8]);
+			} else {
+				dsts.iter().for_each(|dst| hash.absorb(dst));
+				hash.absorb(&[dst_len as u8]);
+			}
+
+			hash.into_squeezer()
+		}
+
+		#[doc = concat!("`expand_message_xof` over ", stringify!($hash), " for `hash_to_field`.")]
+		#[cfg(feature = "hash2curve")]
+		#[derive(Clone, Copy, Debug)]
+		pub struct $expand_msg($squeezer<Lsbu64<25>>);
+
+		/// Fails if the output is empty or longer than [`MAX_OUTPUT_LEN`], or if the DST is empty.
+		#[cfg(feature = "hash2curve")]
+		impl<'a> elliptic_curve::hash2curve::ExpandMsg<'a> for $expand_msg {
+			type Expander = Self;
+
+			fn expand_message(
+				msgs: &[&[u8]],
+				dsts: &'a [&'a [u8]],
+				len_in_bytes: usize
+			) -> elliptic_curve::Result<Self> {
+				let len = u16::try_from(len_in_bytes).map_err(|_| elliptic_curve::Error)?;
+				if len == 0 || dsts.iter().all(|dst| dst.is_empty()) {
+					return Err(elliptic_curve::Error);
+				}
+
+				Ok(Self($expand::<Lsbu64<25>>(msgs, dsts, len)))
+			}
+		}
+
+		#[cfg(feature = "hash2curve")]
+		impl elliptic_curve::hash2curve::Expander for $expand_msg {
+			fn fill_bytes(&mut self, okm: &mut [u8]) {
+				self.0.squeeze_into(okm);
+			}
+		}
+	};
+}
+
+expand_message_xof!(
+	expand_message_xof_shake128,
+	expand_message_xof_shake128_with_state,
+	expand_shake128,
+	ExpandMsgShake128,
+	Shake128,
+	Shake128Squeezer,
+	2 * 128 / 8
+);
+expand_message_xof!(
+	expand_message_xof_shake256,
+	expand_message_xof_shake256_with_state,
+	expand_shake256,
+	ExpandMsgShake256,
+	Shake256,
+	Shake256Squeezer,
+	2 * 256 / 8
+);
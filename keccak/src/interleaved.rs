@@ -0,0 +1,264 @@
+//! Bit-interleaved Keccak-f[1600] for 32-bit targets.
+//!
+//! Each 64-bit lane is stored as two `u32` words, the first holding its even bits and the second
+//! its odd bits, so that every lane rotation becomes two 32-bit rotations.
+
+use core::ops::Range;
+
+use sponge::{Permutation, State};
+
+use crate::keccakp::Keccak1600;
+
+/// A sponge state of `LEN` bit-interleaved 64-bit lanes.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interleaved<const LEN: usize>([[u32; 2]; LEN]);
+
+impl<const LEN: usize> Interleaved<LEN> {
+	pub fn from_lanes(lanes: [u64; LEN]) -> Self {
+		Self(lanes.map(interleave))
+	}
+
+	pub fn into_lanes(self) -> [u64; LEN] {
+		self.0.map(deinterleave)
+	}
+
+	/// Calls `f` with the little-endian bytes of each lane overlapping `index..index + len`, the
+	/// range of them that overlaps it and its offset from `index`.
+	#[inline]
+	#[track_caller]
+	fn for_each_lane<F>(&self, index: usize, len: usize, mut f: F)
+	where
+		F: FnMut(&[u8; 8], Range<usize>, usize)
+	{
+		let mut offset = 0;
+
+		while offset < len {
+			let (lane, start) = ((index + offset) / 8, (index + offset) % 8);
+			let end = 8.min(start + len - offset);
+
+			f(&deinterleave(self.0[lane]).to_le_bytes(), start..end, offset);
+			offset += end - start;
+		}
+	}
+
+	/// Like [`Self::for_each_lane`], but writes the bytes back to the lanes afterwards.
+	#[inline]
+	#[track_caller]
+	fn update_each_lane<F>(&mut self, index: usize, len: usize, mut f: F)
+	where
+		F: FnMut(&mut [u8; 8], Range<usize>, usize)
+	{
+		let mut offset = 0;
+
+		while offset < len {
+			let (lane, start) = ((index + offset) / 8, (index + offset) % 8);
+			let end = 8.min(start + len - offset);
+
+			let mut bytes = deinterleave(self.0[lane]).to_le_bytes();
+			f(&mut bytes, start..end, offset);
+			self.0[lane] = interleave(u64::from_le_bytes(bytes));
+
+			offset += end - start;
+		}
+	}
+}
+
+impl<const LEN: usize> Default for Interleaved<LEN> {
+	#[inline]
+	fn default() -> Self {
+		Self([[0; 2]; LEN])
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<const LEN: usize> zeroize::DefaultIsZeroes for Interleaved<LEN> {}
+
+impl<const LEN: usize> State for Interleaved<LEN> {
+	const LEN: usize = LEN * 8;
+	type Inner = [[u32; 2]; LEN];
+
+	#[inline]
+	fn from_inner(inner: Self::Inner) -> Self {
+		Self(inner)
+	}
+
+	#[inline]
+	#[track_caller]
+	fn get_u8_slice(&self, index: usize, buf: &mut [u8]) {
+		self.for_each_lane(index, buf.len(), |bytes, range, offset| {
+			buf[offset..][..range.len()].copy_from_slice(&bytes[range]);
+		});
+	}
+
+	#[inline]
+	#[track_caller]
+	fn xor_out_u8_slice(&self, index: usize, buf: &mut [u8]) {
+		self.for_each_lane(index, buf.len(), |bytes, range, offset| {
+			for (dst, &src) in buf[offset..].iter_mut().zip(&bytes[range]) {
+				*dst ^= src;
+			}
+		});
+	}
+
+	#[inline]
+	#[track_caller]
+	fn set_u8_slice(&mut self, index: usize, buf: &[u8]) {
+		self.update_each_lane(index, buf.len(), |bytes, range, offset| {
+			bytes[range.clone()].copy_from_slice(&buf[offset..][..range.len()]);
+		});
+	}
+
+	#[inline]
+	#[track_caller]
+	fn set_u8_repeated(&mut self, index: usize, b: u8, len: usize) {
+		self.update_each_lane(index, len, |bytes, range, _| bytes[range].fill(b));
+	}
+
+	#[inline]
+	#[track_caller]
+	fn xor_in_u8_slice(&mut self, index: usize, buf: &[u8]) {
+		self.update_each_lane(index, buf.len(), |bytes, range, offset| {
+			for (dst, &src) in bytes[range].iter_mut().zip(&buf[offset..]) {
+				*dst ^= src;
+			}
+		});
+	}
+
+	#[inline]
+	#[track_caller]
+	fn xor_in_u8(&mut self, index: usize, b: u8) {
+		let [even, odd] = interleave(u64::from(b) << (index % 8 * 8));
+		let lane = &mut self.0[index / 8];
+		lane[0] ^= even;
+		lane[1] ^= odd;
+	}
+
+	#[inline]
+	fn permute<P>(&mut self)
+	where
+		P: Permutation<Self::Inner>
+	{
+		P::permute(&mut self.0);
+	}
+}
+
+impl<const ROUNDS: usize> Keccak1600<ROUNDS> {
+	pub const RCS_INTERLEAVED: [[u32; 2]; ROUNDS] = {
+		let mut rcs = [[0; 2]; ROUNDS];
+
+		let mut i = 0;
+		while i < ROUNDS {
+			rcs[i] = interleave(Self::RCS[i]);
+			i += 1;
+		}
+
+		rcs
+	};
+}
+
+impl<const ROUNDS: usize> Permutation<[[u32; 2]; 25]> for Keccak1600<ROUNDS> {
+	#[inline]
+	fn permute(state: &mut [[u32; 2]; 25]) {
+		keccakp_1600_interleaved(state, &Self::RCS_INTERLEAVED);
+	}
+}
+
+/// Splits `x` into its even and odd bits.
+pub const fn interleave(mut x: u64) -> [u32; 2] {
+	let mut t;
+	t = (x ^ (x >> 1)) & 0x2222_2222_2222_2222;
+	x ^= t ^ (t << 1);
+	t = (x ^ (x >> 2)) & 0x0C0C_0C0C_0C0C_0C0C;
+	x ^= t ^ (t << 2);
+	t = (x ^ (x >> 4)) & 0x00F0_00F0_00F0_00F0;
+	x ^= t ^ (t << 4);
+	t = (x ^ (x >> 8)) & 0x0000_FF00_0000_FF00;
+	x ^= t ^ (t << 8);
+	t = (x ^ (x >> 16)) & 0x0000_0000_FFFF_0000;
+	x ^= t ^ (t << 16);
+
+	[x as u32, (x >> 32) as u32]
+}
+
+/// Inverse of [`interleave`].
+pub const fn deinterleave([even, odd]: [u32; 2]) -> u64 {
+	let mut x = even as u64 | (odd as u64) << 32;
+
+	let mut t;
+	t = (x ^ (x >> 16)) & 0x0000_0000_FFFF_0000;
+	x ^= t ^ (t << 16);
+	t = (x ^ (x >> 8)) & 0x0000_FF00_0000_FF00;
+	x ^= t ^ (t << 8);
+	t = (x ^ (x >> 4)) & 0x00F0_00F0_00F0_00F0;
+	x ^= t ^ (t << 4);
+	t = (x ^ (x >> 2)) & 0x0C0C_0C0C_0C0C_0C0C;
+	x ^= t ^ (t << 2);
+	t = (x ^ (x >> 1)) & 0x2222_2222_2222_2222;
+	x ^= t ^ (t << 1);
+
+	x
+}
+
+/// Rotates the bit-interleaved lane `[even, odd]` left by `n` bits.
+#[inline(always)]
+const fn rotate_left([even, odd]: [u32; 2], n: u32) -> [u32; 2] {
+	if n.is_multiple_of(2) {
+		[even.rotate_left(n / 2), odd.rotate_left(n / 2)]
+	} else {
+		[odd.rotate_left(n / 2 + 1), even.rotate_left(n / 2)]
+	}
+}
+
+#[inline(always)]
+const fn xor([a0, a1]: [u32; 2], [b0, b1]: [u32; 2]) -> [u32; 2] {
+	[a0 ^ b0, a1 ^ b1]
+}
+
+/// Applies Keccak-p[1600] with the interleaved round constants `rcs` to the bit-interleaved `a`.
+pub fn keccakp_1600_interleaved(a: &mut [[u32; 2]; 25], rcs: &[[u32; 2]]) {
+	const RHO: [u32; 24] =
+		[1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44];
+
+	const PI: [usize; 24] =
+		[10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1];
+
+	for &rc in rcs {
+		// theta
+		let mut b = [[0; 2]; 5];
+
+		for x in 0..5 {
+			for y in 0..5 {
+				b[x] = xor(b[x], a[x + y * 5]);
+			}
+		}
+
+		for x in 0..5 {
+			let d = xor(b[(x + 4) % 5], rotate_left(b[(x + 1) % 5], 1));
+
+			for y in 0..5 {
+				a[x + y * 5] = xor(a[x + y * 5], d);
+			}
+		}
+
+		// rho and pi
+		let mut t1 = a[1];
+		for i in 0..24 {
+			let t2 = a[PI[i]];
+			a[PI[i]] = rotate_left(t1, RHO[i]);
+			t1 = t2;
+		}
+
+		// chi
+		for y in 0..5 {
+			let b: [[u32; 2]; 5] = core::array::from_fn(|x| a[x + y * 5]);
+
+			for x in 0..5 {
+				let [b1, b2] = [b[(x + 1) % 5], b[(x + 2) % 5]];
+				a[x + y * 5] = xor(b[x], [!b1[0] & b2[0], !b1[1] & b2[1]]);
+			}
+		}
+
+		// iota
+		a[0] = xor(a[0], rc);
+	}
+}
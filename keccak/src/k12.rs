@@ -1,139 +1,148 @@
-use core::mem;
-
-use sponge::{state::Lsbu64, suffix, Absorb, IntoSqueezer, Permutation, State};
-
-use crate::{
-	encode::RightEncoded,
-	keccakp::Keccak1600,
-	sha3::{TurboShake128, TurboShake128Squeezer}
+use sponge::{
+	sakura::{Kangaroo, Tree},
+	sponge::Squeezer,
+	state::Lsbu64,
+	Absorb, IntoSqueezer, Permutation, State
 };
 
+use crate::{encode::RightEncoded, keccakp::Keccak1600, parallel};
+
 const CHUNK_LEN: usize = 8 << 10;
 
+/// The Sakura tree layout shared by the KangarooTwelve family.
+type Layout<S, P, const CAPACITY: usize, const CV_LEN: usize> =
+	Tree<S, P, Kangaroo, CAPACITY, CHUNK_LEN, CV_LEN>;
+
+/// KangarooTwelve-style tree hashing over a Keccak-p sponge with the given capacity, producing
+/// leaf chaining values of `CV_LEN` bytes.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct KangarooTwelve<S> {
-	root: TurboShake128<S>,
-	leaf: TurboShake128<S>,
-	chunk: u64,
-	length: usize
+pub struct TreeHash<S, P, const CAPACITY: usize, const CV_LEN: usize>(
+	Layout<S, P, CAPACITY, CV_LEN>
+);
+
+/// KT128 from RFC 9861, on TurboSHAKE128 with 32-byte chaining values.
+pub type Kt128<S> = TreeHash<S, Keccak1600<12>, { 128 * 2 / 8 }, 32>;
+
+/// KT256 from RFC 9861, on TurboSHAKE256 with 64-byte chaining values.
+pub type Kt256<S> = TreeHash<S, Keccak1600<12>, { 256 * 2 / 8 }, 64>;
+
+pub type KangarooTwelve<S> = Kt128<S>;
+
+/// MarsupilamiFourteen from the KangarooTwelve paper, the 256-bit security sibling of
+/// KangarooTwelve using 14 rounds and 64-byte chaining values.
+pub type MarsupilamiFourteen<S> = TreeHash<S, Keccak1600<14>, { 256 * 2 / 8 }, 64>;
+
+impl Kt128<Lsbu64<25>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl Kt256<Lsbu64<25>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
 }
 
-impl KangarooTwelve<Lsbu64<25>> {
+impl MarsupilamiFourteen<Lsbu64<25>> {
 	pub fn new() -> Self {
 		Self::default()
 	}
 }
 
-impl<S> KangarooTwelve<S>
+/// Hashes `buf` with KangarooTwelve in one go, hashing its leaves on worker threads.
+#[cfg(feature = "std")]
+pub fn hash_parallel(buf: &[u8]) -> Squeezer<Lsbu64<25>, Keccak1600<12>, { 128 * 2 / 8 }> {
+	let mut hash = KangarooTwelve::new();
+	hash.absorb(buf);
+	hash.into_squeezer()
+}
+
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
 	pub fn absorb_u8(&mut self, b: u8) {
-		debug_assert!(self.length <= CHUNK_LEN);
-
-		if self.chunk == 0 {
-			if self.length == CHUNK_LEN {
-				self.switch_to_chaining();
-			} else {
-				self.root.absorb_u8(b);
-				self.length += 1;
-				return;
-			}
-		}
-
-		debug_assert!(self.chunk != 0);
-		debug_assert!(self.length <= CHUNK_LEN);
-
-		if self.length == CHUNK_LEN {
-			self.absorb_chaining_value();
-		}
+		self.0.absorb_u8(b);
+	}
 
-		debug_assert!(self.length < CHUNK_LEN);
-		self.leaf.absorb_u8(b);
-		self.length += 1;
-		debug_assert!(self.length <= CHUNK_LEN);
+	pub fn absorb(&mut self, buf: &[u8]) {
+		self.0.absorb_with(buf, |leaves, sink| {
+			parallel::hash_leaves(leaves, CHUNK_LEN, Self::hash_leaves, sink);
+		});
 	}
 
-	pub fn absorb(&mut self, mut buf: &[u8]) {
-		if buf.is_empty() {
-			return;
-		}
+	pub fn into_squeezer(mut self) -> Squeezer<S, P, CAPACITY> {
+		self.absorb_u8(0);
+		self.0.into_squeezer()
+	}
 
-		debug_assert!(self.length <= CHUNK_LEN);
+	pub fn into_squeezer_customized(mut self, customization: &[u8]) -> Squeezer<S, P, CAPACITY> {
+		self.absorb(customization);
+		self.absorb(&RightEncoded::from(customization.len()));
+		self.0.into_squeezer()
+	}
 
-		if self.chunk == 0 {
-			let (l, r) = buf.split_at(buf.len().min(CHUNK_LEN - self.length));
-			buf = r;
+	/// Hashes consecutive complete chunks as leaves, four or two at a time in lockstep where
+	/// possible.
+	fn hash_leaves(leaves: &[u8], cvs: &mut [[u8; CV_LEN]]) {
+		debug_assert_eq!(leaves.len(), cvs.len() * CHUNK_LEN);
 
-			self.root.absorb(l);
-			self.length += l.len();
-			debug_assert!(self.length <= CHUNK_LEN);
+		let mut leaves = leaves.chunks_exact(4 * CHUNK_LEN);
+		let mut cvs = cvs.chunks_exact_mut(4);
 
-			if !buf.is_empty() {
-				self.switch_to_chaining();
-			}
+		for (leaves, cvs) in (&mut leaves).zip(&mut cvs) {
+			cvs.copy_from_slice(&Self::hash_chunks::<4>(leaves));
 		}
 
-		while !buf.is_empty() {
-			debug_assert!(self.chunk != 0);
-			debug_assert!(self.length <= CHUNK_LEN);
+		let (mut leaves, mut cvs) = (leaves.remainder(), cvs.into_remainder());
 
-			if self.length == CHUNK_LEN {
-				self.absorb_chaining_value();
-			}
+		if cvs.len() >= 2 {
+			let (l, r) = leaves.split_at(2 * CHUNK_LEN);
+			leaves = r;
 
-			debug_assert!(self.length < CHUNK_LEN);
-			let (l, r) = buf.split_at(buf.len().min(CHUNK_LEN - self.length));
-			buf = r;
+			let (l_cvs, r_cvs) = cvs.split_at_mut(2);
+			cvs = r_cvs;
 
-			self.leaf.absorb(l);
-			self.length += l.len();
-			debug_assert!(self.length <= CHUNK_LEN);
+			l_cvs.copy_from_slice(&Self::hash_chunks::<2>(l));
 		}
-	}
 
-	pub fn into_squeezer(mut self) -> TurboShake128Squeezer<S> {
-		self.absorb_u8(0);
-		self.into_squeezer_impl()
+		for (leaf, cv) in leaves.chunks(CHUNK_LEN).zip(cvs) {
+			*cv = Layout::<S, P, CAPACITY, CV_LEN>::hash_leaf(leaf);
+		}
 	}
 
-	pub fn into_squeezer_customized(mut self, customization: &[u8]) -> TurboShake128Squeezer<S> {
-		self.absorb(customization);
-		self.absorb(&RightEncoded::from(customization.len()));
-		self.into_squeezer_impl()
-	}
+	/// Hashes `N` complete chunks as leaves, permuting their states together with
+	/// [`State::permute_multi`].
+	fn hash_chunks<const N: usize>(leaves: &[u8]) -> [[u8; CV_LEN]; N] {
+		debug_assert_eq!(leaves.len(), N * CHUNK_LEN);
 
-	fn switch_to_chaining(&mut self) {
-		debug_assert!(self.chunk == 0);
-		debug_assert!(self.length == CHUNK_LEN);
+		let rate = S::LEN - CAPACITY;
+		let full = CHUNK_LEN - CHUNK_LEN % rate;
+		let mut states: [S; N] = core::array::from_fn(|_| S::default());
 
-		self.root.absorb(&[suffix!(1).into(), 0, 0, 0, 0, 0, 0, 0]);
-		self.chunk = 1;
-		self.length = 0;
-	}
+		for offset in (0..full).step_by(rate) {
+			for (state, leaf) in states.iter_mut().zip(leaves.chunks(CHUNK_LEN)) {
+				state.xor_in_u8_slice(0, &leaf[offset..][..rate]);
+			}
 
-	fn absorb_chaining_value(&mut self) {
-		debug_assert!(self.chunk != 0);
-		debug_assert!(self.length <= CHUNK_LEN);
+			S::permute_multi::<P, N>(&mut states);
+		}
 
-		self.root.absorb(&mem::take(&mut self.leaf).squeeze::<32>(suffix!(1, 1, 0)));
-		self.chunk += 1;
-		self.length = 0;
-	}
+		for (state, leaf) in states.iter_mut().zip(leaves.chunks(CHUNK_LEN)) {
+			state.xor_in_u8_slice(0, &leaf[full..]);
+			state.xor_in_u8(CHUNK_LEN - full, Layout::<S, P, CAPACITY, CV_LEN>::LEAF_SUFFIX.into());
+			state.xor_in_u8(rate - 1, 0x80);
+		}
 
-	fn into_squeezer_impl(mut self) -> TurboShake128Squeezer<S> {
-		let suffix = if self.chunk == 0 {
-			suffix!(1, 1)
-		} else {
-			debug_assert!(self.length != 0);
-			self.absorb_chaining_value();
-			self.root.absorb(&RightEncoded::from(self.chunk - 1));
-			self.root.absorb(&[0xFF; 2]);
-			suffix!(0, 1)
-		};
+		S::permute_multi::<P, N>(&mut states);
 
-		self.root.into_squeezer(suffix)
+		states.each_ref().map(|state| {
+			let mut cv = [0; CV_LEN];
+			state.get_u8_slice(0, &mut cv);
+			cv
+		})
 	}
 
 	pub fn squeeze_into(self, buf: &mut [u8]) {
@@ -153,32 +162,33 @@ where
 	}
 }
 
-impl<S> Default for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Default for TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
 	fn default() -> Self {
-		Self { root: TurboShake128::default(), leaf: TurboShake128::default(), chunk: 0, length: 0 }
+		Self(Layout::default())
 	}
 }
 
-impl<S> Absorb for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Absorb for TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
 	fn absorb(&mut self, buf: &[u8]) {
 		self.absorb(buf);
 	}
 }
 
-impl<S> IntoSqueezer for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> IntoSqueezer
+	for TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
-	type Squeezer = TurboShake128Squeezer<S>;
+	type Squeezer = Squeezer<S, P, CAPACITY>;
 
 	fn into_squeezer(self) -> Self::Squeezer {
 		self.into_squeezer()
@@ -186,10 +196,11 @@ where
 }
 
 #[cfg(feature = "digest")]
-impl<S> digest::Update for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Update
+	for TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
 	fn update(&mut self, buf: &[u8]) {
 		self.absorb(buf);
@@ -197,22 +208,365 @@ where
 }
 
 #[cfg(feature = "digest")]
-impl<S> digest::Reset for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Reset
+	for TreeHash<S, P, CAPACITY, CV_LEN>
 where
 	S: State,
-	Keccak1600<12>: Permutation<S::Inner>
+	P: Permutation<S::Inner>
 {
 	fn reset(&mut self) {
 		*self = Self::default();
 	}
 }
 
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutput
+	for TreeHash<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Reader = Squeezer<S, P, CAPACITY>;
+
+	fn finalize_xof(self) -> Self::Reader {
+		self.into_squeezer()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutputReset
+	for TreeHash<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn finalize_xof_reset(&mut self) -> Self::Reader {
+		core::mem::take(self).into_squeezer()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::HashMarker
+	for TreeHash<S, P, CAPACITY, CV_LEN>
+{
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> zeroize::Zeroize
+	for TreeHash<S, P, CAPACITY, CV_LEN>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+/// A [`TreeHash`] with a customization string, which is absorbed after the message.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Customized<'a, S, P, const CAPACITY: usize, const CV_LEN: usize> {
+	hash: TreeHash<S, P, CAPACITY, CV_LEN>,
+	customization: &'a [u8]
+}
+
+impl<'a, S, P, const CAPACITY: usize, const CV_LEN: usize> TreeHash<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	pub fn with_customization(customization: &'a [u8]) -> Customized<'a, S, P, CAPACITY, CV_LEN> {
+		Customized { hash: Self::default(), customization }
+	}
+}
+
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	pub fn absorb(&mut self, buf: &[u8]) {
+		self.hash.absorb(buf);
+	}
+
+	pub fn into_squeezer(self) -> Squeezer<S, P, CAPACITY> {
+		self.hash.into_squeezer_customized(self.customization)
+	}
+
+	pub fn squeeze_into(self, buf: &mut [u8]) {
+		self.into_squeezer().squeeze_into(buf);
+	}
+
+	pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+		self.into_squeezer().squeeze()
+	}
+}
+
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Absorb
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn absorb(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> IntoSqueezer
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Squeezer = Squeezer<S, P, CAPACITY>;
+
+	fn into_squeezer(self) -> Self::Squeezer {
+		self.into_squeezer()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Update
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn update(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Reset
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn reset(&mut self) {
+		self.hash = TreeHash::default();
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutput
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Reader = Squeezer<S, P, CAPACITY>;
+
+	fn finalize_xof(self) -> Self::Reader {
+		self.into_squeezer()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutputReset
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn finalize_xof_reset(&mut self) -> Self::Reader {
+		let hash = core::mem::take(&mut self.hash);
+		hash.into_squeezer_customized(self.customization)
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::HashMarker
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
+{
+}
+
 #[cfg(feature = "zeroize")]
-impl<S> zeroize::Zeroize for KangarooTwelve<S>
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> zeroize::Zeroize
+	for Customized<'_, S, P, CAPACITY, CV_LEN>
 where
 	S: zeroize::Zeroize
 {
 	fn zeroize(&mut self) {
-		self.root.zeroize();
+		self.hash.zeroize();
+	}
+}
+
+/// A [`TreeHash`] that collects small writes into a buffer of at most `capacity` bytes, so that
+/// whole batches of leaves can be hashed on worker threads.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Buffered<S, P, const CAPACITY: usize, const CV_LEN: usize> {
+	hash: TreeHash<S, P, CAPACITY, CV_LEN>,
+	buf: std::vec::Vec<u8>,
+	capacity: usize
+}
+
+#[cfg(feature = "std")]
+pub type BufferedKangarooTwelve<S> = Buffered<S, Keccak1600<12>, { 128 * 2 / 8 }, 32>;
+
+#[cfg(feature = "std")]
+impl BufferedKangarooTwelve<Lsbu64<25>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(feature = "std")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	pub const DEFAULT_CAPACITY: usize = 512 * CHUNK_LEN;
+
+	#[track_caller]
+	pub fn with_capacity(capacity: usize) -> Self {
+		assert!(capacity >= CHUNK_LEN, "capacity must hold at least one chunk");
+
+		Self {
+			hash: TreeHash::default(),
+			buf: std::vec::Vec::with_capacity(capacity),
+			capacity: capacity - capacity % CHUNK_LEN
+		}
+	}
+
+	pub fn absorb(&mut self, mut buf: &[u8]) {
+		while !buf.is_empty() {
+			if self.buf.is_empty() && buf.len() >= self.capacity {
+				let (l, r) = buf.split_at(buf.len() - buf.len() % CHUNK_LEN);
+				buf = r;
+
+				self.hash.absorb(l);
+				continue;
+			}
+
+			let (l, r) = buf.split_at(buf.len().min(self.capacity - self.buf.len()));
+			buf = r;
+
+			self.buf.extend_from_slice(l);
+
+			if self.buf.len() == self.capacity {
+				self.flush();
+			}
+		}
+	}
+
+	pub fn into_squeezer(mut self) -> Squeezer<S, P, CAPACITY> {
+		self.flush();
+		self.hash.into_squeezer()
+	}
+
+	pub fn into_squeezer_customized(mut self, customization: &[u8]) -> Squeezer<S, P, CAPACITY> {
+		self.flush();
+		self.hash.into_squeezer_customized(customization)
 	}
+
+	pub fn squeeze_into(self, buf: &mut [u8]) {
+		self.into_squeezer().squeeze_into(buf);
+	}
+
+	pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+		self.into_squeezer().squeeze()
+	}
+
+	fn flush(&mut self) {
+		self.hash.absorb(&self.buf);
+		self.buf.clear();
+	}
+}
+
+#[cfg(feature = "std")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Default for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn default() -> Self {
+		Self::with_capacity(Self::DEFAULT_CAPACITY)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> Absorb for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn absorb(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+#[cfg(feature = "std")]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> IntoSqueezer
+	for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Squeezer = Squeezer<S, P, CAPACITY>;
+
+	fn into_squeezer(self) -> Self::Squeezer {
+		self.into_squeezer()
+	}
+}
+
+#[cfg(all(feature = "std", feature = "digest"))]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Update
+	for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn update(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+#[cfg(all(feature = "std", feature = "digest"))]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::Reset
+	for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn reset(&mut self) {
+		self.hash = TreeHash::default();
+		self.buf.clear();
+	}
+}
+
+#[cfg(all(feature = "std", feature = "digest"))]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutput
+	for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Reader = Squeezer<S, P, CAPACITY>;
+
+	fn finalize_xof(self) -> Self::Reader {
+		self.into_squeezer()
+	}
+}
+
+#[cfg(all(feature = "std", feature = "digest"))]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::ExtendableOutputReset
+	for Buffered<S, P, CAPACITY, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn finalize_xof_reset(&mut self) -> Self::Reader {
+		self.flush();
+		core::mem::take(&mut self.hash).into_squeezer()
+	}
+}
+
+#[cfg(all(feature = "std", feature = "digest"))]
+impl<S, P, const CAPACITY: usize, const CV_LEN: usize> digest::HashMarker
+	for Buffered<S, P, CAPACITY, CV_LEN>
+{
 }
@@ -0,0 +1,260 @@
+//! HMAC and HKDF (RFC 2104 and RFC 5869) over SHA3, and the KMAC-based key derivation function
+//! from SP 800-108r1.
+
+use sponge::{state::Lsbu64, Absorb, Permutation, Squeeze, State};
+
+use crate::{
+	keccakp::Keccak1600,
+	kmac::{Kmac128, Kmac256},
+	sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512}
+};
+
+macro_rules! hmac {
+	(
+		$hmac:ident,
+		$hkdf:ident,
+		$hash:ident,
+		$hash_len:expr,
+		$block_len:expr,
+		$typenum:ident,
+		$block_typenum:ident
+	) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $hmac<S> {
+			inner: $hash<S>,
+			outer: $hash<S>
+		}
+
+		impl $hmac<Lsbu64<25>> {
+			pub fn new(key: &[u8]) -> Self {
+				Self::with_key(key)
+			}
+		}
+
+		impl<S> $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			pub fn with_key(key: &[u8]) -> Self {
+				let mut block = [0; $block_len];
+
+				if key.len() > $block_len {
+					let mut hash = $hash::<S>::default();
+					hash.absorb(key);
+					hash.squeeze_into(&mut block[..$hash_len]);
+				} else {
+					block[..key.len()].copy_from_slice(key);
+				}
+
+				// The pads are made in place so that no copy of the key is left behind.
+				block.iter_mut().for_each(|b| *b ^= 0x36);
+				let mut inner = $hash::default();
+				inner.absorb(&block);
+
+				block.iter_mut().for_each(|b| *b ^= 0x36 ^ 0x5C);
+				let mut outer = $hash::default();
+				outer.absorb(&block);
+
+				#[cfg(feature = "zeroize")]
+				zeroize::Zeroize::zeroize(&mut block);
+
+				Self { inner, outer }
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.inner.absorb(buf);
+			}
+
+			pub fn squeeze(self) -> [u8; $hash_len] {
+				let Self { inner, mut outer } = self;
+				outer.absorb(&inner.squeeze());
+				outer.squeeze()
+			}
+		}
+
+		impl<S> Absorb for $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> Squeeze for $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			#[track_caller]
+			fn squeeze_into(self, buf: &mut [u8]) {
+				buf.copy_from_slice(&self.squeeze());
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::crypto_common::KeySizeUser for $hmac<S> {
+			type KeySize = digest::consts::$block_typenum;
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::KeyInit for $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn new(key: &digest::Key<Self>) -> Self {
+				Self::with_key(key)
+			}
+
+			fn new_from_slice(key: &[u8]) -> Result<Self, digest::InvalidLength> {
+				Ok(Self::with_key(key))
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::OutputSizeUser for $hmac<S> {
+			type OutputSize = digest::consts::$typenum;
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::FixedOutput for $hmac<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn finalize_into(self, out: &mut digest::Output<Self>) {
+				out.copy_from_slice(&self.squeeze());
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::MacMarker for $hmac<S> {}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $hmac<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.inner.zeroize();
+				self.outer.zeroize();
+			}
+		}
+
+		/// HKDF, holding the pseudorandom key as an HMAC keyed with it.
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $hkdf<S>($hmac<S>);
+
+		impl $hkdf<Lsbu64<25>> {
+			pub fn new(salt: &[u8], ikm: &[u8]) -> Self {
+				Self::with_ikm(salt, ikm)
+			}
+		}
+
+		impl<S> $hkdf<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			pub const MAX_OUTPUT_LEN: usize = 255 * $hash_len;
+
+			/// HKDF-Extract, returning the pseudorandom key.
+			pub fn extract(salt: &[u8], ikm: &[u8]) -> [u8; $hash_len] {
+				// An empty salt and one of `$hash_len` zeroes pad to the same HMAC key.
+				let mut hmac = $hmac::<S>::with_key(salt);
+				hmac.absorb(ikm);
+				hmac.squeeze()
+			}
+
+			pub fn with_ikm(salt: &[u8], ikm: &[u8]) -> Self {
+				Self::from_prk(&Self::extract(salt, ikm))
+			}
+
+			#[track_caller]
+			pub fn from_prk(prk: &[u8]) -> Self {
+				assert!(prk.len() >= $hash_len, "pseudorandom key is shorter than the hash output");
+				Self($hmac::with_key(prk))
+			}
+
+			/// HKDF-Expand, filling `okm` with output keying material.
+			#[track_caller]
+			pub fn expand(&self, info: &[u8], okm: &mut [u8])
+			where
+				S: Clone
+			{
+				assert!(okm.len() <= Self::MAX_OUTPUT_LEN, "output keying material is too long");
+
+				let mut t = [0; $hash_len];
+
+				for (i, chunk) in okm.chunks_mut($hash_len).enumerate() {
+					let mut hmac = self.0.clone();
+					if i != 0 {
+						hmac.absorb(&t);
+					}
+					hmac.absorb(info);
+					hmac.absorb(&[i as u8 + 1]);
+					t = hmac.squeeze();
+
+					chunk.copy_from_slice(&t[..chunk.len()]);
+				}
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $hkdf<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+	};
+}
+
+hmac!(HmacSha3_224, HkdfSha3_224, Sha3_224, 224 / 8, 200 - 224 * 2 / 8, U28, U144);
+hmac!(HmacSha3_256, HkdfSha3_256, Sha3_256, 256 / 8, 200 - 256 * 2 / 8, U32, U136);
+hmac!(HmacSha3_384, HkdfSha3_384, Sha3_384, 384 / 8, 200 - 384 * 2 / 8, U48, U104);
+hmac!(HmacSha3_512, HkdfSha3_512, Sha3_512, 512 / 8, 200 - 512 * 2 / 8, U64, U72);
+
+macro_rules! kmac_kdf {
+	($fn:ident, $with_state:ident, $kmac:ident) => {
+		/// KDF in SP 800-108r1 section 4.4, filling `out` with `KMAC(key, context, out.len() * 8,
+		/// label)`.
+		#[track_caller]
+		pub fn $fn(key: &[u8], label: &[u8], context: &[u8], out: &mut [u8]) {
+			$with_state::<Lsbu64<25>>(key, label, context, out);
+		}
+
+		#[doc = concat!("Like [`", stringify!($fn), "`], over any Keccak state.")]
+		#[track_caller]
+		pub fn $with_state<S>(key: &[u8], label: &[u8], context: &[u8], out: &mut [u8])
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			assert!(!out.is_empty(), "derived key must be non-empty");
+
+			let mut kmac = $kmac::<S>::with_key(key, label);
+			kmac.absorb(context);
+			kmac.squeeze_into(out);
+		}
+	};
+}
+
+kmac_kdf!(kmac128_kdf, kmac128_kdf_with_state, Kmac128);
+kmac_kdf!(kmac256_kdf, kmac256_kdf_with_state, Kmac256);
@@ -1,5 +1,15 @@
 use sponge::Permutation;
 
+pub use self::{
+	backend::Backend,
+	simd::{keccakp_1600_x2, keccakp_1600_x4},
+	unrolled::keccakp_1600_unrolled
+};
+
+mod backend;
+mod simd;
+mod unrolled;
+
 macro_rules! permutation {
 	($name:ident, $lane:ty, $make_rcs:ident, $keccakf:ident) => {
 		#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -21,31 +31,66 @@ macro_rules! permutation {
 permutation!(Keccak200, u8, make_rcs_u8, keccakp_200);
 permutation!(Keccak400, u16, make_rcs_u16, keccakp_400);
 permutation!(Keccak800, u32, make_rcs_u32, keccakp_800);
-permutation!(Keccak1600, u64, make_rcs_u64, keccakp_1600);
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Keccak1600<const ROUNDS: usize>;
+
+impl<const ROUNDS: usize> Keccak1600<ROUNDS> {
+	pub const RCS: [u64; ROUNDS] = make_rcs_u64::<ROUNDS>();
+}
+
+impl<const ROUNDS: usize> Permutation<[u64; 25]> for Keccak1600<ROUNDS> {
+	#[inline]
+	fn permute(state: &mut [u64; 25]) {
+		keccakp_1600_dispatch(state, &Self::RCS);
+	}
+
+	/// Permutes the states in lockstep where the selected backend can, see
+	/// [`Backend::keccakp_1600_multi`].
+	#[inline]
+	fn permute_multi<const N: usize>(states: &mut [[u64; 25]; N]) {
+		Backend::current().keccakp_1600_multi(states, &Self::RCS);
+	}
+}
+
+/// Applies Keccak-p[1600] with the backend selected at runtime, see [`Backend`].
+#[inline]
+fn keccakp_1600_dispatch(a: &mut [u64; 25], rcs: &[u64]) {
+	Backend::current().keccakp_1600(a, rcs);
+}
 
 macro_rules! keccakp {
 	($name:ident, $lane:ty) => {
+		/// Written with `while` loops so that it can also run at compile time, see
+		/// [`const_hash`](crate::const_hash).
 		#[inline(always)]
-		pub fn $name(a: &mut [$lane; 5 * 5], rcs: &[$lane]) {
+		pub const fn $name(a: &mut [$lane; 5 * 5], rcs: &[$lane]) {
 			#[inline(always)]
-			fn theta(a: &mut [$lane; 5 * 5]) {
+			const fn theta(a: &mut [$lane; 5 * 5]) {
 				let mut b = [0; 5];
 
-				for x in 0..5 {
-					for y in 0..5 {
-						b[x] ^= a[x + y * 5];
-					}
+				let mut x = 0;
+				while x < 5 {
+					b[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+					x += 1;
 				}
 
-				for x in 0..5 {
-					for y in 0..5 {
-						a[x + y * 5] ^= b[(x + 4) % 5] ^ b[(x + 1) % 5].rotate_left(1);
+				let mut x = 0;
+				while x < 5 {
+					let d = b[(x + 4) % 5] ^ b[(x + 1) % 5].rotate_left(1);
+
+					let mut y = 0;
+					while y < 5 {
+						a[x + y * 5] ^= d;
+						y += 1;
 					}
+
+					x += 1;
 				}
 			}
 
 			#[inline(always)]
-			fn rho_and_pi(a: &mut [$lane; 5 * 5]) {
+			const fn rho_and_pi(a: &mut [$lane; 5 * 5]) {
 				const RHO: [u32; 24] = [
 					1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39,
 					61, 20, 44
@@ -57,35 +102,40 @@ macro_rules! keccakp {
 				];
 
 				let mut t1 = a[1];
-				for i in 0..24 {
+				let mut i = 0;
+				while i < 24 {
 					let t2 = a[PI[i]];
 					a[PI[i]] = t1.rotate_left(RHO[i]);
 					t1 = t2;
+					i += 1;
 				}
 			}
 
 			#[inline(always)]
-			fn chi(a: &mut [$lane; 5 * 5]) {
-				for y in 0..5 {
-					let mut b = [0; 5];
+			const fn chi(a: &mut [$lane; 5 * 5]) {
+				let mut y = 0;
+				while y < 5 {
+					let b = [a[y * 5], a[y * 5 + 1], a[y * 5 + 2], a[y * 5 + 3], a[y * 5 + 4]];
 
-					for x in 0..5 {
-						b[x] = a[x + y * 5];
-					}
-
-					for x in 0..5 {
+					let mut x = 0;
+					while x < 5 {
 						a[x + y * 5] = b[x] ^ (!b[(x + 1) % 5] & b[(x + 2) % 5]);
+						x += 1;
 					}
+
+					y += 1;
 				}
 			}
 
-			for rc in rcs {
+			let mut i = 0;
+			while i < rcs.len() {
 				theta(a);
 				rho_and_pi(a);
 				chi(a);
 
 				// iota
-				a[0] ^= rc;
+				a[0] ^= rcs[i];
+				i += 1;
 			}
 		}
 	};
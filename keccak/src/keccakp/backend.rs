@@ -0,0 +1,171 @@
+//! Runtime selection of the Keccak-p[1600] implementation behind [`Keccak1600`].
+//!
+//! The CPU is probed once, on the first permutation, and the fastest available backend is used
+//! from then on. With the `std` feature the `KECCAK_BACKEND` environment variable can name the
+//! backend to use instead, unless it is unknown or unsupported, and [`Backend::force`] overrides
+//! the choice at any time.
+//!
+//! [`Keccak1600`]: super::Keccak1600
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(target_arch = "x86_64")]
+use super::simd::{keccakp_1600_x2_sse2, keccakp_1600_x4_avx2};
+use super::{keccakp_1600, unrolled::keccakp_1600_unrolled};
+
+#[cfg(target_arch = "x86_64")]
+#[allow(deprecated_in_future)]
+pub(super) mod cpuid {
+	cpufeatures::new!(avx2, "avx2");
+	cpufeatures::new!(bmi2, "bmi1", "bmi2");
+
+	pub(in crate::keccakp) fn has_avx2() -> bool {
+		avx2::get()
+	}
+
+	pub(in crate::keccakp) fn has_bmi2() -> bool {
+		bmi2::get()
+	}
+}
+
+const UNSELECTED: u8 = u8::MAX;
+
+static SELECTED: AtomicU8 = AtomicU8::new(UNSELECTED);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Backend {
+	/// The portable loop-based reference, [`keccakp_1600`].
+	Generic,
+	/// The portable unrolled, lane-complementing [`keccakp_1600_unrolled`].
+	Unrolled,
+	/// The reference compiled for BMI1 and BMI2, using `andn` for chi and `rorx` for the
+	/// rotations. Several states are permuted in lockstep with AVX2, or SSE2 without it.
+	#[cfg(target_arch = "x86_64")]
+	Bmi2
+}
+
+impl Backend {
+	/// Every backend compiled for this target, whether the CPU supports it or not.
+	pub const ALL: &'static [Self] = &[
+		Self::Generic,
+		Self::Unrolled,
+		#[cfg(target_arch = "x86_64")]
+		Self::Bmi2
+	];
+
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Generic => "generic",
+			Self::Unrolled => "unrolled",
+			#[cfg(target_arch = "x86_64")]
+			Self::Bmi2 => "bmi2"
+		}
+	}
+
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::ALL.iter().copied().find(|backend| backend.name() == name)
+	}
+
+	pub fn is_available(self) -> bool {
+		match self {
+			Self::Generic | Self::Unrolled => true,
+			#[cfg(target_arch = "x86_64")]
+			Self::Bmi2 => cpuid::has_bmi2()
+		}
+	}
+
+	/// The fastest backend the CPU supports.
+	pub fn best() -> Self {
+		#[cfg(target_arch = "x86_64")]
+		if Self::Bmi2.is_available() {
+			return Self::Bmi2;
+		}
+
+		if cfg!(any(feature = "unrolled", target_pointer_width = "64")) {
+			Self::Unrolled
+		} else {
+			Self::Generic
+		}
+	}
+
+	/// The backend used by [`Keccak1600`](super::Keccak1600), selecting it first if needed.
+	#[inline]
+	pub fn current() -> Self {
+		match SELECTED.load(Ordering::Relaxed) {
+			UNSELECTED => Self::select(),
+			i => Self::ALL[usize::from(i)]
+		}
+	}
+
+	/// Makes [`Keccak1600`](super::Keccak1600) use this backend from now on.
+	#[track_caller]
+	pub fn force(self) {
+		assert!(self.is_available(), "the {} Keccak-p backend is not supported", self.name());
+		SELECTED.store(self as u8, Ordering::Relaxed);
+	}
+
+	/// Applies Keccak-p[1600] with the round constants `rcs` to `a` with this backend.
+	#[inline]
+	#[track_caller]
+	pub fn keccakp_1600(self, a: &mut [u64; 25], rcs: &[u64]) {
+		match self {
+			Self::Generic => keccakp_1600(a, rcs),
+			Self::Unrolled => keccakp_1600_unrolled(a, rcs),
+			#[cfg(target_arch = "x86_64")]
+			Self::Bmi2 => {
+				assert!(cpuid::has_bmi2(), "the bmi2 Keccak-p backend is not supported");
+				// SAFETY: BMI1 and BMI2 support was just checked.
+				unsafe { keccakp_1600_bmi2(a, rcs) }
+			}
+		}
+	}
+
+	/// Applies Keccak-p[1600] with the round constants `rcs` to all `states` with this backend.
+	#[inline]
+	#[track_caller]
+	pub fn keccakp_1600_multi<const N: usize>(self, states: &mut [[u64; 25]; N], rcs: &[u64]) {
+		#[cfg(target_arch = "x86_64")]
+		if self == Self::Bmi2 {
+			let mut rest = &mut states[..];
+			if cpuid::has_avx2() {
+				let mut x4 = rest.chunks_exact_mut(4);
+				for states in &mut x4 {
+					// SAFETY: AVX2 support was just checked.
+					unsafe { keccakp_1600_x4_avx2(states.try_into().unwrap(), rcs) };
+				}
+				rest = x4.into_remainder();
+			}
+
+			let mut x2 = rest.chunks_exact_mut(2);
+			for states in &mut x2 {
+				// SAFETY: SSE2 is part of the x86_64 baseline.
+				unsafe { keccakp_1600_x2_sse2(states.try_into().unwrap(), rcs) };
+			}
+			rest = x2.into_remainder();
+
+			rest.iter_mut().for_each(|state| self.keccakp_1600(state, rcs));
+			return;
+		}
+
+		states.iter_mut().for_each(|state| self.keccakp_1600(state, rcs));
+	}
+
+	#[cold]
+	fn select() -> Self {
+		#[cfg(feature = "std")]
+		let requested = std::env::var("KECCAK_BACKEND").ok().and_then(|name| Self::from_name(&name));
+		#[cfg(not(feature = "std"))]
+		let requested = None;
+
+		let backend = requested.filter(|backend| backend.is_available()).unwrap_or_else(Self::best);
+		SELECTED.store(backend as u8, Ordering::Relaxed);
+		backend
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi1,bmi2")]
+unsafe fn keccakp_1600_bmi2(a: &mut [u64; 25], rcs: &[u64]) {
+	keccakp_1600(a, rcs);
+}
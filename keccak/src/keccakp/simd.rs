@@ -0,0 +1,189 @@
+//! Keccak-p[1600] over several independent states in lockstep, with one SIMD lane per state.
+
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::Backend;
+
+/// `RHO[x + 5 * y]` is the rotation applied to lane `(x, y)`.
+#[cfg(target_arch = "x86_64")]
+const RHO: [u32; 25] =
+	[0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14];
+
+/// `PI[x + 5 * y]` is the index lane `(x, y)` moves to, `(y, 2 * x + 3 * y)`.
+#[cfg(target_arch = "x86_64")]
+const PI: [usize; 25] =
+	[0, 10, 20, 5, 15, 16, 1, 11, 21, 6, 7, 17, 2, 12, 22, 23, 8, 18, 3, 13, 14, 24, 9, 19, 4];
+
+/// Applies Keccak-p[1600] with the round constants `rcs` to both states with the selected
+/// backend, see [`Backend::keccakp_1600_multi`].
+pub fn keccakp_1600_x2(states: &mut [[u64; 25]; 2], rcs: &[u64]) {
+	Backend::current().keccakp_1600_multi(states, rcs);
+}
+
+/// Applies Keccak-p[1600] with the round constants `rcs` to all four states with the selected
+/// backend, see [`Backend::keccakp_1600_multi`].
+pub fn keccakp_1600_x4(states: &mut [[u64; 25]; 4], rcs: &[u64]) {
+	Backend::current().keccakp_1600_multi(states, rcs);
+}
+
+/// Expands to the rounds of Keccak-p[1600] over `$a`, an array of 25 vectors with one 64-bit
+/// lane per state, using the named intrinsics. It is expanded inside each `#[target_feature]`
+/// kernel so that the intrinsics are only ever called where their features are enabled.
+#[cfg(target_arch = "x86_64")]
+macro_rules! keccakp {
+	(
+		$a:ident, $rcs:ident,
+		set1: $set1:ident, xor: $xor:ident, andnot: $andnot:ident, or: $or:ident,
+		sll: $sll:ident, srl: $srl:ident
+	) => {
+		for &rc in $rcs {
+			// theta
+			let mut c = [$a[0]; 5];
+			for x in 0..5 {
+				c[x] = $xor($xor($xor($a[x], $a[x + 5]), $xor($a[x + 10], $a[x + 15])), $a[x + 20]);
+			}
+
+			// rho and pi
+			let mut b = [$a[0]; 25];
+			for x in 0..5 {
+				let c1 = c[(x + 1) % 5];
+				let rotated = $or($sll(c1, _mm_cvtsi32_si128(1)), $srl(c1, _mm_cvtsi32_si128(63)));
+				let d = $xor(c[(x + 4) % 5], rotated);
+
+				for y in 0..5 {
+					let i = x + 5 * y;
+					let lane = $xor($a[i], d);
+					let n = RHO[i] as i32;
+					b[PI[i]] = $or(
+						$sll(lane, _mm_cvtsi32_si128(n)),
+						$srl(lane, _mm_cvtsi32_si128(64 - n))
+					);
+				}
+			}
+
+			// chi
+			for y in 0..5 {
+				for x in 0..5 {
+					let i = 5 * y;
+					$a[x + i] = $xor(b[x + i], $andnot(b[(x + 1) % 5 + i], b[(x + 2) % 5 + i]));
+				}
+			}
+
+			// iota
+			$a[0] = $xor($a[0], $set1(rc as i64));
+		}
+	};
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+pub(super) unsafe fn keccakp_1600_x2_sse2(states: &mut [[u64; 25]; 2], rcs: &[u64]) {
+	let [a, b] = states;
+
+	let mut lanes = [_mm_setzero_si128(); 25];
+	for (i, lane) in lanes.iter_mut().enumerate() {
+		*lane = _mm_set_epi64x(b[i] as i64, a[i] as i64);
+	}
+
+	keccakp!(
+		lanes, rcs,
+		set1: _mm_set1_epi64x, xor: _mm_xor_si128, andnot: _mm_andnot_si128, or: _mm_or_si128,
+		sll: _mm_sll_epi64, srl: _mm_srl_epi64
+	);
+
+	for (i, lane) in lanes.iter().enumerate() {
+		let mut words = [0_u64; 2];
+		// SAFETY: `words` is 16 bytes long and `_mm_storeu_si128` has no alignment requirement.
+		unsafe { _mm_storeu_si128(words.as_mut_ptr().cast(), *lane) };
+		[a[i], b[i]] = words;
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn keccakp_1600_x4_avx2(states: &mut [[u64; 25]; 4], rcs: &[u64]) {
+	let [a, b, c, d] = states;
+
+	let mut lanes = [_mm256_setzero_si256(); 25];
+	for (i, lane) in lanes.iter_mut().enumerate() {
+		*lane = _mm256_set_epi64x(d[i] as i64, c[i] as i64, b[i] as i64, a[i] as i64);
+	}
+
+	keccakp!(
+		lanes, rcs,
+		set1: _mm256_set1_epi64x, xor: _mm256_xor_si256, andnot: _mm256_andnot_si256,
+		or: _mm256_or_si256, sll: _mm256_sll_epi64, srl: _mm256_srl_epi64
+	);
+
+	for (i, lane) in lanes.iter().enumerate() {
+		let mut words = [0_u64; 4];
+		// SAFETY: `words` is 32 bytes long and `_mm256_storeu_si256` has no alignment requirement.
+		unsafe { _mm256_storeu_si256(words.as_mut_ptr().cast(), *lane) };
+		[a[i], b[i], c[i], d[i]] = words;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::keccakp::{keccakp_1600, Backend, Keccak1600};
+
+	fn states<const N: usize>(seed: u64) -> [[u64; 25]; N] {
+		let mut x = seed;
+		[[0; 25]; N].map(|state| {
+			state.map(|_| {
+				x = x
+					.wrapping_mul(6_364_136_223_846_793_005)
+					.wrapping_add(1_442_695_040_888_963_407);
+				x
+			})
+		})
+	}
+
+	fn check<const N: usize>(permute: impl Fn(&mut [[u64; 25]; N], &[u64])) {
+		for rounds in [1, 12, 14, 24] {
+			let rcs = &Keccak1600::<24>::RCS[24 - rounds..];
+			let mut states = states::<N>(rounds as u64);
+			let mut expected = states;
+
+			for _ in 0..3 {
+				permute(&mut states, rcs);
+				expected.iter_mut().for_each(|state| keccakp_1600(state, rcs));
+				assert_eq!(states, expected, "rounds = {rounds}");
+			}
+		}
+	}
+
+	#[test]
+	fn portable() {
+		check::<3>(|states, rcs| Backend::Generic.keccakp_1600_multi(states, rcs));
+	}
+
+	#[test]
+	fn x2() {
+		check(super::keccakp_1600_x2);
+	}
+
+	#[test]
+	fn x4() {
+		check(super::keccakp_1600_x4);
+	}
+
+	#[cfg(target_arch = "x86_64")]
+	#[test]
+	fn x2_sse2() {
+		// SAFETY: SSE2 is part of the x86_64 baseline.
+		check(|states, rcs| unsafe { super::keccakp_1600_x2_sse2(states, rcs) });
+	}
+
+	#[cfg(target_arch = "x86_64")]
+	#[test]
+	fn x4_avx2() {
+		if !crate::keccakp::backend::cpuid::has_avx2() {
+			return;
+		}
+
+		// SAFETY: AVX2 support was just detected.
+		check(|states, rcs| unsafe { super::keccakp_1600_x4_avx2(states, rcs) });
+	}
+}
@@ -0,0 +1,103 @@
+//! Keccak-p[1600] with each round fully unrolled and the lane complementing transform, after the
+//! XKCP "opt64" implementation.
+//!
+//! Complementing lanes 1, 2, 8, 12, 17 and 20 on entry and exit lets chi be computed with a single
+//! NOT per plane instead of five.
+
+/// Lanes kept complemented while the rounds run.
+const COMPLEMENTED: [usize; 6] = [1, 2, 8, 12, 17, 20];
+
+/// Applies Keccak-p[1600] with the round constants `rcs` to `a`.
+#[inline]
+pub fn keccakp_1600_unrolled(a: &mut [u64; 25], rcs: &[u64]) {
+	for i in COMPLEMENTED {
+		a[i] = !a[i];
+	}
+
+	let mut pairs = rcs.chunks_exact(2);
+	for pair in &mut pairs {
+		round(a, pair[0]);
+		round(a, pair[1]);
+	}
+
+	for &rc in pairs.remainder() {
+		round(a, rc);
+	}
+
+	for i in COMPLEMENTED {
+		a[i] = !a[i];
+	}
+}
+
+#[inline(always)]
+fn round(a: &mut [u64; 25], rc: u64) {
+	let [aba, abe, abi, abo, abu, aga, age, agi, ago, agu, aka, ake, aki, ako, aku, ama, ame, ami, amo, amu, asa, ase, asi, aso, asu] =
+		*a;
+
+	let ca = aba ^ aga ^ aka ^ ama ^ asa;
+	let ce = abe ^ age ^ ake ^ ame ^ ase;
+	let ci = abi ^ agi ^ aki ^ ami ^ asi;
+	let co = abo ^ ago ^ ako ^ amo ^ aso;
+	let cu = abu ^ agu ^ aku ^ amu ^ asu;
+
+	let da = cu ^ ce.rotate_left(1);
+	let de = ca ^ ci.rotate_left(1);
+	let di = ce ^ co.rotate_left(1);
+	let d_o = ci ^ cu.rotate_left(1);
+	let du = co ^ ca.rotate_left(1);
+
+	let bba = aba ^ da;
+	let bbe = (age ^ de).rotate_left(44);
+	let bbi = (aki ^ di).rotate_left(43);
+	let bbo = (amo ^ d_o).rotate_left(21);
+	let bbu = (asu ^ du).rotate_left(14);
+	a[0] = bba ^ (bbe | bbi) ^ rc;
+	a[1] = bbe ^ (!bbi | bbo);
+	a[2] = bbi ^ (bbo & bbu);
+	a[3] = bbo ^ (bbu | bba);
+	a[4] = bbu ^ (bba & bbe);
+
+	let bga = (abo ^ d_o).rotate_left(28);
+	let bge = (agu ^ du).rotate_left(20);
+	let bgi = (aka ^ da).rotate_left(3);
+	let bgo = (ame ^ de).rotate_left(45);
+	let bgu = (asi ^ di).rotate_left(61);
+	a[5] = bga ^ (bge | bgi);
+	a[6] = bge ^ (bgi & bgo);
+	a[7] = bgi ^ (bgo | !bgu);
+	a[8] = bgo ^ (bgu | bga);
+	a[9] = bgu ^ (bga & bge);
+
+	let bka = (abe ^ de).rotate_left(1);
+	let bke = (agi ^ di).rotate_left(6);
+	let bki = (ako ^ d_o).rotate_left(25);
+	let bko = (amu ^ du).rotate_left(8);
+	let bku = (asa ^ da).rotate_left(18);
+	a[10] = bka ^ (bke | bki);
+	a[11] = bke ^ (bki & bko);
+	a[12] = bki ^ (!bko & bku);
+	a[13] = !bko ^ (bku | bka);
+	a[14] = bku ^ (bka & bke);
+
+	let bma = (abu ^ du).rotate_left(27);
+	let bme = (aga ^ da).rotate_left(36);
+	let bmi = (ake ^ de).rotate_left(10);
+	let bmo = (ami ^ di).rotate_left(15);
+	let bmu = (aso ^ d_o).rotate_left(56);
+	a[15] = bma ^ (bme & bmi);
+	a[16] = bme ^ (bmi | bmo);
+	a[17] = bmi ^ (!bmo | bmu);
+	a[18] = !bmo ^ (bmu & bma);
+	a[19] = bmu ^ (bma | bme);
+
+	let bsa = (abi ^ di).rotate_left(62);
+	let bse = (ago ^ d_o).rotate_left(55);
+	let bsi = (aku ^ du).rotate_left(39);
+	let bso = (ama ^ da).rotate_left(41);
+	let bsu = (ase ^ de).rotate_left(2);
+	a[20] = bsa ^ (!bse & bsi);
+	a[21] = !bse ^ (bsi | bso);
+	a[22] = bsi ^ (bso & bsu);
+	a[23] = bso ^ (bsu | bsa);
+	a[24] = bsu ^ (bsa & bse);
+}
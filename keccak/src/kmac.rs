@@ -0,0 +1,195 @@
+use sponge::{state::Lsbu64, Absorb, IntoSqueezer, Permutation, Squeeze, State};
+
+use crate::{
+	encode::{encode_string, Bytepad, RightEncoded},
+	keccakp::Keccak1600,
+	sha3::{CShake128, CShake256, Shake128Squeezer, Shake256Squeezer}
+};
+
+macro_rules! kmac {
+	($name:ident, $xof:ident, $cshake:ident, $squeezer:ident, $rate:expr) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $name<S>($cshake<S>);
+
+		impl $name<Lsbu64<25>> {
+			pub fn new(key: &[u8], customization: &[u8]) -> Self {
+				Self::with_key(key, customization)
+			}
+		}
+
+		impl<S> $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			pub fn with_key(key: &[u8], customization: &[u8]) -> Self {
+				let mut cshake = $cshake::with_customization(b"KMAC", customization);
+
+				let mut bytepad = Bytepad::new(&mut cshake, $rate);
+				encode_string(&mut bytepad, key);
+				bytepad.finish();
+
+				Self(cshake)
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.0.absorb(buf);
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				let len = buf.len() as u128 * 8;
+				self.into_squeezer_impl(len).squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				let mut buf = [0; LEN];
+				self.squeeze_into(&mut buf);
+				buf
+			}
+
+			pub fn into_xof(self) -> $xof<S> {
+				$xof(self)
+			}
+
+			fn into_squeezer_impl(mut self, len: u128) -> $squeezer<S> {
+				self.0.absorb(&RightEncoded::from(len).sp800_185());
+				self.0.into_squeezer()
+			}
+		}
+
+		impl<S> Absorb for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> Squeeze for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn squeeze_into(self, buf: &mut [u8]) {
+				self.squeeze_into(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $name<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $xof<S>($name<S>);
+
+		impl $xof<Lsbu64<25>> {
+			pub fn new(key: &[u8], customization: &[u8]) -> Self {
+				Self::with_key(key, customization)
+			}
+		}
+
+		impl<S> $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			pub fn with_key(key: &[u8], customization: &[u8]) -> Self {
+				Self($name::with_key(key, customization))
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.0.absorb(buf);
+			}
+
+			pub fn into_squeezer(self) -> $squeezer<S> {
+				self.0.into_squeezer_impl(0)
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				self.into_squeezer().squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				self.into_squeezer().squeeze()
+			}
+		}
+
+		impl<S> Absorb for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> IntoSqueezer for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Squeezer = $squeezer<S>;
+
+			fn into_squeezer(self) -> Self::Squeezer {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutput for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $xof<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+	};
+}
+
+kmac!(Kmac128, KmacXof128, CShake128, Shake128Squeezer, 200 - 128 * 2 / 8);
+kmac!(Kmac256, KmacXof256, CShake256, Shake256Squeezer, 200 - 256 * 2 / 8);
@@ -21,7 +21,26 @@
 	unused_results
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+// Only used by the benchmarks and integration tests.
+#[cfg(test)]
+use {criterion as _, hkdf as _, hmac as _};
+
+#[cfg(feature = "acvp")]
+pub mod acvp;
+pub mod const_hash;
 pub mod encode;
+pub mod expand_msg;
+pub mod interleaved;
 pub mod k12;
+pub mod kdf;
 pub mod keccakp;
+pub mod kmac;
+pub mod multi;
+pub mod parallel_hash;
 pub mod sha3;
+pub mod stream;
+
+mod parallel;
@@ -0,0 +1,106 @@
+//! SHAKE128 and SHA3-256 of four independent messages at once, with the four Keccak-p[1600]
+//! states permuted in lockstep.
+
+use sponge::{
+	multi::{MultiSponge, MultiSqueezer},
+	state::Lsbu64,
+	suffix, Permutation, State
+};
+
+use crate::keccakp::Keccak1600;
+
+pub type Shake128x4Squeezer<S> = MultiSqueezer<S, Keccak1600<24>, 4, { 128 * 2 / 8 }>;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Shake128x4<S>(MultiSponge<S, Keccak1600<24>, 4, { 128 * 2 / 8 }>);
+
+impl Shake128x4<Lsbu64<25>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl<S> Shake128x4<S>
+where
+	S: State + Clone,
+	Keccak1600<24>: Permutation<S::Inner>
+{
+	/// Absorbs `bufs[i]` into the `i`th message.
+	pub fn absorb(&mut self, bufs: [&[u8]; 4]) {
+		self.0.absorb(bufs);
+	}
+
+	pub fn into_squeezer(self) -> Shake128x4Squeezer<S> {
+		self.0.into_squeezer(suffix!(1, 1, 1, 1))
+	}
+
+	pub fn squeeze_into(self, bufs: [&mut [u8]; 4]) {
+		self.into_squeezer().squeeze_into(bufs);
+	}
+
+	pub fn squeeze<const LEN: usize>(self) -> [[u8; LEN]; 4] {
+		self.into_squeezer().squeeze()
+	}
+}
+
+impl<S> Default for Shake128x4<S>
+where
+	S: State
+{
+	fn default() -> Self {
+		Self(MultiSponge::default())
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S> zeroize::Zeroize for Shake128x4<S>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Sha3_256x4<S>(MultiSponge<S, Keccak1600<24>, 4, { 256 * 2 / 8 }>);
+
+impl Sha3_256x4<Lsbu64<25>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl<S> Sha3_256x4<S>
+where
+	S: State + Clone,
+	Keccak1600<24>: Permutation<S::Inner>
+{
+	/// Absorbs `bufs[i]` into the `i`th message.
+	pub fn absorb(&mut self, bufs: [&[u8]; 4]) {
+		self.0.absorb(bufs);
+	}
+
+	pub fn squeeze(self) -> [[u8; 256 / 8]; 4] {
+		self.0.squeeze(suffix!(0, 1))
+	}
+}
+
+impl<S> Default for Sha3_256x4<S>
+where
+	S: State
+{
+	fn default() -> Self {
+		Self(MultiSponge::default())
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S> zeroize::Zeroize for Sha3_256x4<S>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
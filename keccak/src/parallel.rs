@@ -0,0 +1,141 @@
+#[cfg(feature = "std")]
+use std::{num::NonZeroUsize, thread, vec};
+
+/// Minimum number of input bytes worth handing to a worker thread.
+#[cfg(feature = "std")]
+const MIN_THREAD_LEN: usize = 256 << 10;
+
+/// Upper bound on the leaves hashed per thread in one batch, which bounds the chaining values
+/// buffered while waiting for the batch to complete.
+#[cfg(feature = "std")]
+const MAX_THREAD_LEAVES: usize = 1 << 14;
+
+/// Number of leaves handed to `hash` at once when hashing on the calling thread, which lets it
+/// hash several leaves in lockstep.
+const BATCH_LEAVES: usize = 8;
+
+/// Hashes each `leaf_len` chunk of `buf` with `hash`, passing the chaining values to `sink` in
+/// input order. `hash` is given a run of consecutive leaves and writes one chaining value per
+/// leaf.
+#[cfg(not(feature = "std"))]
+pub(crate) fn hash_leaves<const LEN: usize, F, G>(buf: &[u8], leaf_len: usize, hash: F, sink: G)
+where
+	F: Fn(&[u8], &mut [[u8; LEN]]) + Sync,
+	G: FnMut([u8; LEN])
+{
+	hash_batched(buf, leaf_len, &hash, sink);
+}
+
+fn hash_batched<const LEN: usize, F, G>(buf: &[u8], leaf_len: usize, hash: &F, mut sink: G)
+where
+	F: Fn(&[u8], &mut [[u8; LEN]]),
+	G: FnMut([u8; LEN])
+{
+	debug_assert!(buf.len().is_multiple_of(leaf_len));
+
+	let mut cvs = [[0; LEN]; BATCH_LEAVES];
+
+	for batch in buf.chunks(BATCH_LEAVES * leaf_len) {
+		let cvs = &mut cvs[..batch.len() / leaf_len];
+		hash(batch, cvs);
+		cvs.iter().copied().for_each(&mut sink);
+	}
+}
+
+/// Hashes each `leaf_len` chunk of `buf` with `hash`, passing the chaining values to `sink` in
+/// input order. `hash` is given a run of consecutive leaves and writes one chaining value per
+/// leaf. Large inputs are split into batches whose leaves are hashed on worker threads.
+#[cfg(feature = "std")]
+pub(crate) fn hash_leaves<const LEN: usize, F, G>(buf: &[u8], leaf_len: usize, hash: F, sink: G)
+where
+	F: Fn(&[u8], &mut [[u8; LEN]]) + Sync,
+	G: FnMut([u8; LEN])
+{
+	let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+	hash_leaves_with(threads, buf, leaf_len, hash, sink);
+}
+
+#[cfg(feature = "std")]
+fn hash_leaves_with<const LEN: usize, F, G>(
+	threads: usize,
+	buf: &[u8],
+	leaf_len: usize,
+	hash: F,
+	mut sink: G
+) where
+	F: Fn(&[u8], &mut [[u8; LEN]]) + Sync,
+	G: FnMut([u8; LEN])
+{
+	debug_assert!(buf.len().is_multiple_of(leaf_len));
+
+	let thread_leaves = (MIN_THREAD_LEN / leaf_len).clamp(1, MAX_THREAD_LEAVES);
+
+	if threads == 1 || buf.len() / leaf_len <= thread_leaves {
+		hash_batched(buf, leaf_len, &hash, sink);
+		return;
+	}
+
+	let hash = &hash;
+	let mut cvs = vec![[0; LEN]; threads * thread_leaves];
+
+	for batch in buf.chunks(threads * thread_leaves * leaf_len) {
+		let cvs = &mut cvs[..batch.len() / leaf_len];
+
+		thread::scope(|scope| {
+			for (batch, cvs) in
+				batch.chunks(thread_leaves * leaf_len).zip(cvs.chunks_mut(thread_leaves))
+			{
+				let _ = scope.spawn(move || hash(batch, cvs));
+			}
+		});
+
+		cvs.iter().copied().for_each(&mut sink);
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+	use std::vec::Vec;
+
+	use super::{hash_leaves_with, MAX_THREAD_LEAVES, MIN_THREAD_LEN};
+
+	fn hash_leaf(leaf: &[u8]) -> [u8; 8] {
+		let mut cv = [0_u8; 8];
+
+		for (i, &b) in leaf.iter().enumerate() {
+			cv[i % 8] = cv[i % 8].rotate_left(3) ^ b;
+		}
+
+		cv
+	}
+
+	fn hash(leaves: &[u8], cvs: &mut [[u8; 8]], leaf_len: usize) {
+		assert_eq!(leaves.len(), cvs.len() * leaf_len);
+
+		for (leaf, cv) in leaves.chunks(leaf_len).zip(cvs) {
+			*cv = hash_leaf(leaf);
+		}
+	}
+
+	#[test]
+	fn hash_leaves_in_order() {
+		let buf: Vec<u8> = (0..=250).cycle().take(3 * MIN_THREAD_LEN + 3 * 4096).collect();
+
+		for leaf_len in [1, 7, 4096, MIN_THREAD_LEN / MAX_THREAD_LEAVES, MIN_THREAD_LEN] {
+			let buf = &buf[..buf.len() - buf.len() % leaf_len];
+			let expected: Vec<_> = buf.chunks(leaf_len).map(hash_leaf).collect();
+
+			for threads in [1, 2, 3, 8] {
+				let mut cvs = Vec::new();
+				hash_leaves_with(
+					threads,
+					buf,
+					leaf_len,
+					|leaves, cvs| hash(leaves, cvs, leaf_len),
+					|cv| cvs.push(cv)
+				);
+				assert_eq!(cvs, expected, "leaf_len = {leaf_len}, threads = {threads}");
+			}
+		}
+	}
+}
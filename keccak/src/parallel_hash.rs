@@ -0,0 +1,262 @@
+use core::mem;
+
+use sponge::{state::Lsbu64, Absorb, IntoSqueezer, Permutation, Squeeze, State};
+
+use crate::{
+	encode::{LeftEncoded, RightEncoded},
+	keccakp::Keccak1600,
+	parallel,
+	sha3::{CShake128, CShake256, Shake128, Shake128Squeezer, Shake256, Shake256Squeezer}
+};
+
+macro_rules! parallel_hash {
+	($name:ident, $xof:ident, $cshake:ident, $shake:ident, $squeezer:ident, $cv_len:expr) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $name<S> {
+			root: $cshake<S>,
+			leaf: $shake<S>,
+			block_len: usize,
+			blocks: u64,
+			length: usize
+		}
+
+		impl $name<Lsbu64<25>> {
+			#[track_caller]
+			pub fn new(block_len: usize, customization: &[u8]) -> Self {
+				Self::with_customization(block_len, customization)
+			}
+		}
+
+		impl<S> $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			#[track_caller]
+			pub fn with_customization(block_len: usize, customization: &[u8]) -> Self {
+				assert!(block_len != 0, "block length must be non-zero");
+
+				let mut root = $cshake::with_customization(b"ParallelHash", customization);
+				root.absorb(&LeftEncoded::from(block_len));
+
+				Self { root, leaf: $shake::default(), block_len, blocks: 0, length: 0 }
+			}
+
+			pub fn absorb(&mut self, mut buf: &[u8]) {
+				while !buf.is_empty() {
+					debug_assert!(self.length <= self.block_len);
+
+					if self.length == self.block_len {
+						self.absorb_chaining_value();
+					}
+
+					if self.length == 0 && buf.len() >= self.block_len {
+						let (l, r) = buf.split_at(buf.len() - buf.len() % self.block_len);
+						buf = r;
+
+						let (root, block_len) = (&mut self.root, self.block_len);
+						let hash = |leaves: &[u8], cvs: &mut [[u8; $cv_len]]| {
+							for (leaf, cv) in leaves.chunks(block_len).zip(cvs) {
+								*cv = Self::hash_leaf(leaf);
+							}
+						};
+						parallel::hash_leaves(l, block_len, hash, |cv| root.absorb(&cv));
+
+						self.blocks += (l.len() / self.block_len) as u64;
+						continue;
+					}
+
+					let (l, r) = buf.split_at(buf.len().min(self.block_len - self.length));
+					buf = r;
+
+					self.leaf.absorb(l);
+					self.length += l.len();
+				}
+			}
+
+			#[track_caller]
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				let len = buf.len() as u128 * 8;
+				self.into_squeezer_impl(len).squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				let mut buf = [0; LEN];
+				self.squeeze_into(&mut buf);
+				buf
+			}
+
+			pub fn into_xof(self) -> $xof<S> {
+				$xof(self)
+			}
+
+			fn hash_leaf(buf: &[u8]) -> [u8; $cv_len] {
+				let mut leaf = $shake::<S>::default();
+				leaf.absorb(buf);
+				leaf.squeeze()
+			}
+
+			fn absorb_chaining_value(&mut self) {
+				debug_assert!(self.length != 0);
+
+				self.root.absorb(&mem::take(&mut self.leaf).squeeze::<$cv_len>());
+				self.blocks += 1;
+				self.length = 0;
+			}
+
+			fn into_squeezer_impl(mut self, len: u128) -> $squeezer<S> {
+				if self.length != 0 {
+					self.absorb_chaining_value();
+				}
+
+				self.root.absorb(&RightEncoded::from(self.blocks).sp800_185());
+				self.root.absorb(&RightEncoded::from(len).sp800_185());
+				self.root.into_squeezer()
+			}
+		}
+
+		impl<S> Absorb for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> Squeeze for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn squeeze_into(self, buf: &mut [u8]) {
+				self.squeeze_into(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $name<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $name<S> {}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $name<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.root.zeroize();
+				self.leaf.zeroize();
+			}
+		}
+
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $xof<S>($name<S>);
+
+		impl $xof<Lsbu64<25>> {
+			#[track_caller]
+			pub fn new(block_len: usize, customization: &[u8]) -> Self {
+				Self::with_customization(block_len, customization)
+			}
+		}
+
+		impl<S> $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			#[track_caller]
+			pub fn with_customization(block_len: usize, customization: &[u8]) -> Self {
+				Self($name::with_customization(block_len, customization))
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.0.absorb(buf);
+			}
+
+			pub fn into_squeezer(self) -> $squeezer<S> {
+				self.0.into_squeezer_impl(0)
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				self.into_squeezer().squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				self.into_squeezer().squeeze()
+			}
+		}
+
+		impl<S> Absorb for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> IntoSqueezer for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Squeezer = $squeezer<S>;
+
+			fn into_squeezer(self) -> Self::Squeezer {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutput for $xof<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $xof<S> {}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $xof<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+	};
+}
+
+parallel_hash!(ParallelHash128, ParallelHashXof128, CShake128, Shake128, Shake128Squeezer, 32);
+parallel_hash!(ParallelHash256, ParallelHashXof256, CShake256, Shake256, Shake256Squeezer, 64);
@@ -3,10 +3,21 @@ use sponge::{
 	State, Suffix
 };
 
-use crate::keccakp::Keccak1600;
+use crate::{
+	encode::{encode_string, Bytepad},
+	keccakp::Keccak1600
+};
 
 macro_rules! sha3 {
-	($sponge:ident, $capacity:expr, $hash_len:expr, $typenum:ident) => {
+	(
+		$sponge:ident,
+		$name:literal,
+		$capacity:expr,
+		$hash_len:expr,
+		$typenum:ident,
+		$block_typenum:ident,
+		$suffix:expr
+	) => {
 		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 		pub struct $sponge<S>(Sponge<S, Keccak1600<24>, $capacity, false>);
 
@@ -38,7 +49,7 @@ macro_rules! sha3 {
 			#[track_caller]
 			pub fn squeeze_into(self, buf: &mut [u8]) {
 				assert!(buf.len() == $hash_len);
-				self.0.squeeze_into(suffix!(0, 1), buf);
+				self.0.squeeze_into($suffix, buf);
 			}
 
 			pub fn squeeze(self) -> [u8; $hash_len] {
@@ -113,6 +124,17 @@ macro_rules! sha3 {
 			}
 		}
 
+		#[cfg(feature = "digest")]
+		impl<S> digest::FixedOutputReset for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+				core::mem::take(self).squeeze_into(out);
+			}
+		}
+
 		#[cfg(feature = "digest")]
 		impl<S> digest::Reset for $sponge<S>
 		where
@@ -124,6 +146,58 @@ macro_rules! sha3 {
 			}
 		}
 
+		// The block-level API makes the type usable with `hmac::SimpleHmac` and `hkdf::SimpleHkdf`
+		// directly, and with `hmac::Hmac` and `hkdf::Hkdf` through `digest::core_api::CoreWrapper`.
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $sponge<S> {}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::BlockSizeUser for $sponge<S> {
+			type BlockSize = digest::consts::$block_typenum;
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::BufferKindUser for $sponge<S> {
+			type BufferKind = digest::block_buffer::Eager;
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::UpdateCore for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update_blocks(&mut self, blocks: &[digest::core_api::Block<Self>]) {
+				for block in blocks {
+					self.absorb(block);
+				}
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::FixedOutputCore for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn finalize_fixed_core(
+				&mut self,
+				buffer: &mut digest::core_api::Buffer<Self>,
+				out: &mut digest::Output<Self>
+			) {
+				self.absorb(buffer.get_data());
+				core::mem::take(self).squeeze_into(out);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::AlgorithmName for $sponge<S> {
+			fn write_alg_name(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.write_str($name)
+			}
+		}
+
 		#[cfg(feature = "zeroize")]
 		impl<S> zeroize::Zeroize for $sponge<S>
 		where
@@ -137,7 +211,14 @@ macro_rules! sha3 {
 }
 
 macro_rules! shake_impl {
-	($sponge:ident, $squeezer:ident, $rounds:expr, $capacity:expr) => {
+	(
+		$sponge:ident,
+		$name:literal,
+		$squeezer:ident,
+		$rounds:expr,
+		$capacity:expr,
+		$block_typenum:ident
+	) => {
 		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 		pub struct $sponge<S>(Sponge<S, Keccak1600<$rounds>, $capacity, false>);
 
@@ -215,6 +296,21 @@ macro_rules! shake_impl {
 			}
 		}
 
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $sponge<S> {}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::BlockSizeUser for $sponge<S> {
+			type BlockSize = digest::consts::$block_typenum;
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::AlgorithmName for $sponge<S> {
+			fn write_alg_name(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+				f.write_str($name)
+			}
+		}
+
 		#[cfg(feature = "zeroize")]
 		impl<S> zeroize::Zeroize for $sponge<S>
 		where
@@ -228,8 +324,8 @@ macro_rules! shake_impl {
 }
 
 macro_rules! shake {
-	($sponge:ident, $squeezer:ident, $capacity:expr) => {
-		shake_impl!($sponge, $squeezer, 24, $capacity);
+	($sponge:ident, $name:literal, $squeezer:ident, $capacity:expr, $block_typenum:ident) => {
+		shake_impl!($sponge, $name, $squeezer, 24, $capacity, $block_typenum);
 
 		impl<S> $sponge<S>
 		where
@@ -273,12 +369,23 @@ macro_rules! shake {
 				self.into_squeezer()
 			}
 		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutputReset for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn finalize_xof_reset(&mut self) -> Self::Reader {
+				core::mem::take(self).into_squeezer()
+			}
+		}
 	};
 }
 
 macro_rules! turbo_shake {
-	($sponge:ident, $squeezer:ident, $capacity:expr) => {
-		shake_impl!($sponge, $squeezer, 12, $capacity);
+	($sponge:ident, $name:literal, $squeezer:ident, $capacity:expr, $block_typenum:ident) => {
+		shake_impl!($sponge, $name, $squeezer, 12, $capacity, $block_typenum);
 
 		impl<S> $sponge<S>
 		where
@@ -297,16 +404,452 @@ macro_rules! turbo_shake {
 				self.into_squeezer(suffix).squeeze()
 			}
 		}
+
+		// As a `digest` XOF, TurboSHAKE uses the default domain separation byte of RFC 9861, 0x1F.
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutput for $sponge<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer(suffix!(1, 1, 1, 1))
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutputReset for $sponge<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn finalize_xof_reset(&mut self) -> Self::Reader {
+				core::mem::take(self).into_squeezer(suffix!(1, 1, 1, 1))
+			}
+		}
+	};
+}
+
+macro_rules! turbo_shake_domain {
+	($domain:ident, $dyn_domain:ident, $sponge:ident, $squeezer:ident) => {
+		/// TurboSHAKE with the domain separation byte `D` from RFC 9861, which must be in
+		/// `0x01..=0x7F`.
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $domain<S, const D: u8 = 0x1F>($sponge<S>);
+
+		impl<const D: u8> $domain<Lsbu64<25>, D> {
+			pub fn new() -> Self {
+				Self::default()
+			}
+		}
+
+		impl<S, const D: u8> $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			const SUFFIX: Suffix = match Suffix::from_u8(D) {
+				Some(suffix) => suffix,
+				None => panic!("domain separation byte must be in 0x01..=0x7F")
+			};
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.0.absorb(buf);
+			}
+
+			pub fn into_squeezer(self) -> $squeezer<S> {
+				self.0.into_squeezer(Self::SUFFIX)
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				self.into_squeezer().squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				self.into_squeezer().squeeze()
+			}
+		}
+
+		impl<S, const D: u8> Default for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn default() -> Self {
+				let _ = Self::SUFFIX;
+				Self($sponge::default())
+			}
+		}
+
+		impl<S, const D: u8> Absorb for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S, const D: u8> IntoSqueezer for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			type Squeezer = $squeezer<S>;
+
+			fn into_squeezer(self) -> Self::Squeezer {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S, const D: u8> digest::Update for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S, const D: u8> digest::Reset for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn reset(&mut self) {
+				*self = Self::default();
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S, const D: u8> digest::ExtendableOutput for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S, const D: u8> digest::ExtendableOutputReset for $domain<S, D>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn finalize_xof_reset(&mut self) -> Self::Reader {
+				core::mem::take(self).into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S, const D: u8> digest::HashMarker for $domain<S, D> {}
+
+		#[cfg(feature = "zeroize")]
+		impl<S, const D: u8> zeroize::Zeroize for $domain<S, D>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+
+		/// TurboSHAKE with a domain separation byte chosen at runtime.
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $dyn_domain<S> {
+			sponge: $sponge<S>,
+			suffix: Suffix
+		}
+
+		impl $dyn_domain<Lsbu64<25>> {
+			#[track_caller]
+			pub fn new(domain: u8) -> Self {
+				Self::with_domain(domain)
+			}
+		}
+
+		impl<S> $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			#[track_caller]
+			pub fn with_domain(domain: u8) -> Self {
+				let suffix =
+					Suffix::from_u8(domain).expect("domain separation byte must be in 0x01..=0x7F");
+
+				Self { sponge: $sponge::default(), suffix }
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.sponge.absorb(buf);
+			}
+
+			pub fn into_squeezer(self) -> $squeezer<S> {
+				self.sponge.into_squeezer(self.suffix)
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				self.into_squeezer().squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				self.into_squeezer().squeeze()
+			}
+		}
+
+		impl<S> Absorb for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		impl<S> IntoSqueezer for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			type Squeezer = $squeezer<S>;
+
+			fn into_squeezer(self) -> Self::Squeezer {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Reset for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn reset(&mut self) {
+				self.sponge = $sponge::default();
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutput for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutputReset for $dyn_domain<S>
+		where
+			S: State,
+			Keccak1600<12>: Permutation<S::Inner>
+		{
+			fn finalize_xof_reset(&mut self) -> Self::Reader {
+				core::mem::take(&mut self.sponge).into_squeezer(self.suffix)
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $dyn_domain<S> {}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $dyn_domain<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.sponge.zeroize();
+			}
+		}
 	};
 }
 
-sha3!(Sha3_224, { 224 * 2 / 8 }, 224 / 8, U28);
-sha3!(Sha3_256, { 256 * 2 / 8 }, 256 / 8, U32);
-sha3!(Sha3_384, { 384 * 2 / 8 }, 384 / 8, U48);
-sha3!(Sha3_512, { 512 * 2 / 8 }, 512 / 8, U64);
+macro_rules! cshake {
+	($sponge:ident, $squeezer:ident, $capacity:expr, $block_typenum:ident) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $sponge<S> {
+			sponge: Sponge<S, Keccak1600<24>, $capacity, false>,
+			suffix: Suffix
+		}
 
-shake!(Shake128, Shake128Squeezer, { 128 * 2 / 8 });
-shake!(Shake256, Shake256Squeezer, { 256 * 2 / 8 });
+		impl $sponge<Lsbu64<25>> {
+			pub fn new(name: &[u8], customization: &[u8]) -> Self {
+				Self::with_customization(name, customization)
+			}
+		}
+
+		impl<S> $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			const RATE: usize = Sponge::<S, Keccak1600<24>, $capacity, false>::ABSORB_RATE;
+
+			pub fn with_customization(name: &[u8], customization: &[u8]) -> Self {
+				let mut sponge = Sponge::default();
+
+				// cSHAKE with an empty name and customization string is SHAKE.
+				if name.is_empty() && customization.is_empty() {
+					return Self { sponge, suffix: suffix!(1, 1, 1, 1) };
+				}
+
+				let mut bytepad = Bytepad::new(&mut sponge, Self::RATE);
+				encode_string(&mut bytepad, name);
+				encode_string(&mut bytepad, customization);
+				bytepad.finish();
+
+				Self { sponge, suffix: suffix!(0, 0) }
+			}
+
+			pub fn absorb(&mut self, buf: &[u8]) {
+				self.sponge.absorb(buf);
+			}
+
+			pub fn into_squeezer(self) -> $squeezer<S> {
+				self.sponge.into_squeezer(self.suffix)
+			}
+
+			pub fn squeeze_into(self, buf: &mut [u8]) {
+				self.into_squeezer().squeeze_into(buf);
+			}
+
+			pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+				self.into_squeezer().squeeze()
+			}
+		}
+
+		impl<S> Absorb for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn absorb(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+
+			fn absorb_u8(&mut self, b: u8) {
+				self.sponge.absorb_u8(b);
+			}
+
+			fn absorb_zeroes(&mut self, len: usize) {
+				self.sponge.absorb_zeroes(len);
+			}
+		}
+
+		impl<S> IntoSqueezer for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Squeezer = $squeezer<S>;
+
+			fn into_squeezer(self) -> Self::Squeezer {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::Update for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			fn update(&mut self, buf: &[u8]) {
+				self.absorb(buf);
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::ExtendableOutput for $sponge<S>
+		where
+			S: State,
+			Keccak1600<24>: Permutation<S::Inner>
+		{
+			type Reader = $squeezer<S>;
+
+			fn finalize_xof(self) -> Self::Reader {
+				self.into_squeezer()
+			}
+		}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::HashMarker for $sponge<S> {}
+
+		#[cfg(feature = "digest")]
+		impl<S> digest::core_api::BlockSizeUser for $sponge<S> {
+			type BlockSize = digest::consts::$block_typenum;
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $sponge<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.sponge.zeroize();
+			}
+		}
+	};
+}
 
-turbo_shake!(TurboShake128, TurboShake128Squeezer, { 128 * 2 / 8 });
-turbo_shake!(TurboShake256, TurboShake256Squeezer, { 256 * 2 / 8 });
+sha3!(Sha3_224, "SHA3-224", { 224 * 2 / 8 }, 224 / 8, U28, U144, suffix!(0, 1));
+sha3!(Sha3_256, "SHA3-256", { 256 * 2 / 8 }, 256 / 8, U32, U136, suffix!(0, 1));
+sha3!(Sha3_384, "SHA3-384", { 384 * 2 / 8 }, 384 / 8, U48, U104, suffix!(0, 1));
+sha3!(Sha3_512, "SHA3-512", { 512 * 2 / 8 }, 512 / 8, U64, U72, suffix!(0, 1));
+
+// The original Keccak submission padding, without the SHA-3 domain separation bits, as used by
+// Ethereum.
+sha3!(Keccak224, "Keccak-224", { 224 * 2 / 8 }, 224 / 8, U28, U144, suffix!());
+sha3!(Keccak256, "Keccak-256", { 256 * 2 / 8 }, 256 / 8, U32, U136, suffix!());
+sha3!(Keccak384, "Keccak-384", { 384 * 2 / 8 }, 384 / 8, U48, U104, suffix!());
+sha3!(Keccak512, "Keccak-512", { 512 * 2 / 8 }, 512 / 8, U64, U72, suffix!());
+
+shake!(Shake128, "SHAKE128", Shake128Squeezer, { 128 * 2 / 8 }, U168);
+shake!(Shake256, "SHAKE256", Shake256Squeezer, { 256 * 2 / 8 }, U136);
+
+cshake!(CShake128, Shake128Squeezer, { 128 * 2 / 8 }, U168);
+cshake!(CShake256, Shake256Squeezer, { 256 * 2 / 8 }, U136);
+
+turbo_shake!(TurboShake128, "TurboSHAKE128", TurboShake128Squeezer, { 128 * 2 / 8 }, U168);
+turbo_shake!(TurboShake256, "TurboSHAKE256", TurboShake256Squeezer, { 256 * 2 / 8 }, U136);
+
+turbo_shake_domain!(
+	TurboShake128Domain,
+	TurboShake128DynDomain,
+	TurboShake128,
+	TurboShake128Squeezer
+);
+turbo_shake_domain!(
+	TurboShake256Domain,
+	TurboShake256DynDomain,
+	TurboShake256,
+	TurboShake256Squeezer
+);
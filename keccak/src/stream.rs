@@ -0,0 +1,137 @@
+//! Stream ciphers using SHAKE and TurboSHAKE of the key and nonce as the keystream.
+
+use sponge::{state::Lsbu64, stream::Keystream, Permutation, State};
+
+use crate::{
+	keccakp::Keccak1600,
+	sha3::{Shake128, Shake256, TurboShake128Domain, TurboShake256Domain}
+};
+
+macro_rules! stream_cipher {
+	(
+		$cipher:ident,
+		$hash:ident,
+		$rounds:expr,
+		$capacity:expr,
+		$key_len:expr,
+		$key_typenum:ident,
+		$nonce_len:expr,
+		$nonce_typenum:ident
+	) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+		pub struct $cipher<S>(Keystream<S, Keccak1600<$rounds>, $capacity>);
+
+		impl $cipher<Lsbu64<25>> {
+			pub fn new(key: &[u8; $key_len], nonce: &[u8; $nonce_len]) -> Self {
+				Self::with_key_nonce(key, nonce)
+			}
+		}
+
+		impl<S> $cipher<S>
+		where
+			S: State + Clone,
+			Keccak1600<$rounds>: Permutation<S::Inner>
+		{
+			pub fn with_key_nonce(key: &[u8; $key_len], nonce: &[u8; $nonce_len]) -> Self {
+				let mut hash = $hash::<S>::default();
+				hash.absorb(key);
+				hash.absorb(nonce);
+				Self(Keystream::new(hash.into_squeezer()))
+			}
+
+			pub const fn position(&self) -> u64 {
+				self.0.position()
+			}
+
+			#[track_caller]
+			pub fn apply_keystream(&mut self, buf: &mut [u8]) {
+				self.0.apply_keystream(buf);
+			}
+
+			/// Moves to byte `position` of the keystream. Seeking backwards replays the keystream
+			/// from the start on its next use, see [`Keystream::seek`].
+			pub const fn seek(&mut self, position: u64) {
+				self.0.seek(position);
+			}
+		}
+
+		#[cfg(feature = "cipher")]
+		impl<S> cipher::KeySizeUser for $cipher<S> {
+			type KeySize = cipher::consts::$key_typenum;
+		}
+
+		#[cfg(feature = "cipher")]
+		impl<S> cipher::IvSizeUser for $cipher<S> {
+			type IvSize = cipher::consts::$nonce_typenum;
+		}
+
+		#[cfg(feature = "cipher")]
+		impl<S> cipher::KeyIvInit for $cipher<S>
+		where
+			S: State + Clone,
+			Keccak1600<$rounds>: Permutation<S::Inner>
+		{
+			fn new(key: &cipher::Key<Self>, iv: &cipher::Iv<Self>) -> Self {
+				Self::with_key_nonce(key.as_ref(), iv.as_ref())
+			}
+		}
+
+		#[cfg(feature = "cipher")]
+		impl<S> cipher::StreamCipher for $cipher<S>
+		where
+			S: State + Clone,
+			Keccak1600<$rounds>: Permutation<S::Inner>
+		{
+			fn try_apply_keystream_inout(
+				&mut self,
+				buf: cipher::inout::InOutBuf<'_, '_, u8>
+			) -> Result<(), cipher::StreamCipherError> {
+				self.0.try_apply_keystream_inout(buf)
+			}
+
+			fn try_apply_keystream(
+				&mut self,
+				buf: &mut [u8]
+			) -> Result<(), cipher::StreamCipherError> {
+				cipher::StreamCipher::try_apply_keystream(&mut self.0, buf)
+			}
+		}
+
+		/// Seeking backwards replays the keystream from the start on its next use.
+		#[cfg(feature = "cipher")]
+		impl<S> cipher::StreamCipherSeek for $cipher<S>
+		where
+			S: State + Clone,
+			Keccak1600<$rounds>: Permutation<S::Inner>
+		{
+			fn try_current_pos<T>(&self) -> Result<T, cipher::OverflowError>
+			where
+				T: cipher::SeekNum
+			{
+				self.0.try_current_pos()
+			}
+
+			fn try_seek<T>(&mut self, pos: T) -> Result<(), cipher::StreamCipherError>
+			where
+				T: cipher::SeekNum
+			{
+				self.0.try_seek(pos)
+			}
+		}
+
+		#[cfg(feature = "zeroize")]
+		impl<S> zeroize::Zeroize for $cipher<S>
+		where
+			S: zeroize::Zeroize
+		{
+			fn zeroize(&mut self) {
+				self.0.zeroize();
+			}
+		}
+	};
+}
+
+stream_cipher!(Shake128Cipher, Shake128, 24, { 128 * 2 / 8 }, 16, U16, 16, U16);
+stream_cipher!(Shake256Cipher, Shake256, 24, { 256 * 2 / 8 }, 32, U32, 32, U32);
+stream_cipher!(TurboShake128Cipher, TurboShake128Domain, 12, { 128 * 2 / 8 }, 16, U16, 16, U16);
+stream_cipher!(TurboShake256Cipher, TurboShake256Domain, 12, { 256 * 2 / 8 }, 32, U32, 32, U32);
@@ -0,0 +1,179 @@
+//! Runs sample ACVP vector sets. The bundled ones are not NIST's: their responses were computed
+//! independently with Python's hashlib, following the Monte Carlo pseudocode of the ACVP SHA3
+//! specification. NIST's published samples, the `prompt.json` and `expectedResults.json` files
+//! under `gen-val/json-files` in a checkout of usnistgov/ACVP-Server, are run by `published` when
+//! `ACVP_JSON_FILES` names that directory.
+
+#![cfg(feature = "acvp")]
+
+use std::{env, fs, path::Path};
+
+use keccak::acvp::{self, Error};
+use serde_json::{json, Value};
+
+macro_rules! test {
+	($name:ident, $algorithm:literal) => {
+		#[test]
+		fn $name() {
+			let request = include_str!(concat!("ACVP_", $algorithm, "_request.json"));
+			let expected = include_str!(concat!("ACVP_", $algorithm, "_response.json"));
+
+			let response = acvp::respond(request).unwrap();
+			assert_eq!(
+				serde_json::from_str::<Value>(&response).unwrap(),
+				serde_json::from_str::<Value>(expected).unwrap()
+			);
+		}
+	};
+}
+
+test!(sha3_256, "SHA3-256");
+test!(sha3_512, "SHA3-512");
+test!(shake128, "SHAKE-128");
+test!(shake256, "SHAKE-256");
+
+/// Runs every SHA3 and SHAKE sample in `$ACVP_JSON_FILES`, which is skipped if it is not set.
+#[test]
+fn published() {
+	let Some(dir) = env::var_os("ACVP_JSON_FILES") else {
+		eprintln!("skipped: ACVP_JSON_FILES not set");
+		return;
+	};
+
+	let mut ran = 0;
+	for entry in fs::read_dir(&dir).unwrap() {
+		let path = entry.unwrap().path();
+		let name = path.file_name().unwrap().to_string_lossy();
+		if !["SHA3-", "SHAKE-"].iter().any(|prefix| name.starts_with(prefix)) {
+			continue;
+		}
+
+		let read = |file: &str| fs::read_to_string(Path::new(&path).join(file)).unwrap();
+		let response =
+			acvp::respond(&read("prompt.json")).unwrap_or_else(|err| panic!("{name}: {err}"));
+		let response: Value = serde_json::from_str(&response).unwrap();
+		let expected: Value = serde_json::from_str(&read("expectedResults.json")).unwrap();
+
+		assert_matches(&response["testGroups"], &expected["testGroups"], &name);
+		ran += 1;
+	}
+
+	assert_ne!(ran, 0, "no SHA3 or SHAKE vector sets in {dir:?}");
+}
+
+/// Checks that `actual` has every field of `expected`, with the same value.
+#[track_caller]
+fn assert_matches(actual: &Value, expected: &Value, path: &str) {
+	match (actual, expected) {
+		(Value::Object(actual), Value::Object(expected)) => {
+			for (key, value) in expected {
+				let path = format!("{path}.{key}");
+				assert_matches(actual.get(key).unwrap_or(&Value::Null), value, &path);
+			}
+		}
+		(Value::Array(actual), Value::Array(expected)) => {
+			assert_eq!(actual.len(), expected.len(), "{path}");
+			for (i, (actual, expected)) in actual.iter().zip(expected).enumerate() {
+				assert_matches(actual, expected, &format!("{path}[{i}]"));
+			}
+		}
+		_ => assert_eq!(actual, expected, "{path}")
+	}
+}
+
+fn respond(request: Value) -> Result<Value, Error> {
+	acvp::respond(&request.to_string()).map(|response| serde_json::from_str(&response).unwrap())
+}
+
+#[test]
+fn bare() {
+	let request = json!({
+		"vsId": 7, "algorithm": "SHA3-224", "revision": "2.0",
+		"testGroups": [{ "tgId": 1, "testType": "AFT", "tests": [{ "tcId": 1, "msg": "", "len": 0 }] }]
+	});
+
+	assert_eq!(
+		respond(request).unwrap(),
+		json!({
+			"vsId": 7, "algorithm": "SHA3-224", "revision": "2.0",
+			"testGroups": [{
+				"tgId": 1,
+				"tests": [{
+					"tcId": 1,
+					"md": "6B4E03423667DBB73B6E15454F0EB1ABD4597F9A1B078E3F5B5A6BC7"
+				}]
+			}]
+		})
+	);
+}
+
+#[test]
+fn unsupported_algorithm() {
+	let request =
+		json!({ "vsId": 1, "algorithm": "SHA2-256", "revision": "1.0", "testGroups": [] });
+
+	assert!(
+		matches!(respond(request), Err(Error::UnsupportedAlgorithm(name)) if name == "SHA2-256")
+	);
+}
+
+#[test]
+fn invalid_tests() {
+	let invalid = |group: Value| {
+		let request = json!({
+			"vsId": 1, "algorithm": "SHAKE-128", "revision": "1.0", "testGroups": [group]
+		});
+
+		match respond(request) {
+			Err(Error::InvalidTest { tg_id: 2, tc_id: 3, reason }) => reason,
+			result => panic!("unexpected result: {result:?}")
+		}
+	};
+
+	let aft = |test: Value| json!({ "tgId": 2, "testType": "AFT", "tests": [test] });
+
+	assert_eq!(
+		invalid(aft(json!({ "tcId": 3, "msg": "80", "len": 1, "outLen": 128 }))),
+		"message is not whole bytes"
+	);
+	assert_eq!(
+		invalid(aft(json!({ "tcId": 3, "msg": "00", "len": 16, "outLen": 128 }))),
+		"message is shorter than its length"
+	);
+	assert_eq!(
+		invalid(aft(json!({ "tcId": 3, "msg": "00", "len": 8, "outLen": 129 }))),
+		"output is not whole bytes"
+	);
+	assert_eq!(
+		invalid(json!({
+			"tgId": 2, "testType": "MCT", "minOutLen": 8, "maxOutLen": 1024,
+			"tests": [{ "tcId": 3, "msg": "00", "len": 8 }]
+		})),
+		"invalid output lengths"
+	);
+	assert_eq!(
+		invalid(json!({
+			"tgId": 2, "testType": "LDT",
+			"tests": [{
+				"tcId": 3,
+				"largeMsg": {
+					"content": "00", "contentLength": 8, "fullLength": 80,
+					"expansionTechnique": "other"
+				},
+				"outLen": 128
+			}]
+		})),
+		"unsupported expansion technique"
+	);
+}
+
+#[test]
+fn invalid_json() {
+	assert!(matches!(acvp::respond("[{}]"), Err(Error::Json(_))));
+	assert!(matches!(
+		respond(json!({ "vsId": 1, "algorithm": "SHA3-256", "revision": "2.0", "testGroups": [{
+			"tgId": 1, "testType": "AFT", "tests": [{ "tcId": 1, "msg": "0G", "len": 8 }]
+		}] })),
+		Err(Error::Json(_))
+	));
+}
@@ -0,0 +1,68 @@
+use keccak::{
+	keccakp::{keccakp_1600, Backend, Keccak1600},
+	sha3::Sha3_256
+};
+use sponge::Permutation;
+
+fn states() -> impl Iterator<Item = [u64; 25]> {
+	let mut x = 0x0123_4567_89AB_CDEF_u64;
+
+	(0..8).map(move |_| {
+		core::array::from_fn(|_| {
+			x = x.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+			x
+		})
+	})
+}
+
+#[test]
+fn backends() {
+	for &backend in Backend::ALL.iter().filter(|backend| backend.is_available()) {
+		assert_eq!(Backend::from_name(backend.name()), Some(backend));
+
+		for rounds in 1..=24 {
+			let rcs = &Keccak1600::<24>::RCS[24 - rounds..];
+
+			for mut state in states() {
+				let mut expected = state;
+				keccakp_1600(&mut expected, rcs);
+				backend.keccakp_1600(&mut state, rcs);
+				assert_eq!(state, expected, "backend = {backend:?}, rounds = {rounds}");
+			}
+
+			let mut inputs = states();
+			let mut multi: [_; 7] = core::array::from_fn(|_| inputs.next().unwrap());
+			let mut expected = multi;
+			expected.iter_mut().for_each(|state| keccakp_1600(state, rcs));
+			backend.keccakp_1600_multi(&mut multi, rcs);
+			assert_eq!(multi, expected, "backend = {backend:?}, rounds = {rounds}");
+		}
+	}
+}
+
+#[test]
+fn force() {
+	assert!(Backend::best().is_available());
+	assert!(Backend::current().is_available());
+
+	for &backend in Backend::ALL.iter().filter(|backend| backend.is_available()) {
+		backend.force();
+		assert_eq!(Backend::current(), backend);
+
+		let mut hash = Sha3_256::new();
+		hash.absorb(b"abc");
+		assert_eq!(
+			super::hex::encode(&hash.squeeze()),
+			"3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+		);
+
+		let mut inputs = states();
+		let mut multi: [_; 4] = core::array::from_fn(|_| inputs.next().unwrap());
+		let mut expected = multi;
+		expected.iter_mut().for_each(|state| keccakp_1600(state, &Keccak1600::<24>::RCS));
+		Keccak1600::<24>::permute_multi(&mut multi);
+		assert_eq!(multi, expected, "backend = {backend:?}");
+	}
+
+	Backend::best().force();
+}
@@ -0,0 +1,206 @@
+//! Checks that every hash works through the generic `digest` traits, agreeing with its own API.
+
+#![cfg(feature = "digest")]
+
+use digest::{
+	Digest, DynDigest, ExtendableOutput, ExtendableOutputReset, FixedOutputReset, Reset, Update,
+	XofReader
+};
+use keccak::{
+	k12::{BufferedKangarooTwelve, Kt128, Kt256, MarsupilamiFourteen},
+	kmac::{KmacXof128, KmacXof256},
+	parallel_hash::{ParallelHashXof128, ParallelHashXof256},
+	sha3::*
+};
+use sponge::{state::Lsbu64, suffix, Absorb, IntoSqueezer, Squeeze};
+
+fn message() -> Vec<u8> {
+	(0..20_000).map(|i| (i % 251) as u8).collect()
+}
+
+fn digest<D>()
+where
+	D: Digest + DynDigest + FixedOutputReset + Reset + Default + Clone + Absorb + Squeeze + 'static
+{
+	let msg = message();
+
+	let mut hash = D::default();
+	Absorb::absorb(&mut hash, &msg);
+	let expected = Squeeze::squeeze_boxed(hash, <D as Digest>::output_size());
+
+	assert_eq!(D::digest(&msg)[..], expected[..]);
+
+	let mut hash = <D as Digest>::new();
+	for chunk in msg.chunks(1000) {
+		Digest::update(&mut hash, chunk);
+	}
+	assert_eq!(hash.finalize_reset()[..], expected[..]);
+
+	Digest::update(&mut hash, &msg);
+	assert_eq!(Digest::finalize(hash)[..], expected[..]);
+
+	let mut hash = D::default();
+	Digest::update(&mut hash, b"discarded");
+	Digest::reset(&mut hash);
+	Digest::update(&mut hash, &msg);
+	assert_eq!(hash.finalize()[..], expected[..]);
+
+	let mut hash = D::default();
+	let dyn_hash: &mut dyn DynDigest = &mut hash;
+	assert_eq!(dyn_hash.output_size(), expected.len());
+
+	let mut out = vec![0; expected.len()];
+	dyn_hash.update(&msg);
+	dyn_hash.finalize_into_reset(&mut out).unwrap();
+	assert_eq!(out[..], expected[..]);
+
+	dyn_hash.update(&msg);
+	dyn_hash.finalize_into_reset(&mut out).unwrap();
+	assert_eq!(out[..], expected[..]);
+}
+
+fn xof<X>(hash: X)
+where
+	X: ExtendableOutput + Update + Clone + IntoSqueezer
+{
+	xof_with(hash, IntoSqueezer::squeeze_into);
+}
+
+/// Like [`xof`], with `squeeze` producing the expected output.
+fn xof_with<X>(hash: X, squeeze: fn(X, &mut [u8]))
+where
+	X: ExtendableOutput + Update + Clone
+{
+	let msg = message();
+
+	let mut expected = vec![0; 1000];
+	squeeze(hash.clone().chain(&msg), &mut expected);
+
+	let mut out = [0; 1000];
+	let mut chunked = hash.clone();
+	for chunk in msg.chunks(999) {
+		chunked.update(chunk);
+	}
+	let mut reader = chunked.finalize_xof();
+	for chunk in out.chunks_mut(7) {
+		reader.read(chunk);
+	}
+	assert_eq!(out[..], expected[..]);
+
+	let mut out = [0; 1000];
+	hash.chain(&msg).finalize_xof_into(&mut out);
+	assert_eq!(out[..], expected[..]);
+}
+
+fn xof_reset<X>()
+where
+	X: ExtendableOutputReset + Update + Reset + Default + Clone + IntoSqueezer
+{
+	xof_reset_with::<X>(IntoSqueezer::squeeze_into);
+}
+
+fn xof_reset_with<X>(squeeze: fn(X, &mut [u8]))
+where
+	X: ExtendableOutputReset + Update + Reset + Default + Clone
+{
+	xof_with(X::default(), squeeze);
+
+	let msg = message();
+	let mut expected = [0; 100];
+	X::default().chain(&msg).finalize_xof_into(&mut expected);
+
+	let mut out = [0; 100];
+	let mut hash = X::default();
+	hash.update(b"discarded");
+	hash.reset();
+	hash.update(&msg);
+	hash.finalize_xof_reset_into(&mut out);
+	assert_eq!(out, expected);
+
+	hash.update(&msg);
+	hash.finalize_xof_reset_into(&mut out);
+	assert_eq!(out, expected);
+}
+
+#[test]
+fn sha3() {
+	digest::<Sha3_224<Lsbu64<25>>>();
+	digest::<Sha3_256<Lsbu64<25>>>();
+	digest::<Sha3_384<Lsbu64<25>>>();
+	digest::<Sha3_512<Lsbu64<25>>>();
+}
+
+#[test]
+fn keccak() {
+	digest::<Keccak224<Lsbu64<25>>>();
+	digest::<Keccak256<Lsbu64<25>>>();
+	digest::<Keccak384<Lsbu64<25>>>();
+	digest::<Keccak512<Lsbu64<25>>>();
+}
+
+#[test]
+fn shake() {
+	xof_reset::<Shake128<Lsbu64<25>>>();
+	xof_reset::<Shake256<Lsbu64<25>>>();
+}
+
+#[test]
+fn turbo_shake() {
+	// The default domain separation byte 0x1F has the same bits as SHAKE's suffix.
+	xof_reset_with::<TurboShake128<Lsbu64<25>>>(|hash, buf| {
+		hash.squeeze_into(suffix!(1, 1, 1, 1), buf);
+	});
+	xof_reset_with::<TurboShake256<Lsbu64<25>>>(|hash, buf| {
+		hash.squeeze_into(suffix!(1, 1, 1, 1), buf);
+	});
+
+	xof_reset::<TurboShake128Domain<Lsbu64<25>, 0x06>>();
+	xof_reset::<TurboShake256Domain<Lsbu64<25>, 0x06>>();
+	xof(TurboShake128DynDomain::new(0x06));
+	xof(TurboShake256DynDomain::new(0x06));
+}
+
+#[test]
+fn cshake() {
+	xof(CShake128::new(b"name", b"customization"));
+	xof(CShake256::new(b"name", b"customization"));
+}
+
+#[test]
+fn parallel_hash() {
+	xof(ParallelHashXof128::new(1000, b"customization"));
+	xof(ParallelHashXof256::new(1000, b"customization"));
+}
+
+#[test]
+fn kmac() {
+	xof(KmacXof128::new(b"key", b"customization"));
+	xof(KmacXof256::new(b"key", b"customization"));
+}
+
+#[test]
+fn kangaroo_twelve() {
+	xof_reset::<Kt128<Lsbu64<25>>>();
+	xof_reset::<Kt256<Lsbu64<25>>>();
+	xof_reset::<MarsupilamiFourteen<Lsbu64<25>>>();
+	xof_reset::<BufferedKangarooTwelve<Lsbu64<25>>>();
+}
+
+#[test]
+fn kangaroo_twelve_customized() {
+	let msg = message();
+
+	let mut hash = Kt128::<Lsbu64<25>>::with_customization(b"customization");
+	xof(hash);
+
+	let expected = Kt128::new().chain(&msg).squeeze_customized::<100>(b"customization");
+
+	let mut out = [0; 100];
+	hash.update(&msg);
+	hash.finalize_xof_reset_into(&mut out);
+	assert_eq!(out, expected);
+
+	hash.update(&msg);
+	hash.finalize_xof_into(&mut out);
+	assert_eq!(out, expected);
+}
@@ -0,0 +1,127 @@
+use keccak::{
+	const_hash::{
+		k12_const, kt256_const, sha3_224_const, sha3_256_const, sha3_384_const, sha3_512_const,
+		shake128_const, shake256_const, turbo_shake128_const, turbo_shake256_const
+	},
+	k12::{KangarooTwelve, Kt256},
+	sha3::{
+		Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256, TurboShake128DynDomain,
+		TurboShake256DynDomain
+	}
+};
+
+const LENS: [usize; 8] = [0, 1, 71, 72, 135, 136, 168, 500];
+
+fn message(len: usize) -> Vec<u8> {
+	(0..len).map(|i| (i * 13 + 5) as u8).collect()
+}
+
+#[test]
+fn compile_time() {
+	const SHA3_256: [u8; 32] = sha3_256_const(b"abc");
+	const SHAKE128: [u8; 16] = shake128_const(b"");
+	const K12: [u8; 32] = k12_const(b"", b"");
+
+	assert_eq!(
+		crate::hex::encode(&SHA3_256),
+		"3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+	);
+	assert_eq!(crate::hex::encode(&SHAKE128), "7f9c2ba4e88f827d616045507605853e");
+	assert_eq!(
+		crate::hex::encode(&K12),
+		"1ac2d450fc3b4205d19da7bfca1b37513c0803577ac7167f06fe2ce1f0ef39e5"
+	);
+}
+
+#[test]
+fn sha3() {
+	for len in LENS {
+		let msg = message(len);
+
+		macro_rules! check {
+			($hash:ident, $const:ident) => {
+				let mut hash = $hash::new();
+				hash.absorb(&msg);
+				assert_eq!($const(&msg), hash.squeeze(), "len = {len}");
+			};
+		}
+
+		check!(Sha3_224, sha3_224_const);
+		check!(Sha3_256, sha3_256_const);
+		check!(Sha3_384, sha3_384_const);
+		check!(Sha3_512, sha3_512_const);
+	}
+}
+
+#[test]
+fn shake() {
+	for len in LENS {
+		let msg = message(len);
+
+		let mut hash = Shake128::new();
+		hash.absorb(&msg);
+		assert_eq!(shake128_const::<400>(&msg), hash.squeeze::<400>(), "len = {len}");
+
+		let mut hash = Shake256::new();
+		hash.absorb(&msg);
+		assert_eq!(shake256_const::<400>(&msg), hash.squeeze::<400>(), "len = {len}");
+	}
+}
+
+#[test]
+fn turbo_shake() {
+	for len in LENS {
+		let msg = message(len);
+
+		for domain in [0x01, 0x06, 0x1F, 0x7F] {
+			let mut hash = TurboShake128DynDomain::new(domain);
+			hash.absorb(&msg);
+			assert_eq!(
+				turbo_shake128_const::<200>(&msg, domain),
+				hash.squeeze::<200>(),
+				"len = {len}, domain = {domain:#x}"
+			);
+
+			let mut hash = TurboShake256DynDomain::new(domain);
+			hash.absorb(&msg);
+			assert_eq!(
+				turbo_shake256_const::<200>(&msg, domain),
+				hash.squeeze::<200>(),
+				"len = {len}, domain = {domain:#x}"
+			);
+		}
+	}
+}
+
+#[test]
+#[should_panic = "domain separation byte must be in 0x01..=0x7F"]
+fn turbo_shake_zero_domain() {
+	let _ = turbo_shake128_const::<32>(b"", 0);
+}
+
+#[test]
+fn k12() {
+	for (len, customization) in [
+		(0, &b""[..]),
+		(8191, b""),
+		(8192, b""),
+		(8190, b"ab"),
+		(8192, b"c"),
+		(3 * 8192 + 17, b""),
+		(17, &[0x5A; 300][..])
+	] {
+		let msg = message(len);
+
+		let mut hash = KangarooTwelve::new();
+		hash.absorb(&msg);
+		let mut expected = [0; 64];
+		hash.into_squeezer_customized(customization).squeeze_into(&mut expected);
+		assert_eq!(k12_const::<64>(&msg, customization), expected, "len = {len}");
+
+		let mut hash = Kt256::new();
+		hash.absorb(&msg);
+		let mut expected = [0; 64];
+		hash.into_squeezer_customized(customization).squeeze_into(&mut expected);
+		assert_eq!(kt256_const::<64>(&msg, customization), expected, "len = {len}");
+	}
+}
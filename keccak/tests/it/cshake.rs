@@ -0,0 +1,70 @@
+// https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values
+
+use keccak::sha3::{CShake128, CShake256, Shake128, Shake256};
+
+fn input(len: u8) -> Vec<u8> {
+	(0..len).collect()
+}
+
+#[test]
+fn cshake_128_sample_1() {
+	let mut cshake = CShake128::new(b"", b"Email Signature");
+	cshake.absorb(&input(4));
+
+	assert_eq!(
+		crate::hex::encode(&cshake.squeeze::<32>()),
+		"c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5"
+	);
+}
+
+#[test]
+fn cshake_128_sample_2() {
+	let mut cshake = CShake128::new(b"", b"Email Signature");
+	cshake.absorb(&input(200));
+
+	assert_eq!(
+		crate::hex::encode(&cshake.squeeze::<32>()),
+		"c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b"
+	);
+}
+
+#[test]
+fn cshake_256_sample_3() {
+	let mut cshake = CShake256::new(b"", b"Email Signature");
+	cshake.absorb(&input(4));
+
+	assert_eq!(
+		crate::hex::encode(&cshake.squeeze::<64>()),
+		"d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c"
+	);
+}
+
+#[test]
+fn cshake_256_sample_4() {
+	let mut cshake = CShake256::new(b"", b"Email Signature");
+	cshake.absorb(&input(200));
+
+	assert_eq!(
+		crate::hex::encode(&cshake.squeeze::<64>()),
+		"07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac86430273091727f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb"
+	);
+}
+
+#[test]
+fn cshake_is_shake_when_empty() {
+	let mut cshake = CShake128::new(b"", b"");
+	cshake.absorb(b"abc");
+
+	let mut shake = Shake128::new();
+	shake.absorb(b"abc");
+
+	assert_eq!(cshake.squeeze::<64>(), shake.squeeze::<64>());
+
+	let mut cshake = CShake256::new(b"", b"");
+	cshake.absorb(b"abc");
+
+	let mut shake = Shake256::new();
+	shake.absorb(b"abc");
+
+	assert_eq!(cshake.squeeze::<64>(), shake.squeeze::<64>());
+}
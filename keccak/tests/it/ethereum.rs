@@ -0,0 +1,96 @@
+use keccak::sha3::{Keccak224, Keccak256, Keccak384, Keccak512};
+
+#[test]
+fn empty() {
+	assert_eq!(
+		crate::hex::encode(&Keccak224::new().squeeze()),
+		"f71837502ba8e10837bdd8d365adb85591895602fc552b48b7390abd"
+	);
+
+	assert_eq!(
+		crate::hex::encode(&Keccak256::new().squeeze()),
+		"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+	);
+
+	assert_eq!(
+		crate::hex::encode(&Keccak384::new().squeeze()),
+		"2c23146a63a29acf99e73b88f8c24eaa7dc60aa771780ccc006afbfa8fe2479b2dd2b21362337441ac12b515911957ff"
+	);
+
+	assert_eq!(
+		crate::hex::encode(&Keccak512::new().squeeze()),
+		"0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"
+	);
+}
+
+#[test]
+fn quick_brown_fox() {
+	const INPUT: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+	let mut keccak = Keccak224::new();
+	keccak.absorb(INPUT);
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"310aee6b30c47350576ac2873fa89fd190cdc488442f3ef654cf23fe"
+	);
+
+	let mut keccak = Keccak256::new();
+	keccak.absorb(INPUT);
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15"
+	);
+
+	let mut keccak = Keccak384::new();
+	keccak.absorb(INPUT);
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"283990fa9d5fb731d786c5bbee94ea4db4910f18c62c03d173fc0a5e494422e8a0b3da7574dae7fa0baf005e504063b3"
+	);
+
+	let mut keccak = Keccak512::new();
+	keccak.absorb(INPUT);
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"d135bb84d0439dbac432247ee573a23ea7d3c9deb2a968eb31d47c4fb45f1ef4422d6c531b5b9bd6f449ebcc449ea94d0a8f05f62130fda612da53c79659f609"
+	);
+}
+
+fn selector(signature: &str) -> String {
+	let mut keccak = Keccak256::new();
+	keccak.absorb(signature.as_bytes());
+	crate::hex::encode(&keccak.squeeze()[..4])
+}
+
+#[test]
+fn function_selectors() {
+	assert_eq!(selector("transfer(address,uint256)"), "a9059cbb");
+	assert_eq!(selector("balanceOf(address)"), "70a08231");
+	assert_eq!(selector("approve(address,uint256)"), "095ea7b3");
+	assert_eq!(selector("transferFrom(address,address,uint256)"), "23b872dd");
+}
+
+#[test]
+fn event_topic() {
+	let mut keccak = Keccak256::new();
+	keccak.absorb(b"Transfer(address,address,uint256)");
+
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+	);
+}
+
+#[test]
+fn multi_block() {
+	let mut keccak = Keccak256::new();
+
+	for _ in 0..200 {
+		keccak.absorb(&[0]);
+	}
+
+	assert_eq!(
+		crate::hex::encode(&keccak.squeeze()),
+		"e1bb54e1bc3af48d01e5dbfc81015c98152a574f6428c6948aa4837c9c0baad9"
+	);
+}
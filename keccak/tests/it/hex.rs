@@ -10,7 +10,7 @@ pub fn decode(s: &str) -> Vec<u8> {
 		}
 	}
 
-	assert!(s.len() % 2 == 0);
+	assert!(s.len().is_multiple_of(2));
 	let s = s.as_bytes();
 
 	let mut vec = Vec::with_capacity(s.len() / 2);
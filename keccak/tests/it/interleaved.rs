@@ -0,0 +1,127 @@
+use keccak::{
+	interleaved::{deinterleave, interleave, Interleaved},
+	k12::KangarooTwelve,
+	keccakp::{keccakp_1600, Keccak1600},
+	sha3::{Sha3_256, Sha3_512, Shake128, Shake256, TurboShake128}
+};
+use sponge::{state::Lsbu64, suffix, State};
+
+fn pattern(count: usize) -> Vec<u8> {
+	(0x00..=0xFA).cycle().take(count).collect()
+}
+
+#[test]
+fn interleave_roundtrip() {
+	for x in [0, 1, 2, 1 << 63, 0xAAAA_AAAA_AAAA_AAAA, 0x0123_4567_89AB_CDEF, u64::MAX] {
+		let [even, odd] = interleave(x);
+
+		for i in 0..32 {
+			assert_eq!(even >> i & 1, (x >> (2 * i) & 1) as u32);
+			assert_eq!(odd >> i & 1, (x >> (2 * i + 1) & 1) as u32);
+		}
+
+		assert_eq!(deinterleave([even, odd]), x);
+	}
+}
+
+#[test]
+fn permutation() {
+	let lanes: [u64; 25] =
+		core::array::from_fn(|i| (i as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+	let mut expected = lanes;
+	keccakp_1600(&mut expected, &Keccak1600::<24>::RCS);
+	let mut state = Interleaved::from_lanes(lanes);
+	state.permute::<Keccak1600<24>>();
+	assert_eq!(state.into_lanes(), expected);
+
+	let mut expected = lanes;
+	keccakp_1600(&mut expected, &Keccak1600::<12>::RCS);
+	let mut state = Interleaved::from_lanes(lanes);
+	state.permute::<Keccak1600<12>>();
+	assert_eq!(state.into_lanes(), expected);
+}
+
+#[test]
+fn byte_view() {
+	let mut reference = Lsbu64::<25>::default();
+	let mut state = Interleaved::<25>::default();
+
+	for (index, len) in [(0, 200), (3, 17), (8, 8), (13, 1), (190, 10), (199, 1)] {
+		let buf = pattern(len);
+
+		reference.xor_in_u8_slice(index, &buf);
+		state.xor_in_u8_slice(index, &buf);
+		reference.xor_in_u8(index + len / 2, 0x5A);
+		state.xor_in_u8(index + len / 2, 0x5A);
+
+		let (mut expected, mut actual) = ([0; 200], [0; 200]);
+		reference.get_u8_slice(0, &mut expected);
+		state.get_u8_slice(0, &mut actual);
+		assert_eq!(actual, expected);
+
+		let (mut expected, mut actual) = (buf.clone(), buf.clone());
+		reference.xor_out_u8_slice(index, &mut expected);
+		state.xor_out_u8_slice(index, &mut actual);
+		assert_eq!(actual, expected);
+
+		reference.set_u8_slice(200 - len, &buf);
+		state.set_u8_slice(200 - len, &buf);
+		reference.set_u8_repeated(index, 0x33, len / 3);
+		state.set_u8_repeated(index, 0x33, len / 3);
+
+		let (mut expected, mut actual) = ([0; 200], [0; 200]);
+		reference.get_u8_slice(0, &mut expected);
+		state.get_u8_slice(0, &mut actual);
+		assert_eq!(actual, expected);
+	}
+}
+
+#[test]
+fn hashes() {
+	let input = pattern(3 * 8192 + 1000);
+
+	for len in [0, 1, 135, 136, 137, 1000, 8192, 8193, input.len()] {
+		let input = &input[..len];
+
+		let mut reference = Sha3_256::new();
+		let mut hash = Sha3_256::<Interleaved<25>>::default();
+		reference.absorb(input);
+		hash.absorb(input);
+		assert_eq!(hash.squeeze(), reference.squeeze(), "len = {len}");
+
+		let mut reference = Sha3_512::new();
+		let mut hash = Sha3_512::<Interleaved<25>>::default();
+		reference.absorb(input);
+		hash.absorb(input);
+		assert_eq!(hash.squeeze(), reference.squeeze(), "len = {len}");
+
+		let mut reference = Shake128::new();
+		let mut hash = Shake128::<Interleaved<25>>::default();
+		reference.absorb(input);
+		hash.absorb(input);
+		assert_eq!(hash.squeeze::<500>(), reference.squeeze::<500>(), "len = {len}");
+
+		let mut reference = Shake256::new();
+		let mut hash = Shake256::<Interleaved<25>>::default();
+		reference.absorb(input);
+		hash.absorb(input);
+		assert_eq!(hash.squeeze::<300>(), reference.squeeze::<300>(), "len = {len}");
+
+		let mut reference = TurboShake128::new();
+		let mut hash = TurboShake128::<Interleaved<25>>::default();
+		reference.absorb(input);
+		hash.absorb(input);
+		assert_eq!(
+			hash.into_squeezer(suffix!(1, 1, 0)).squeeze::<200>(),
+			reference.into_squeezer(suffix!(1, 1, 0)).squeeze::<200>(),
+			"len = {len}"
+		);
+
+		let mut reference = KangarooTwelve::new();
+		let mut hash = KangarooTwelve::<Interleaved<25>>::default();
+		reference.absorb(input);
+		input.chunks(1000).for_each(|chunk| hash.absorb(chunk));
+		assert_eq!(hash.squeeze::<64>(), reference.squeeze::<64>(), "len = {len}");
+	}
+}
@@ -1,6 +1,7 @@
 // https://github.com/XKCP/XKCP/blob/master/tests/TestVectors/KangarooTwelve.txt
+// https://www.rfc-editor.org/rfc/rfc9861#section-5
 
-use keccak::k12::KangarooTwelve;
+use keccak::k12::{KangarooTwelve, Kt256, MarsupilamiFourteen};
 
 fn pattern(count: usize) -> Vec<u8> {
 	(0x00..=0xFA).cycle().take(count).collect()
@@ -24,6 +25,24 @@ fn hash(customization: &[u8], input: &[u8], skip: usize, output: &mut [u8]) {
 	squeezer.squeeze_into(output);
 }
 
+fn hash_kt256(customization: &[u8], input: &[u8], skip: usize, output: &mut [u8]) {
+	let mut absorber = Kt256::new();
+	absorber.absorb(input);
+
+	let mut squeezer = if customization.is_empty() {
+		absorber.into_squeezer()
+	} else {
+		absorber.into_squeezer_customized(customization)
+	};
+
+	if skip != 0 {
+		let mut skip = vec![0; skip];
+		squeezer.squeeze_into(&mut skip);
+	}
+
+	squeezer.squeeze_into(output);
+}
+
 #[track_caller]
 fn test(customization: usize, input: &[u8], skip: usize, expected: &str) {
 	let mut output = vec![0; expected.len() / 2];
@@ -31,6 +50,13 @@ fn test(customization: usize, input: &[u8], skip: usize, expected: &str) {
 	assert_eq!(super::hex::encode(&output), expected);
 }
 
+#[track_caller]
+fn test_kt256(customization: usize, input: &[u8], skip: usize, expected: &str) {
+	let mut output = vec![0; expected.len() / 2];
+	hash_kt256(&pattern(customization), input, skip, &mut output);
+	assert_eq!(super::hex::encode(&output), expected);
+}
+
 #[test]
 fn k12_ref() {
 	let pattern = pattern(16 << 10);
@@ -159,3 +185,279 @@ fn k12_14() {
 		"75d2f86a2e644566726b4fbcfc5657b9dbcf070c7b0dca06450ab291d7443bcf"
 	);
 }
+
+#[test]
+fn k12_chunk_boundaries() {
+	test(0, &pattern(8191), 0, "1b577636f723643e990cc7d6a659837436fd6a103626600eb8301cd1dbe553d6");
+	test(0, &pattern(8192), 0, "48f256f6772f9edfb6a8b661ec92dc93b95ebd05a08a17b39ae3490870c926c3");
+
+	test(
+		8189,
+		&pattern(8192),
+		0,
+		"3ed12f70fb05ddb58689510ab3e4d23c6c6033849aa01e1d8c220a297fedcd0b"
+	);
+
+	test(
+		8190,
+		&pattern(8192),
+		0,
+		"6a7c1b6a5cd0d8c9ca943a4a216cc64604559a2ea45f78570a15253d67ba00ae"
+	);
+}
+
+#[test]
+fn kt256_01() {
+	test_kt256(
+		0,
+		&[],
+		0,
+		"b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9"
+	);
+}
+
+#[test]
+fn kt256_02() {
+	test_kt256(
+		0,
+		&[],
+		0,
+		"b23d2e9cea9f4904e02bec06817fc10ce38ce8e93ef4c89e6537076af8646404e3e8b68107b8833a5d30490aa33482353fd4adc7148ecb782855003aaebde4a9b0925319d8ea1e121a609821ec19efea89e6d08daee1662b69c840289f188ba860f55760b61f82114c030c97e5178449608ccd2cd2d919fc7829ff69931ac4d0"
+	);
+}
+
+#[test]
+fn kt256_03() {
+	test_kt256(
+		0,
+		&[],
+		10_032 - 64,
+		"b4456a955bb89a72fb87189201714d1fc0bb44a50a3423de2b1bf33b40ff8b1cad4a1d718cf950506709a4c33396139b4449041fc79a05d68da35f1e453522e0"
+	);
+}
+
+#[test]
+fn kt256_04() {
+	test_kt256(
+		0,
+		&pattern(1),
+		0,
+		"0d005a194085360217128cf17f91e1f71314efa5564539d444912e3437efa17f82db6f6ffe76e781eaa068bce01f2bbf81eacb983d7230f2fb02834a21b1ddd0"
+	);
+}
+
+#[test]
+fn kt256_05() {
+	test_kt256(
+		0,
+		&pattern(17),
+		0,
+		"1ba3c02b1fc514474f06c8979978a9056c8483f4a1b63d0dccefe3a28a2f323e1cdcca40ebf006ac76ef0397152346837b1277d3e7faa9c9653b19075098527b"
+	);
+}
+
+#[test]
+fn kt256_06() {
+	test_kt256(
+		0,
+		&pattern(17_usize.pow(2)),
+		0,
+		"de8ccbc63e0f133ebb4416814d4c66f691bbf8b6a61ec0a7700f836b086cb029d54f12ac7159472c72db118c35b4e6aa213c6562caaa9dcc518959e69b10f3ba"
+	);
+}
+
+#[test]
+fn kt256_07() {
+	test_kt256(
+		0,
+		&pattern(17_usize.pow(3)),
+		0,
+		"647efb49fe9d717500171b41e7f11bd491544443209997ce1c2530d15eb1ffbb598935ef954528ffc152b1e4d731ee2683680674365cd191d562bae753b84aa5"
+	);
+}
+
+#[test]
+fn kt256_08() {
+	test_kt256(
+		0,
+		&pattern(17_usize.pow(4)),
+		0,
+		"b06275d284cd1cf205bcbe57dccd3ec1ff6686e3ed15776383e1f2fa3c6ac8f08bf8a162829db1a44b2a43ff83dd89c3cf1ceb61ede659766d5ccf817a62ba8d"
+	);
+}
+
+#[test]
+fn kt256_09() {
+	test_kt256(
+		0,
+		&pattern(17_usize.pow(5)),
+		0,
+		"9473831d76a4c7bf77ace45b59f1458b1673d64bcd877a7c66b2664aa6dd149e60eab71b5c2bab858c074ded81ddce2b4022b5215935c0d4d19bf511aeeb0772"
+	);
+}
+
+#[test]
+fn kt256_10() {
+	test_kt256(
+		0,
+		&pattern(17_usize.pow(6)),
+		0,
+		"0652b740d78c5e1f7c8dcc1777097382768b7ff38f9a7a20f29f413bb1b3045b31a5578f568f911e09cf44746da84224a5266e96a4a535e871324e4f9c7004da"
+	);
+}
+
+#[test]
+fn kt256_11() {
+	test_kt256(
+		1,
+		&[],
+		0,
+		"9280f5cc39b54a5a594ec63de0bb99371e4609d44bf845c2f5b8c316d72b159811f748f23e3fabbe5c3226ec96c62186df2d33e9df74c5069ceecbb4dd10eff6"
+	);
+}
+
+#[test]
+fn kt256_12() {
+	test_kt256(
+		41,
+		&[0xFF],
+		0,
+		"47ef96dd616f200937aa7847e34ec2feae8087e3761dc0f8c1a154f51dc9ccf845d7adbce57ff64b639722c6a1672e3bf5372d87e00aff89be97240756998853"
+	);
+}
+
+#[test]
+fn kt256_13() {
+	test_kt256(
+		41_usize.pow(2),
+		&[0xFF; 3],
+		0,
+		"3b48667a5051c5966c53c5d42b95de451e05584e7806e2fb765eda959074172cb438a9e91dde337c98e9c41bed94c4e0aef431d0b64ef2324f7932caa6f54969"
+	);
+}
+
+#[test]
+fn kt256_14() {
+	test_kt256(
+		41_usize.pow(3),
+		&[0xFF; 7],
+		0,
+		"e0911cc00025e1540831e266d94add9b98712142b80d2629e643aac4efaf5a3a30a88cbf4ac2a91a2432743054fbcc9897670e86ba8cec2fc2ace9c966369724"
+	);
+}
+
+#[test]
+fn kt256_chunk_boundaries() {
+	test_kt256(
+		0,
+		&pattern(8191),
+		0,
+		"3081434d93a4108d8d8a3305b89682cebedc7ca4ea8a3ce869fbb73cbe4a58eef6f24de38ffc170514c70e7ab2d01f03812616e863d769afb3753193ba045b20"
+	);
+
+	test_kt256(
+		0,
+		&pattern(8192),
+		0,
+		"c6ee8e2ad3200c018ac87aaa031cdac22121b412d07dc6e0dccbb53423747e9a1c18834d99df596cf0cf4b8dfafb7bf02d139d0c9035725adc1a01b7230a41fa"
+	);
+
+	test_kt256(
+		8189,
+		&pattern(8192),
+		0,
+		"74e47879f10a9c5d11bd2da7e194fe57e86378bf3c3f7448eff3c576a0f18c5caae0999979512090a7f348af4260d4de3c37f1ecaf8d2c2c96c1d16c64b12496"
+	);
+
+	test_kt256(
+		8190,
+		&pattern(8192),
+		0,
+		"f4b5908b929ffe01e0f79ec2f21243d41a396b2e7303a6af1d6399cd6c7a0a2dd7c4f607e8277f9c9b1cb4ab9ddc59d4b92d1fc7558441f1832c3279a4241b8b"
+	);
+}
+
+// Whole chunks absorbed at once are hashed several leaves at a time, while small writes go
+// through the scalar leaf sponge.
+#[test]
+fn lockstep_leaves() {
+	let input = pattern(12 * 8192 + 100);
+
+	for leaves in 0..=10 {
+		for extra in [1, 100] {
+			let input = &input[..(leaves + 1) * 8192 + extra];
+
+			let mut bulk = KangarooTwelve::new();
+			let mut scalar = KangarooTwelve::new();
+			bulk.absorb(input);
+			input.chunks(1000).for_each(|chunk| scalar.absorb(chunk));
+			assert_eq!(bulk.squeeze::<32>(), scalar.squeeze::<32>(), "leaves = {leaves}");
+
+			let mut bulk = Kt256::new();
+			let mut scalar = Kt256::new();
+			bulk.absorb(input);
+			input.chunks(1000).for_each(|chunk| scalar.absorb(chunk));
+			assert_eq!(bulk.squeeze::<64>(), scalar.squeeze::<64>(), "leaves = {leaves}");
+
+			let mut bulk = MarsupilamiFourteen::new();
+			let mut scalar = MarsupilamiFourteen::new();
+			bulk.absorb(input);
+			input.chunks(1000).for_each(|chunk| scalar.absorb(chunk));
+			assert_eq!(bulk.squeeze::<64>(), scalar.squeeze::<64>(), "leaves = {leaves}");
+		}
+	}
+}
+
+// The leaves hashed in lockstep live in the state type of the hash.
+#[test]
+fn lockstep_leaves_states() {
+	use keccak::{interleaved::Interleaved, k12::Kt128};
+	use sponge::state::{Lsbu64, SecretState};
+
+	let input = pattern(7 * 8192 + 100);
+
+	let mut plain = Kt128::<Lsbu64<25>>::new();
+	let mut secret = Kt128::<SecretState<Lsbu64<25>>>::default();
+	let mut interleaved = Kt128::<Interleaved<25>>::default();
+	plain.absorb(&input);
+	secret.absorb(&input);
+	interleaved.absorb(&input);
+
+	let expected = plain.squeeze::<32>();
+	assert_eq!(secret.squeeze::<32>(), expected);
+	assert_eq!(interleaved.squeeze::<32>(), expected);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn k12_parallel() {
+	use keccak::k12::{hash_parallel, BufferedKangarooTwelve};
+	use sponge::state::Lsbu64;
+
+	let input = pattern(9 << 20 | 1234);
+
+	for len in [0, 1, 8 << 10, (8 << 10) + 1, 3 << 20, input.len()] {
+		let input = &input[..len];
+
+		let mut k12 = KangarooTwelve::new();
+		for chunk in input.chunks(1000) {
+			k12.absorb(chunk);
+		}
+		let expected = k12.squeeze_customized::<32>(b"custom");
+
+		let parallel = hash_parallel(input).squeeze::<32>();
+		let mut k12 = KangarooTwelve::new();
+		k12.absorb(input);
+		assert_eq!(parallel, k12.squeeze::<32>());
+
+		let mut buffered = BufferedKangarooTwelve::<Lsbu64<25>>::with_capacity(100 << 10);
+		for chunk in input.chunks(1000) {
+			buffered.absorb(chunk);
+		}
+		assert_eq!(buffered.into_squeezer_customized(b"custom").squeeze::<32>(), expected);
+
+		let mut buffered = BufferedKangarooTwelve::new();
+		buffered.absorb(input);
+		assert_eq!(buffered.into_squeezer_customized(b"custom").squeeze::<32>(), expected);
+	}
+}
@@ -0,0 +1,187 @@
+use keccak::kdf::*;
+
+// HMAC-SHA3 with the inputs from the NIST example values, keyed with 0x00, 0x01, ...
+
+fn key(len: usize) -> Vec<u8> {
+	(0..len).map(|i| i as u8).collect()
+}
+
+macro_rules! hmac_test {
+	($name:ident: $hmac:ident, $short:expr, $block:expr, $long:expr => $a:literal, $b:literal, $c:literal) => {
+		#[test]
+		fn $name() {
+			for (len, msg, expected) in [
+				($short, &b"Sample message for keylen<blocklen"[..], $a),
+				($block, b"Sample message for keylen=blocklen", $b),
+				($long, b"Sample message for keylen>blocklen", $c)
+			] {
+				let mut hmac = $hmac::new(&key(len));
+				hmac.absorb(msg);
+				assert_eq!(crate::hex::encode(&hmac.squeeze()), expected, "key length {len}");
+			}
+		}
+	};
+}
+
+hmac_test!(hmac_sha3_224: HmacSha3_224, 28, 144, 172 =>
+	"332cfd59347fdb8e576e77260be4aba2d6dc53117b3bfb52c6d18c04",
+	"d8b733bcf66c644a12323d564e24dcf3fc75f231f3b67968359100c7",
+	"078695eecc227c636ad31d063a15dd05a7e819a66ec6d8de1e193e59");
+
+hmac_test!(hmac_sha3_256: HmacSha3_256, 32, 136, 168 =>
+	"4fe8e202c4f058e8dddc23d8c34e467343e23555e24fc2f025d598f558f67205",
+	"68b94e2e538a9be4103bebb5aa016d47961d4d1aa906061313b557f8af2c3faa",
+	"9bcf2c238e235c3ce88404e813bd2f3a97185ac6f238c63d6229a00b07974258");
+
+hmac_test!(hmac_sha3_384: HmacSha3_384, 48, 104, 152 =>
+	"d588a3c51f3f2d906e8298c1199aa8ff6296218127f6b38a90b6afe2c5617725bc99987f79b22a557b6520db710b7f42",
+	"a27d24b592e8c8cbf6d4ce6fc5bf62d8fc98bf2d486640d9eb8099e24047837f5f3bffbe92dcce90b4ed5b1e7e44fa90",
+	"e5ae4c739f455279368ebf36d4f5354c95aa184c899d3870e460ebc288ef1f9470053f73f7c6da2a71bcaec38ce7d6ac");
+
+hmac_test!(hmac_sha3_512: HmacSha3_512, 64, 72, 136 =>
+	"4efd629d6c71bf86162658f29943b1c308ce27cdfa6db0d9c3ce81763f9cbce5f7ebe9868031db1a8f8eb7b6b95e5c5e3f657a8996c86a2f6527e307f0213196",
+	"544e257ea2a3e5ea19a590e6a24b724ce6327757723fe2751b75bf007d80f6b360744bf1b7a88ea585f9765b47911976d3191cf83c039f5ffab0d29cc9d9b6da",
+	"5f464f5e5b7848e3885e49b2c385f0694985d0e38966242dc4a5fe3fea4b37d46b65ceced5dcf59438dd840bab22269f0ba7febdb9fcf74602a35666b2a32915");
+
+// HKDF with the inputs of the RFC 5869 test cases.
+
+const IKM_1: &str = "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b";
+const SALT_1: &str = "000102030405060708090a0b0c";
+const INFO_1: &str = "f0f1f2f3f4f5f6f7f8f9";
+
+macro_rules! hkdf_test {
+	($name:ident: $hkdf:ident, $ikm:expr, $salt:expr, $info:expr => $prk:literal, $okm:literal) => {
+		#[test]
+		fn $name() {
+			let (ikm, salt, info) =
+				(crate::hex::decode($ikm), crate::hex::decode($salt), crate::hex::decode($info));
+
+			let prk = $hkdf::<sponge::state::Lsbu64<25>>::extract(&salt, &ikm);
+			assert_eq!(crate::hex::encode(&prk), $prk);
+
+			let mut okm = vec![0; $okm.len() / 2];
+			$hkdf::new(&salt, &ikm).expand(&info, &mut okm);
+			assert_eq!(crate::hex::encode(&okm), $okm);
+		}
+	};
+}
+
+hkdf_test!(hkdf_sha3_224: HkdfSha3_224, IKM_1, SALT_1, INFO_1 =>
+	"af44657dfc9946f90d9ff007d083fb106c289171021aad2be48801fb",
+	"5058867fc7bdb118ce6a703add6edbf8e2ce21f5766cfc2e662e1a36ff6922fa96fc149517cf1e451fe6");
+
+hkdf_test!(hkdf_sha3_256_1: HkdfSha3_256, IKM_1, SALT_1, INFO_1 =>
+	"7d4194836f7a113a44677abc825640ade07af1c1d69a9a4b109b280a8fe54ef0",
+	"0c5160501d65021deaf2c14f5abce04c5bd2635abceeba61c2edb6e8ed72674900557728f2c9f2c4c179");
+
+hkdf_test!(hkdf_sha3_256_2: HkdfSha3_256,
+	"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f",
+	"606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+	"b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff" =>
+	"addf31835b49366ac27734104d9f1865c1c2e7c8a2ebc1fed712808e4eab677c",
+	"3dc251e66c75da6560405ec5ac10e17d851eedfbfdc13feafbec16964c25d021bd971465a3e9c615f27769019e3f0407d84986fb0ba24e729c99834624baa21cb623dc0098f430d52e18bbdf694df4edd8b2");
+
+hkdf_test!(hkdf_sha3_256_3: HkdfSha3_256, IKM_1, "", "" =>
+	"b899e6e4b88a35f9f5d618f48b424c313f9704012763eb6295414d673365928a",
+	"bc1342cdd75c05e8b0c3ae609ce4410684d197232875073499b30cdfe2de2853c1c1bed63d725e885e78");
+
+hkdf_test!(hkdf_sha3_384: HkdfSha3_384, IKM_1, SALT_1, INFO_1 =>
+	"7855bc9300a4db532c9cab2593796e1a4bbb77a24d417e66822beaa36fabd412515dcf388810adf27fa23d3d7def84ca",
+	"138d8521e5a346a9cb770f762b9c04d9ca317409fb6a3ef9cb905228385589ae883bbe8b07b009f0e08b");
+
+hkdf_test!(hkdf_sha3_512: HkdfSha3_512, IKM_1, SALT_1, INFO_1 =>
+	"e1c543094f64f3d6c6658a94a94e3818ba13d0b3e77074b80f88f32e6b8433b703536cb500753967fae2ea977e11e4dd4f45389807cdf255b395e46807c87d5d",
+	"40e9f17e9bf2ef99425c2b23ccdf20a018ea5513f9ae68e1ea8c626deb57dfa4d56c27ccf2a2a24488a51e93231829f776f8b8830647d925b8eebe08d63fb711699b200c1ad5c7a40baeef2df7157f3c5e0b874843a8494c68e9cb794ec8c447a6ce6cd6");
+
+#[test]
+fn hkdf_max_output_len() {
+	let hkdf = HkdfSha3_512::new(b"salt", b"ikm");
+	let mut okm = vec![0; HkdfSha3_512::<sponge::state::Lsbu64<25>>::MAX_OUTPUT_LEN];
+	hkdf.expand(b"", &mut okm);
+
+	assert_eq!(okm[..100], {
+		let mut okm = [0; 100];
+		hkdf.expand(b"", &mut okm);
+		okm
+	});
+}
+
+#[test]
+#[should_panic = "output keying material is too long"]
+fn hkdf_too_long() {
+	let mut okm = vec![0; 255 * 32 + 1];
+	HkdfSha3_256::new(b"salt", b"ikm").expand(b"", &mut okm);
+}
+
+#[test]
+#[should_panic = "pseudorandom key is shorter than the hash output"]
+fn hkdf_short_prk() {
+	let _ = HkdfSha3_256::<sponge::state::Lsbu64<25>>::from_prk(&[0; 31]);
+}
+
+#[test]
+fn kmac_kdf() {
+	let key =
+		crate::hex::decode("404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f");
+
+	let mut out = [0; 42];
+	kmac128_kdf(&key, b"label", b"context", &mut out);
+	assert_eq!(
+		crate::hex::encode(&out),
+		"efa6233ed3f3a70938a5bc1b79cdd7b4a841321164fa9364d3b86ae9b4e24d6ab1bfca7c12003caf211d"
+	);
+
+	kmac256_kdf(&key, b"label", b"context", &mut out);
+	assert_eq!(
+		crate::hex::encode(&out),
+		"8743fe6e76f8d99a20d1e4867d7b03cd807f51e27934280fb95e8ea1353ee2573c636a0fba99997be3ac"
+	);
+}
+
+#[cfg(feature = "digest")]
+mod compat {
+	use digest::{core_api::CoreWrapper, Mac};
+	use keccak::{kdf::*, sha3::Sha3_256};
+	use sponge::state::Lsbu64;
+
+	type Sha3 = Sha3_256<Lsbu64<25>>;
+
+	#[test]
+	fn hmac() {
+		for len in [0, 32, 136, 137, 300] {
+			let key = super::key(len);
+
+			let mut ours = HmacSha3_256::new(&key);
+			ours.absorb(b"message");
+			let ours = ours.squeeze();
+
+			let mut simple = hmac::SimpleHmac::<Sha3>::new_from_slice(&key).unwrap();
+			simple.update(b"message");
+			assert_eq!(simple.finalize().into_bytes()[..], ours);
+
+			let mut wrapped = hmac::Hmac::<CoreWrapper<Sha3>>::new_from_slice(&key).unwrap();
+			wrapped.update(b"message");
+			assert_eq!(wrapped.finalize().into_bytes()[..], ours);
+
+			let mut mac = <HmacSha3_256<Lsbu64<25>> as Mac>::new_from_slice(&key).unwrap();
+			mac.update(b"message");
+			mac.verify_slice(&ours).unwrap();
+		}
+	}
+
+	#[test]
+	fn hkdf() {
+		let mut ours = [0; 100];
+		HkdfSha3_256::new(b"salt", b"ikm").expand(b"info", &mut ours);
+
+		let mut simple = [0; 100];
+		hkdf::SimpleHkdf::<Sha3>::new(Some(b"salt"), b"ikm").expand(b"info", &mut simple).unwrap();
+		assert_eq!(simple, ours);
+
+		let mut wrapped = [0; 100];
+		hkdf::Hkdf::<CoreWrapper<Sha3>>::new(Some(b"salt"), b"ikm")
+			.expand(b"info", &mut wrapped)
+			.unwrap();
+		assert_eq!(wrapped, ours);
+	}
+}
@@ -147,3 +147,31 @@ fn keccakf_200() {
 		]
 	);
 }
+
+#[test]
+fn keccakp_1600_rounds() {
+	use sponge::Permutation;
+
+	fn check<const ROUNDS: usize>() {
+		let mut state: [u64; 25] =
+			core::array::from_fn(|i| (i as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+		let mut unrolled = state;
+		let mut expected = state;
+
+		for _ in 0..2 {
+			Keccak1600::<ROUNDS>::permute(&mut state);
+			keccakp_1600_unrolled(&mut unrolled, &Keccak1600::<ROUNDS>::RCS);
+			keccakp_1600(&mut expected, &Keccak1600::<ROUNDS>::RCS);
+			assert_eq!(state, expected, "rounds = {ROUNDS}");
+			assert_eq!(unrolled, expected, "rounds = {ROUNDS}");
+		}
+	}
+
+	macro_rules! check {
+		($($rounds:literal)*) => {
+			$(check::<$rounds>();)*
+		};
+	}
+
+	check!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24);
+}
@@ -0,0 +1,67 @@
+// https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values
+
+use keccak::kmac::*;
+
+const KEY: &str = "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f";
+const X1: &str = "00010203";
+
+fn x2() -> Vec<u8> {
+	(0..=0xC7).collect()
+}
+
+#[test]
+fn kmac128_sample_1() {
+	let mut kmac = Kmac128::new(&crate::hex::decode(KEY), b"");
+	kmac.absorb(&crate::hex::decode(X1));
+
+	assert_eq!(
+		crate::hex::encode(&kmac.squeeze::<32>()),
+		"e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"
+	);
+}
+
+#[test]
+fn kmac128_sample_2() {
+	let mut kmac = Kmac128::new(&crate::hex::decode(KEY), b"My Tagged Application");
+	kmac.absorb(&crate::hex::decode(X1));
+
+	assert_eq!(
+		crate::hex::encode(&kmac.squeeze::<32>()),
+		"3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"
+	);
+}
+
+#[test]
+fn kmac256_sample_5() {
+	let mut kmac = Kmac256::new(&crate::hex::decode(KEY), b"My Tagged Application");
+	kmac.absorb(&x2());
+
+	assert_eq!(
+		crate::hex::encode(&kmac.squeeze::<64>()),
+		"b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d970fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965"
+	);
+}
+
+#[test]
+fn kmac_xof256_sample_4() {
+	let mut kmac = KmacXof256::new(&crate::hex::decode(KEY), b"My Tagged Application");
+	kmac.absorb(&crate::hex::decode(X1));
+
+	assert_eq!(
+		crate::hex::encode(&kmac.squeeze::<64>()),
+		"1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa96faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b"
+	);
+}
+
+#[test]
+fn output_length_is_bound() {
+	let key = crate::hex::decode(KEY);
+
+	let short = Kmac128::new(&key, b"").squeeze::<32>();
+	let long = Kmac128::new(&key, b"").squeeze::<64>();
+	let xof = KmacXof128::new(&key, b"").squeeze::<64>();
+
+	assert_ne!(short, long[..32]);
+	assert_ne!(long, xof);
+	assert_eq!(xof[..32], KmacXof128::new(&key, b"").squeeze::<32>());
+}
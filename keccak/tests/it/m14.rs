@@ -0,0 +1,121 @@
+// These are not published vectors: they were generated with this implementation, using the
+// message and customization patterns of the KangarooTwelve vectors in k12.rs. m14_01, m14_07,
+// m14_08 and m14_12 have also been reproduced with an independent implementation.
+
+use keccak::k12::MarsupilamiFourteen;
+
+fn pattern(count: usize) -> Vec<u8> {
+	(0x00..=0xFA).cycle().take(count).collect()
+}
+
+#[track_caller]
+fn test(customization: usize, input: &[u8], skip: usize, expected: &str) {
+	let mut absorber = MarsupilamiFourteen::new();
+	absorber.absorb(input);
+
+	let mut squeezer = if customization == 0 {
+		absorber.into_squeezer()
+	} else {
+		absorber.into_squeezer_customized(&pattern(customization))
+	};
+
+	if skip != 0 {
+		let mut skip = vec![0; skip];
+		squeezer.squeeze_into(&mut skip);
+	}
+
+	let mut output = vec![0; expected.len() / 2];
+	squeezer.squeeze_into(&mut output);
+
+	assert_eq!(super::hex::encode(&output), expected);
+}
+
+#[test]
+fn m14_01() {
+	test(0, &[], 0, "6f66ef1474eb53807aa329257c768bb88893d9f086e51da2f5c80d17ca0fc57d");
+}
+
+#[test]
+fn m14_02() {
+	test(
+		0,
+		&[],
+		0,
+		"6f66ef1474eb53807aa329257c768bb88893d9f086e51da2f5c80d17ca0fc57d5a24fac879014f8b30a3fdf5ac56ebafa219eb891d4bbbab7e1df3b27205b459"
+	);
+}
+
+#[test]
+fn m14_03() {
+	test(0, &[], 10_000, "c09322de1513d0cd604728f36d11adff58b93f776381095a071921eafb30e1e3");
+}
+
+#[test]
+fn m14_04() {
+	test(0, &pattern(1), 0, "cc05ebc928156c7a03540085355c47c6aea1d07dc811cdded0e4c367f8d99368");
+}
+
+#[test]
+fn m14_05() {
+	test(0, &pattern(17), 0, "aa764fd8b38f19976a305cb007f19384b210a5c7b0fc4499d6f83c6227bff850");
+}
+
+#[test]
+fn m14_06() {
+	test(
+		0,
+		&pattern(17_usize.pow(2)),
+		0,
+		"f18a6e250b1cc83dea89ffbb4de56a8e70041c71fc5b17a2aaab05c606aa6bf2"
+	);
+}
+
+#[test]
+fn m14_07() {
+	test(
+		0,
+		&pattern(17_usize.pow(3)),
+		0,
+		"0ac89b11a06f46b2f6feeff046c97e90dc02910ae509b8739cfea5df1df90b82"
+	);
+}
+
+#[test]
+fn m14_08() {
+	test(
+		0,
+		&pattern(17_usize.pow(4)),
+		0,
+		"35af0a5fc6c4d111fbc68f879d05506aafd300b5ab136986d7aed8a9f1be331e"
+	);
+}
+
+#[test]
+fn m14_09() {
+	test(
+		0,
+		&pattern(17_usize.pow(5)),
+		0,
+		"0c982c5d5334e27cc6591cda308dfa6b4fdd736aadbe64536bdef83c1d496ba0"
+	);
+}
+
+#[test]
+fn m14_10() {
+	test(1, &[], 0, "e6c23ceeab2089d14dc3b088fdfe6d4418bf8a6f330fb3edcc300cd81e1bef2f");
+}
+
+#[test]
+fn m14_11() {
+	test(41, &[0xFF], 0, "2bab75b31b8c3049abeb7674774771b64f59225be20e930ebdbf8e37c24fad69");
+}
+
+#[test]
+fn m14_12() {
+	test(
+		41_usize.pow(2),
+		&[0xFF; 3],
+		0,
+		"732a60c308bebf5f7b3d3e8f0d26e324c04bab4197ca0a608b0befaa25ea5976"
+	);
+}
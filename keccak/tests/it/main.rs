@@ -1,5 +1,23 @@
+mod acvp;
+mod backend;
+mod conformance;
+mod const_hash;
+mod cshake;
+mod ethereum;
+mod expand_msg;
 mod hex;
+mod interleaved;
 mod k12;
 mod kat;
+mod kdf;
 mod keccakf;
+mod kmac;
+mod m14;
+mod multi;
+mod parallel_hash;
+mod sakura;
+mod sample;
 mod sha3;
+mod squeeze_block;
+mod stream;
+mod turbo_shake;
@@ -0,0 +1,107 @@
+use keccak::{
+	keccakp::Keccak1600,
+	multi::{Sha3_256x4, Shake128x4},
+	sha3::{Sha3_256, Shake128}
+};
+use sponge::{
+	multi::MultiSponge,
+	state::{Lsbu64, SecretState},
+	suffix
+};
+
+fn message(lane: usize, len: usize) -> Vec<u8> {
+	(0..len).map(|i| (i * 7 + lane) as u8).collect()
+}
+
+fn shake128(msg: &[u8], len: usize) -> Vec<u8> {
+	let mut hash = Shake128::new();
+	hash.absorb(msg);
+	let mut out = vec![0; len];
+	hash.into_squeezer().squeeze_into(&mut out);
+	out
+}
+
+#[test]
+fn shake128x4() {
+	for lens in [[0, 0, 0, 0], [0, 1, 167, 168], [169, 336, 1000, 3], [500, 500, 500, 500]] {
+		let msgs = [0, 1, 2, 3].map(|lane| message(lane, lens[lane]));
+
+		let mut hash = Shake128x4::new();
+		hash.absorb([&msgs[0][..10.min(lens[0])], &msgs[1], &[], &msgs[3][..lens[3] / 2]]);
+		hash.absorb([&msgs[0][10.min(lens[0])..], &[], &msgs[2], &msgs[3][lens[3] / 2..]]);
+
+		let out_lens = [1, 168, 400, 0];
+		let mut outs = out_lens.map(|len| vec![0; len]);
+		let mut squeezer = hash.into_squeezer();
+		let [a, b, c, d] = &mut outs;
+		squeezer.squeeze_into([&mut a[..], &mut b[..100], &mut c[..], &mut d[..]]);
+		squeezer.squeeze_into([&mut [], &mut b[100..], &mut [], &mut []]);
+
+		for lane in 0..4 {
+			assert_eq!(outs[lane], shake128(&msgs[lane], out_lens[lane]), "lens = {lens:?}");
+		}
+
+		let next = squeezer.squeeze::<200>();
+		for lane in 0..4 {
+			assert_eq!(
+				next[lane][..],
+				shake128(&msgs[lane], out_lens[lane] + 200)[out_lens[lane]..],
+				"lens = {lens:?}"
+			);
+		}
+	}
+}
+
+#[test]
+fn sha3_256x4() {
+	let msgs = [0, 135, 136, 1000].map(|len| message(len, len));
+
+	let mut hash = Sha3_256x4::new();
+	hash.absorb([&msgs[0], &msgs[1], &msgs[2], &msgs[3]]);
+	let digests = hash.squeeze();
+
+	for (msg, digest) in msgs.iter().zip(digests) {
+		let mut hash = Sha3_256::new();
+		hash.absorb(msg);
+		assert_eq!(digest, hash.squeeze());
+	}
+}
+
+#[test]
+fn x3() {
+	let msgs = [0, 300, 17].map(|len| message(len, len));
+
+	let mut hash = MultiSponge::<Lsbu64<25>, Keccak1600<24>, 3, { 128 * 2 / 8 }>::default();
+	hash.absorb([&msgs[0], &msgs[1], &msgs[2]]);
+	let outs = hash.squeeze::<300>(suffix!(1, 1, 1, 1));
+
+	for (msg, out) in msgs.iter().zip(outs) {
+		assert_eq!(out[..], shake128(msg, 300));
+	}
+}
+
+#[test]
+fn x2() {
+	let msgs = [200, 5].map(|len| message(len, len));
+
+	let mut hash = MultiSponge::<Lsbu64<25>, Keccak1600<24>, 2, { 128 * 2 / 8 }>::default();
+	hash.absorb([&msgs[0], &msgs[1]]);
+	let outs = hash.squeeze::<200>(suffix!(1, 1, 1, 1));
+
+	for (msg, out) in msgs.iter().zip(outs) {
+		assert_eq!(out[..], shake128(msg, 200));
+	}
+}
+
+#[test]
+fn secret_state() {
+	let msgs = [3, 168, 169, 400].map(|len| message(len, len));
+
+	let mut hash = Shake128x4::<SecretState<Lsbu64<25>>>::default();
+	hash.absorb([&msgs[0], &msgs[1], &msgs[2], &msgs[3]]);
+	let outs = hash.squeeze::<200>();
+
+	for (msg, out) in msgs.iter().zip(outs) {
+		assert_eq!(out[..], shake128(msg, 200));
+	}
+}
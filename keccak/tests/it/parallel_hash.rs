@@ -0,0 +1,139 @@
+// https://csrc.nist.gov/projects/cryptographic-standards-and-guidelines/example-values
+
+use keccak::parallel_hash::*;
+
+const X1: &str = "000102030405060710111213141516172021222324252627";
+const X2: &str = "000102030405060708090a0b101112131415161718191a1b202122232425262728292a2b303132333435363738393a3b404142434445464748494a4b505152535455565758595a5b";
+
+macro_rules! test {
+	($name:ident: $input:expr, $block_len:expr, $customization:expr => $fixed:literal, $xof:literal) => {
+		mod $name {
+			use super::*;
+
+			#[test]
+			fn fixed() {
+				let input = crate::hex::decode($input);
+
+				let mut hash = ParallelHash::new($block_len, $customization);
+				hash.absorb(&input);
+
+				let mut output = vec![0; $fixed.len() / 2];
+				hash.squeeze_into(&mut output);
+
+				assert_eq!(crate::hex::encode(&output), $fixed);
+			}
+
+			#[test]
+			fn xof() {
+				let input = crate::hex::decode($input);
+
+				let mut hash = ParallelHashXof::new($block_len, $customization);
+				hash.absorb(&input);
+
+				let mut output = vec![0; $xof.len() / 2];
+				hash.squeeze_into(&mut output);
+
+				assert_eq!(crate::hex::encode(&output), $xof);
+			}
+		}
+	};
+}
+
+mod parallel_hash_128 {
+	use keccak::parallel_hash::{
+		ParallelHash128 as ParallelHash, ParallelHashXof128 as ParallelHashXof
+	};
+
+	use super::{X1, X2};
+
+	test!(sample_1: X1, 8, b"" =>
+		"ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5",
+		"fe47d661e49ffe5b7d999922c062356750caf552985b8e8ce6667f2727c3c8d3"
+	);
+
+	test!(sample_2: X1, 8, b"Parallel Data" =>
+		"fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206",
+		"ea2a793140820f7a128b8eb70a9439f93257c6e6e79b4a540d291d6dae7098d7"
+	);
+
+	test!(sample_3: X2, 12, b"Parallel Data" =>
+		"f7fd5312896c6685c828af7e2adb97e393e7f8d54e3c2ea4b95e5aca3796e8fc",
+		"0127ad9772ab904691987fcc4a24888f341fa0db2145e872d4efd255376602f0"
+	);
+
+	test!(empty: "", 8, b"" =>
+		"96427c30224408859f95e89e4fa84e1c7a1478dbf2008ac982ce61a77f37a272",
+		"2b0ba71802defd1a1594f6410c8598dd86c8332b39e7e80bd7a7bc35ae16ca42"
+	);
+}
+
+mod parallel_hash_256 {
+	use keccak::parallel_hash::{
+		ParallelHash256 as ParallelHash, ParallelHashXof256 as ParallelHashXof
+	};
+
+	use super::{X1, X2};
+
+	test!(sample_4: X1, 8, b"" =>
+		"bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c451105531b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429",
+		"c10a052722614684144d28474850b410757e3cba87651ba167a5cbddff7f466675fbf84bcae7378ac444be681d729499afca667fb879348bfdda427863c82f1c"
+	);
+
+	test!(sample_5: X1, 8, b"Parallel Data" =>
+		"cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110",
+		"538e105f1a22f44ed2f5cc1674fbd40be803d9c99bf5f8d90a2c8193f3fe6ea768e5c1a20987e2c9c65febed03887a51d35624ed12377594b5585541dc377efc"
+	);
+
+	test!(sample_6: X2, 12, b"Parallel Data" =>
+		"69d0fcb764ea055dd09334bc6021cb7e4b61348dff375da262671cdec3effa8d1b4568a6cce16b1cad946ddde27f6ce2b8dee4cd1b24851ebf00eb90d43813e9",
+		"6b3e790b330c889a204c2fbc728d809f19367328d852f4002dc829f73afd6bcefb7fe5b607b13a801c0be5c1170bdb794e339458fdb0e62a6af3d42558970249"
+	);
+}
+
+fn pattern(count: usize) -> Vec<u8> {
+	(0x00..=0xFA).cycle().take(count).collect()
+}
+
+#[test]
+fn streaming() {
+	let input = pattern(100_000);
+
+	for split in [0, 1, 7, 8, 9, 4095, 50_000] {
+		let mut hash = ParallelHash128::new(8, b"c");
+		hash.absorb(&input[..split]);
+
+		for chunk in input[split..].chunks(13) {
+			hash.absorb(chunk);
+		}
+
+		assert_eq!(
+			crate::hex::encode(&hash.squeeze::<32>()),
+			"df3e35dfbec6dc2f55acf5a977d6a9b868cfe65183e85490a96c63290a728fac"
+		);
+
+		let mut hash = ParallelHashXof256::new(8 << 10, b"c");
+		hash.absorb(&input[..split]);
+		hash.absorb(&input[split..]);
+
+		assert_eq!(
+			crate::hex::encode(&hash.squeeze::<64>()),
+			"531e9aac1026c61b5f7209c840d2064dc2efbca805ce40d1e27c4de7db3d6e3f93f8a232faeb06792f01df6bdad579f867cb6ece85d53f15bf09bff897327f76"
+		);
+	}
+}
+
+#[test]
+fn large() {
+	let input = pattern(4 << 20);
+
+	let mut one_shot = ParallelHash128::new(64, b"");
+	one_shot.absorb(&input);
+
+	let mut streamed = ParallelHash128::new(64, b"");
+
+	for chunk in input.chunks(1000) {
+		streamed.absorb(chunk);
+	}
+
+	assert_eq!(one_shot.squeeze::<32>(), streamed.squeeze::<32>());
+}
@@ -0,0 +1,161 @@
+// Tree hashes over TurboSHAKE128 with 64-byte chunks, checked against `model`, which builds each
+// node from the Sakura coding directly.
+
+use keccak::{keccakp::Keccak1600, sha3::TurboShake128DynDomain};
+use sponge::{
+	sakura::{FanOut, Hops, Kangaroo, Tree, TwoLevel},
+	state::Lsbu64
+};
+
+type TurboTree<H> = Tree<Lsbu64<25>, Keccak1600<12>, H, { 128 * 2 / 8 }, 64, 32>;
+
+fn pattern(count: usize) -> Vec<u8> {
+	(0x00..=0xFA).cycle().take(count).collect()
+}
+
+/// A node hashed with its frame bits and padding as the TurboSHAKE domain byte.
+fn node(buf: &[u8], domain: u8) -> [u8; 32] {
+	let mut hash = TurboShake128DynDomain::<Lsbu64<25>>::new(domain);
+	hash.absorb(buf);
+	hash.into_squeezer().squeeze()
+}
+
+/// The chaining values of `nodes` followed by their number and the chaining hop suffix.
+fn chaining_values(nodes: &[[u8; 32]]) -> Vec<u8> {
+	let n = nodes.len() as u64;
+	let len = 8 - n.leading_zeros() as usize / 8;
+
+	let mut buf = nodes.concat();
+	buf.extend_from_slice(&n.to_be_bytes()[8 - len..]);
+	buf.extend_from_slice(&[len as u8, 0xFF, 0xFF]);
+	buf
+}
+
+fn model<H>(msg: &[u8]) -> [u8; 32]
+where
+	H: Hops
+{
+	if msg.len() <= 64 {
+		return node(msg, 0x07);
+	}
+
+	let (mut root, leaves) = if H::KANGAROO {
+		let (first, rest) = msg.split_at(64);
+		([first, &[0x03, 0, 0, 0, 0, 0, 0, 0]].concat(), rest)
+	} else {
+		(Vec::new(), msg)
+	};
+
+	let cvs: Vec<_> = leaves.chunks(64).map(|leaf| node(leaf, 0x0B)).collect();
+	let cvs = if H::FAN_OUT == 0 {
+		cvs
+	} else {
+		cvs.chunks(H::FAN_OUT as usize).map(|cvs| node(&chaining_values(cvs), 0x04)).collect()
+	};
+
+	root.extend_from_slice(&chaining_values(&cvs));
+	node(&root, 0x06)
+}
+
+fn check<H>(len: usize, expected: &str)
+where
+	H: Hops
+{
+	let msg = pattern(len);
+	assert_eq!(crate::hex::encode(&model::<H>(&msg)), expected, "len = {len}, model");
+
+	let mut tree = TurboTree::<H>::default();
+	tree.absorb(&msg);
+	assert_eq!(crate::hex::encode(&tree.squeeze::<32>()), expected, "len = {len}");
+
+	let mut tree = TurboTree::<H>::default();
+	msg.iter().for_each(|&b| tree.absorb_u8(b));
+	assert_eq!(crate::hex::encode(&tree.squeeze::<32>()), expected, "len = {len}, bytewise");
+
+	let mut tree = TurboTree::<H>::default();
+	msg.chunks(7).for_each(|chunk| tree.absorb(chunk));
+	assert_eq!(crate::hex::encode(&tree.squeeze::<32>()), expected, "len = {len}, chunked");
+}
+
+fn check_model<H>()
+where
+	H: Hops
+{
+	let msg = pattern(64 * 11 + 1);
+
+	for len in 0..=msg.len() {
+		let mut tree = TurboTree::<H>::default();
+		tree.absorb(&msg[..len]);
+		assert_eq!(tree.squeeze::<32>(), model::<H>(&msg[..len]), "len = {len}");
+	}
+}
+
+#[test]
+fn kangaroo_model() {
+	check_model::<Kangaroo>();
+}
+
+#[test]
+fn two_level_model() {
+	check_model::<TwoLevel>();
+}
+
+#[test]
+fn fan_out_model() {
+	check_model::<FanOut<1>>();
+	check_model::<FanOut<3>>();
+}
+
+macro_rules! test {
+	($name:ident: $hops:ty, $len:expr => $expected:literal) => {
+		#[test]
+		fn $name() {
+			check::<$hops>($len, $expected);
+		}
+	};
+}
+
+test!(kangaroo_0: Kangaroo, 0 =>
+	"5a223ad30b3b8c66a243048cfced430f54e7529287d15150b973133adfac6a2f");
+test!(kangaroo_64: Kangaroo, 64 =>
+	"c70fac5a8ef61f8a858117a463bf87e0171c974bfe5fe80a77a2daa638ae1b2e");
+test!(kangaroo_65: Kangaroo, 65 =>
+	"b1d5d4926b4eea06746353bbdb7eda793dd43fe899e4b2f66fc8bca0d17f40de");
+test!(kangaroo_128: Kangaroo, 128 =>
+	"2bf31b8676af45d9ac3f46d2c14f34c5f7d2e2c42443451a53e24e8d4a2db3e1");
+test!(kangaroo_129: Kangaroo, 129 =>
+	"59f03d6bf49a1e5d5d95fb4b11dd8dd421c69e4e079612edf163e891f551a912");
+test!(kangaroo_197: Kangaroo, 197 =>
+	"cfca119df8895b2797302c10d7bda8badb46db2c20d61a4e92e1ad9782b521ac");
+test!(kangaroo_1000: Kangaroo, 1000 =>
+	"d6f75e1888d2318407458f2d7aa1474a135b6bfa9ac20aeab56cdac7d68bc86c");
+
+test!(two_level_0: TwoLevel, 0 =>
+	"5a223ad30b3b8c66a243048cfced430f54e7529287d15150b973133adfac6a2f");
+test!(two_level_64: TwoLevel, 64 =>
+	"c70fac5a8ef61f8a858117a463bf87e0171c974bfe5fe80a77a2daa638ae1b2e");
+test!(two_level_65: TwoLevel, 65 =>
+	"5b83ecf44e42c471314a76cdbf751ee7507b0cba0410c367930d4ec5029bc7bb");
+test!(two_level_128: TwoLevel, 128 =>
+	"becc6f92ca3955cd6990b933028c6271e6cdf83f20f7cf08a16bd827e79fa060");
+test!(two_level_129: TwoLevel, 129 =>
+	"269b4eb3c6c0f05973bc0802571e6107a6586688f0e4b7487db21e1d2c2d8d48");
+test!(two_level_197: TwoLevel, 197 =>
+	"dffb31df0c8e7d5869f0d139bef4f918650fd4837058e1b49cee3e872b027474");
+test!(two_level_1000: TwoLevel, 1000 =>
+	"a65211c86e2a335ebfb3ddc6cfbb07e9bbc32377d7d462727196d4f52242ae64");
+
+test!(fan_out_3_0: FanOut<3>, 0 =>
+	"5a223ad30b3b8c66a243048cfced430f54e7529287d15150b973133adfac6a2f");
+test!(fan_out_3_64: FanOut<3>, 64 =>
+	"c70fac5a8ef61f8a858117a463bf87e0171c974bfe5fe80a77a2daa638ae1b2e");
+test!(fan_out_3_65: FanOut<3>, 65 =>
+	"afc02af0bbf7352be7e42c8c275c18f768f1f91dec955584087b2162ea8495d5");
+test!(fan_out_3_128: FanOut<3>, 128 =>
+	"3c54b25281e3a6fe7779cf19aae8a35095fa384e8671e851e4bc24fd8397215f");
+test!(fan_out_3_129: FanOut<3>, 129 =>
+	"300521d22dacd89bbbd72705bc8492dbf1192c63eab9b595b670de11c9891096");
+test!(fan_out_3_197: FanOut<3>, 197 =>
+	"888a20f10d6c8b5f1e939a4b584294185d9f0c40a7f5ae50135364fe7c5e6eaa");
+test!(fan_out_3_1000: FanOut<3>, 1000 =>
+	"428eeae502075fa49f0d620f230abbf993e2ca79048fe6233763d250fe5c39a7");
@@ -0,0 +1,110 @@
+use keccak::sha3::{Shake128, Shake128Squeezer};
+use sponge::{
+	sample::{
+		centered_binomial, rejection_sample_12, rejection_sample_23, shuffle, uniform_below_u128,
+		uniform_below_u32, uniform_below_u64
+	},
+	state::Lsbu64
+};
+
+fn squeezer(seed: &[u8]) -> Shake128Squeezer<Lsbu64<25>> {
+	let mut hash = Shake128::new();
+	hash.absorb(seed);
+	hash.into_squeezer()
+}
+
+/// Checks that `squeezer` has consumed `len` bytes of the output for `seed`.
+fn assert_consumed(squeezer: &mut Shake128Squeezer<Lsbu64<25>>, seed: &[u8], len: usize) {
+	let mut expected = self::squeezer(seed);
+	expected.skip(len as u64);
+	assert_eq!(squeezer.squeeze::<8>(), expected.squeeze::<8>());
+}
+
+#[test]
+fn uniform_below() {
+	let mut squeezer = squeezer(b"uniform");
+
+	let u32s: [u32; 8] = core::array::from_fn(|_| uniform_below_u32(&mut squeezer, 1000));
+	assert_eq!(u32s, [101, 57, 766, 580, 924, 604, 443, 55]);
+
+	let u64s: [u64; 4] =
+		core::array::from_fn(|_| uniform_below_u64(&mut squeezer, 10_u64.pow(15) + 7));
+	assert_eq!(
+		u64s,
+		[916_529_237_863_774, 598_510_811_505_355, 144_267_472_409_878, 600_073_706_363_302]
+	);
+
+	let u128s: [u128; 4] = core::array::from_fn(|_| uniform_below_u128(&mut squeezer, 3));
+	assert_eq!(u128s, [0, 1, 2, 0]);
+
+	assert_eq!(crate::hex::encode(&squeezer.squeeze::<8>()), "5bec659cfc9dd4dc");
+}
+
+#[test]
+fn uniform_below_one() {
+	let mut squeezer = squeezer(b"");
+	assert!((0..100).all(|_| uniform_below_u32(&mut squeezer, 1) == 0));
+}
+
+#[test]
+#[should_panic = "upper bound must be non-zero"]
+fn uniform_below_zero() {
+	let _ = uniform_below_u64(&mut squeezer(b""), 0);
+}
+
+#[test]
+fn sample_ntt() {
+	let seed = [&core::array::from_fn::<u8, 32, _>(|i| i as u8)[..], &[1, 2]].concat();
+	let mut squeezer = squeezer(&seed);
+
+	let mut out = [0; 256];
+	rejection_sample_12(&mut squeezer, 3329, &mut out);
+	assert_eq!(out[..8], [1642, 1316, 3309, 3204, 1436, 289, 1683, 2323]);
+	assert_eq!(out[252..], [1181, 1973, 2847, 1454]);
+	assert_eq!(out.iter().map(|&x| u32::from(x)).sum::<u32>(), 426_074);
+
+	assert_consumed(&mut squeezer, &seed, 453);
+}
+
+#[test]
+fn rej_ntt_poly() {
+	let seed = [&core::array::from_fn::<u8, 32, _>(|i| i as u8)[..], &[3, 0]].concat();
+	let mut squeezer = squeezer(&seed);
+
+	let mut out = [0; 256];
+	rejection_sample_23(&mut squeezer, 8_380_417, &mut out);
+	assert_eq!(out[..4], [4_690_948, 6_811_205, 1_451_388, 4_936_679]);
+	assert_eq!(out[254..], [3_234_208, 1_831_591]);
+	assert_eq!(out.iter().map(|&x| u64::from(x)).sum::<u64>(), 1_063_397_858);
+
+	assert_consumed(&mut squeezer, &seed, 768);
+}
+
+#[test]
+fn centered_binomial_eta() {
+	let mut squeezer = squeezer(b"cbd");
+	let mut out = [0; 256];
+
+	centered_binomial(&mut squeezer, 2, &mut out);
+	assert_eq!(out[..10], [0, -2, 1, -2, 1, -1, 0, -1, 0, 0]);
+	assert_eq!(out.iter().map(|&x| i32::from(x)).sum::<i32>(), 31);
+	assert_eq!(out.iter().map(|&x| i32::from(x).abs()).sum::<i32>(), 197);
+
+	centered_binomial(&mut squeezer, 3, &mut out);
+	assert_eq!(out[..10], [0, 1, -2, 0, 1, -1, 0, 1, -2, 0]);
+	assert_eq!(out.iter().map(|&x| i32::from(x)).sum::<i32>(), -71);
+	assert_eq!(out.iter().map(|&x| i32::from(x).abs()).sum::<i32>(), 255);
+
+	assert_consumed(&mut squeezer, b"cbd", 320);
+}
+
+#[test]
+fn fisher_yates() {
+	let mut squeezer = squeezer(b"shuffle");
+	let mut slice: [u8; 10] = core::array::from_fn(|i| i as u8);
+
+	shuffle(&mut squeezer, &mut slice);
+	assert_eq!(slice, [7, 3, 9, 2, 6, 4, 1, 5, 8, 0]);
+
+	assert_consumed(&mut squeezer, b"shuffle", 112);
+}
@@ -0,0 +1,61 @@
+use keccak::sha3::{Shake128, Shake256, TurboShake128Domain, TurboShake256Domain};
+use sponge::state::{Lsbu64, SecretState};
+
+macro_rules! test {
+	($name:ident: $hash:ident, $state:ty, $rate:expr) => {
+		mod $name {
+			use super::*;
+
+			const RATE: usize = $rate;
+
+			fn squeezer() -> <$hash<$state> as sponge::IntoSqueezer>::Squeezer {
+				let mut hash = $hash::<$state>::default();
+				hash.absorb(b"block");
+				hash.into_squeezer()
+			}
+
+			fn output() -> [u8; 4 * RATE] {
+				squeezer().squeeze()
+			}
+
+			#[test]
+			fn next_block() {
+				let output = output();
+				let mut squeezer = squeezer();
+
+				assert_eq!(squeezer.next_block(), &output[..RATE]);
+				assert_eq!(squeezer.next_block(), &output[RATE..][..RATE]);
+
+				assert_eq!(squeezer.squeeze::<10>(), output[2 * RATE..][..10]);
+				assert_eq!(squeezer.next_block(), &output[2 * RATE + 10..3 * RATE]);
+				assert_eq!(squeezer.next_block(), &output[3 * RATE..]);
+			}
+
+			#[test]
+			fn squeeze_blocks() {
+				let output = output();
+				let mut squeezer = squeezer();
+				let mut buf = [0; 4 * RATE + 1];
+
+				squeezer.squeeze_blocks(1, &mut buf);
+				assert_eq!(buf[..RATE], output[..RATE]);
+
+				assert_eq!(squeezer.squeeze::<1>(), output[RATE..][..1]);
+				squeezer.squeeze_blocks(2, &mut buf);
+				assert_eq!(buf[..2 * RATE], output[RATE + 1..][..2 * RATE]);
+				assert_eq!(buf[2 * RATE..], [0; 2 * RATE + 1][..]);
+			}
+		}
+	};
+}
+
+test!(shake128: Shake128, Lsbu64<25>, 168);
+test!(shake256: Shake256, Lsbu64<25>, 136);
+test!(turbo_shake128: TurboShake128Domain, Lsbu64<25>, 168);
+test!(turbo_shake256: TurboShake256Domain, SecretState<Lsbu64<25>>, 136);
+
+#[test]
+#[should_panic = "output buffer is shorter than the requested blocks"]
+fn squeeze_blocks_short() {
+	Shake128::new().into_squeezer().squeeze_blocks(2, &mut [0; 2 * 168 - 1]);
+}
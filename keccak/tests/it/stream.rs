@@ -0,0 +1,105 @@
+use keccak::{
+	sha3::{Shake128, Shake256, TurboShake128Domain, TurboShake256Domain},
+	stream::{Shake128Cipher, Shake256Cipher, TurboShake128Cipher, TurboShake256Cipher}
+};
+use sponge::state::Lsbu64;
+
+const LEN: usize = 1000;
+
+macro_rules! test {
+	($name:ident: $cipher:ident, $hash:ident, $key_len:literal, $nonce_len:literal) => {
+		mod $name {
+			use super::*;
+
+			const KEY: [u8; $key_len] = [0x42; $key_len];
+			const NONCE: [u8; $nonce_len] = [0x24; $nonce_len];
+
+			fn keystream() -> [u8; LEN] {
+				let mut hash = $hash::<Lsbu64<25>>::default();
+				hash.absorb(&KEY);
+				hash.absorb(&NONCE);
+				hash.into_squeezer().squeeze()
+			}
+
+			#[test]
+			fn apply_keystream() {
+				let plaintext: [u8; LEN] = core::array::from_fn(|i| i as u8);
+				let expected: [u8; LEN] = core::array::from_fn(|i| plaintext[i] ^ keystream()[i]);
+
+				let mut buf = plaintext;
+				let mut cipher = $cipher::new(&KEY, &NONCE);
+				for chunk in buf.chunks_mut(7) {
+					cipher.apply_keystream(chunk);
+				}
+				assert_eq!(buf, expected);
+				assert_eq!(cipher.position(), LEN as u64);
+
+				$cipher::new(&KEY, &NONCE).apply_keystream(&mut buf);
+				assert_eq!(buf, plaintext);
+			}
+
+			#[test]
+			fn seek() {
+				let keystream = keystream();
+				let mut cipher = $cipher::new(&KEY, &NONCE);
+
+				for position in [500, 0, 1, 167, 168, 169, 136, 335, 999, 3] {
+					let mut buf = [0; 1];
+					cipher.seek(position as u64);
+					cipher.apply_keystream(&mut buf);
+					assert_eq!(buf[0], keystream[position], "position = {position}");
+					assert_eq!(cipher.position(), position as u64 + 1);
+				}
+
+				let mut buf = [0; 2];
+				cipher.seek(998);
+				cipher.seek(u64::MAX);
+				cipher.apply_keystream(&mut []);
+				cipher.seek(400);
+				cipher.apply_keystream(&mut buf);
+				assert_eq!(buf, keystream[400..402]);
+			}
+
+			#[cfg(feature = "cipher")]
+			#[test]
+			fn stream_cipher() {
+				use cipher::{KeyIvInit, StreamCipher, StreamCipherSeek};
+
+				let keystream = keystream();
+				let mut cipher =
+					<$cipher<Lsbu64<25>> as KeyIvInit>::new(&KEY.into(), &NONCE.into());
+
+				StreamCipherSeek::seek(&mut cipher, 300_u32);
+				assert_eq!(cipher.current_pos::<u64>(), 300);
+
+				let input = [0xFF; 400];
+				let mut output = [0; 400];
+				cipher.apply_keystream_b2b(&input, &mut output).unwrap();
+				assert!(output.iter().zip(&keystream[300..700]).all(|(&o, &k)| o == !k));
+
+				let mut buf = [0; 400];
+				cipher.seek(300_u64);
+				StreamCipher::apply_keystream(&mut cipher, &mut buf);
+				assert_eq!(buf, keystream[300..700]);
+				assert_eq!(cipher.current_pos::<u32>(), 700);
+
+				assert!(cipher.try_seek(-1_i32).is_err());
+
+				// Seeking to the end of the keystream is free, and no byte is left there.
+				cipher.try_seek(u64::MAX).unwrap();
+				assert_eq!(cipher.current_pos::<u64>(), u64::MAX);
+				assert!(cipher.try_apply_keystream(&mut [0; 1]).is_err());
+
+				let mut buf = [0; 100];
+				cipher.seek(900_u64);
+				StreamCipher::apply_keystream(&mut cipher, &mut buf);
+				assert_eq!(buf, keystream[900..]);
+			}
+		}
+	};
+}
+
+test!(shake128: Shake128Cipher, Shake128, 16, 16);
+test!(shake256: Shake256Cipher, Shake256, 32, 32);
+test!(turbo_shake128: TurboShake128Cipher, TurboShake128Domain, 16, 16);
+test!(turbo_shake256: TurboShake256Cipher, TurboShake256Domain, 32, 32);
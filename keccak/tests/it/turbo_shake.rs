@@ -0,0 +1,116 @@
+// https://www.rfc-editor.org/rfc/rfc9861#section-5
+
+use keccak::sha3::{
+	TurboShake128Domain, TurboShake128DynDomain, TurboShake256Domain, TurboShake256DynDomain
+};
+use sponge::state::Lsbu64;
+
+fn pattern(count: usize) -> Vec<u8> {
+	(0x00..=0xFA).cycle().take(count).collect()
+}
+
+macro_rules! test {
+	($name:ident: $domain:ident, $dyn_domain:ident, $input:expr, $d:literal, $skip:expr => $expected:literal) => {
+		#[test]
+		fn $name() {
+			let input: Vec<u8> = $input;
+			let mut expected = [0; $expected.len() / 2];
+
+			let mut hash = $domain::<Lsbu64<25>, $d>::new();
+			hash.absorb(&input);
+			let mut squeezer = hash.into_squeezer();
+			squeezer.squeeze_into(&mut [0; $skip]);
+			squeezer.squeeze_into(&mut expected);
+			assert_eq!(crate::hex::encode(&expected), $expected);
+
+			let mut hash = $dyn_domain::new($d);
+			hash.absorb(&input);
+			let mut squeezer = hash.into_squeezer();
+			squeezer.squeeze_into(&mut [0; $skip]);
+			squeezer.squeeze_into(&mut expected);
+			assert_eq!(crate::hex::encode(&expected), $expected);
+		}
+	};
+}
+
+test!(turbo_shake128_empty_32: TurboShake128Domain, TurboShake128DynDomain, vec![], 0x1F, 0 =>
+	"1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c");
+test!(turbo_shake128_empty_64: TurboShake128Domain, TurboShake128DynDomain, vec![], 0x1F, 0 =>
+	"1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c3e8ccae2a4dae56c84a04c2385c03c15e8193bdf58737363321691c05462c8df");
+test!(turbo_shake128_empty_10032: TurboShake128Domain, TurboShake128DynDomain, vec![], 0x1F, 10000 =>
+	"a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607");
+test!(turbo_shake128_ptn_1: TurboShake128Domain, TurboShake128DynDomain, pattern(1), 0x1F, 0 =>
+	"55cedd6f60af7bb29a4042ae832ef3f58db7299f893ebb9247247d856958daa9");
+test!(turbo_shake128_ptn_17: TurboShake128Domain, TurboShake128DynDomain, pattern(17), 0x1F, 0 =>
+	"9c97d036a3bac819db70ede0ca554ec6e4c2a1a4ffbfd9ec269ca6a111161233");
+test!(turbo_shake128_ptn_17_2: TurboShake128Domain, TurboShake128DynDomain, pattern(17 * 17), 0x1F, 0 =>
+	"96c77c279e0126f7fc07c9b07f5cdae1e0be60bdbe10620040e75d7223a624d2");
+test!(turbo_shake128_ptn_17_3: TurboShake128Domain, TurboShake128DynDomain, pattern(17usize.pow(3)), 0x1F, 0 =>
+	"d4976eb56bcf118520582b709f73e1d6853e001fdaf80e1b13e0d0599d5fb372");
+test!(turbo_shake128_ptn_17_4: TurboShake128Domain, TurboShake128DynDomain, pattern(17usize.pow(4)), 0x1F, 0 =>
+	"da67c7039e98bf530cf7a37830c6664e14cbab7f540f58403b1b82951318ee5c");
+test!(turbo_shake128_ptn_17_5: TurboShake128Domain, TurboShake128DynDomain, pattern(17usize.pow(5)), 0x1F, 0 =>
+	"b97a906fbf83ef7c812517abf3b2d0aea0c4f60318ce11cf103925127f59eecd");
+test!(turbo_shake128_d01: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF; 3], 0x01, 0 =>
+	"bf323f940494e88ee1c540fe660be8a0c93f43d15ec006998462fa994eed5dab");
+test!(turbo_shake128_d06: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF], 0x06, 0 =>
+	"8ec9c66465ed0d4a6c35d13506718d687a25cb05c74cca1e42501abd83874a67");
+test!(turbo_shake128_d07: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF; 3], 0x07, 0 =>
+	"b658576001cad9b1e5f399a9f77723bba05458042d68206f7252682dba3663ed");
+test!(turbo_shake128_d0b: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF; 7], 0x0B, 0 =>
+	"8deeaa1aec47ccee569f659c21dfa8e112db3cee37b18178b2acd805b799cc37");
+test!(turbo_shake128_d30: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF], 0x30, 0 =>
+	"553122e2135e363c3292bed2c6421fa232bab03daa07c7d6636603286506325b");
+test!(turbo_shake128_d7f: TurboShake128Domain, TurboShake128DynDomain, vec![0xFF; 3], 0x7F, 0 =>
+	"16274cc656d44cefd422395d0f9053bda6d28e122aba15c765e5ad0e6eaf26f9");
+
+test!(turbo_shake256_empty_64: TurboShake256Domain, TurboShake256DynDomain, vec![], 0x1F, 0 =>
+	"367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0");
+test!(turbo_shake256_empty_128: TurboShake256Domain, TurboShake256DynDomain, vec![], 0x1F, 0 =>
+	"367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db06c0618043e89afee80f552390fdd09f45c51953be5f0904ac669c6ab50b885c9f5717c743a7a78bf0b72ed4b9a7f51c7a0a06876f0d0526b68b8368ce563c770");
+test!(turbo_shake256_empty_10032: TurboShake256Domain, TurboShake256DynDomain, vec![], 0x1F, 10000 =>
+	"abefa11630c661269249742685ec082f207265dccf2f43534e9c61ba0c9d1d75");
+test!(turbo_shake256_ptn_1: TurboShake256Domain, TurboShake256DynDomain, pattern(1), 0x1F, 0 =>
+	"3e1712f928f8eaf1054632b2aa0a246ed8b0c378728f60bc970410155c28820e90cc90d8a3006aa2372c5c5ea176b0682bf22bae7467ac94f74d43d39b0482e2");
+test!(turbo_shake256_ptn_17: TurboShake256Domain, TurboShake256DynDomain, pattern(17), 0x1F, 0 =>
+	"b3bab0300e6a191fbe6137939835923578794ea54843f5011090fa2f3780a9e5cb22c59d78b40a0fbff9e672c0fbe0970bd2c845091c6044d687054da5d8e9c7");
+test!(turbo_shake256_ptn_17_2: TurboShake256Domain, TurboShake256DynDomain, pattern(17 * 17), 0x1F, 0 =>
+	"66b810db8e90780424c0847372fdc95710882fde31c6df75beb9d4cd9305cfcae35e7b83e8b7e6eb4b78605880116316fe2c078a09b94ad7b8213c0a738b65c0");
+test!(turbo_shake256_ptn_17_3: TurboShake256Domain, TurboShake256DynDomain, pattern(17usize.pow(3)), 0x1F, 0 =>
+	"c74ebc919a5b3b0dd1228185ba02d29ef442d69d3d4276a93efe0bf9a16a7dc0cd4eabadab8cd7a5edd96695f5d360abe09e2c6511a3ec397da3b76b9e1674fb");
+test!(turbo_shake256_ptn_17_4: TurboShake256Domain, TurboShake256DynDomain, pattern(17usize.pow(4)), 0x1F, 0 =>
+	"02cc3a8897e6f4f6ccb6fd46631b1f5207b66c6de9c7b55b2d1a23134a170afdac234eaba9a77cff88c1f020b73724618c5687b362c430b248cd38647f848a1d");
+test!(turbo_shake256_ptn_17_5: TurboShake256Domain, TurboShake256DynDomain, pattern(17usize.pow(5)), 0x1F, 0 =>
+	"add53b06543e584b5823f626996aee50fe45ed15f20243a7165485acb4aa76b4ffda75cedf6d8cdc95c332bd56f4b986b58bb17d1778bfc1b1a97545cdf4ec9f");
+test!(turbo_shake256_d01: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF; 3], 0x01, 0 =>
+	"d21c6fbbf587fa2282f29aea620175fb0257413af78a0b1b2a87419ce031d933ae7a4d383327a8a17641a34f8a1d1003ad7da6b72dba84bb62fef28f62f12424");
+test!(turbo_shake256_d06: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF], 0x06, 0 =>
+	"738d7b4e37d18b7f22ad1b5313e357e3dd7d07056a26a303c433fa3533455280f4f5a7d4f700efb437fe6d281405e07be32a0a972e22e63adc1b090daefe004b");
+test!(turbo_shake256_d07: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF; 3], 0x07, 0 =>
+	"18b3b5b7061c2e67c1753a00e6ad7ed7ba1c906cf93efb7092eaf27fbeebb755ae6e292493c110e48d260028492b8e09b5500612b8f2578985ded5357d00ec67");
+test!(turbo_shake256_d0b: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF; 7], 0x0B, 0 =>
+	"bb36764951ec97e9d85f7ee9a67a7718fc005cf42556be79ce12c0bde50e5736d6632b0d0dfb202d1bbb8ffe3dd74cb00834fa756cb03471bab13a1e2c16b3c0");
+test!(turbo_shake256_d30: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF], 0x30, 0 =>
+	"f3fe12873d34bcbb2e608779d6b70e7f86bec7e90bf113cbd4fdd0c4e2f4625e148dd7ee1a52776cf77f240514d9ccfc3b5ddab8ee255e39ee389072962c111a");
+test!(turbo_shake256_d7f: TurboShake256Domain, TurboShake256DynDomain, vec![0xFF; 3], 0x7F, 0 =>
+	"abe569c1f77ec340f02705e7d37c9ab7e155516e4a6a150021d70b6fac0bb40c069f9a9828a0d575cd99f9bae435ab1acf7ed9110ba97ce0388d074bac768776");
+
+#[test]
+fn default_domain() {
+	assert_eq!(
+		TurboShake128Domain::<Lsbu64<25>>::new().squeeze::<32>(),
+		TurboShake128DynDomain::new(0x1F).squeeze::<32>()
+	);
+}
+
+#[test]
+#[should_panic = "domain separation byte must be in 0x01..=0x7F"]
+fn zero_domain() {
+	let _ = TurboShake128DynDomain::new(0x00);
+}
+
+#[test]
+#[should_panic = "domain separation byte must be in 0x01..=0x7F"]
+fn high_domain() {
+	let _ = TurboShake256DynDomain::new(0x80);
+}
@@ -0,0 +1,269 @@
+use alloc::boxed::Box;
+use core::{
+	fmt::{self, Display, Formatter},
+	str::FromStr
+};
+
+use keccak::{
+	k12::{KangarooTwelve, Kt256, MarsupilamiFourteen},
+	sha3::{
+		Keccak224, Keccak256, Keccak384, Keccak512, Sha3_224, Sha3_256, Sha3_384, Sha3_512,
+		Shake128, Shake256, TurboShake128Domain, TurboShake256Domain
+	}
+};
+use sponge::state::{Lsbu32, Lsbu64};
+use xoodoo::XoodyakHasher;
+
+use crate::{dynamic::Dyn, DynHasher, DynXof};
+
+macro_rules! algorithms {
+	($(
+		$(#[$meta:meta])*
+		$variant:ident($kind:ident $hash:ty) {
+			name: $name:literal $(| $alias:literal)*,
+			oid: $oid:expr,
+			multihash: $multihash:expr,
+			output_len: $output_len:expr,
+			block_len: $block_len:expr
+		}
+	)*) => {
+		#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+		pub enum Algorithm {
+			$($(#[$meta])* $variant),*
+		}
+
+		impl Algorithm {
+			pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+			/// The canonical name, as accepted by [`Self::from_name`] and printed by [`Display`].
+			pub const fn name(self) -> &'static str {
+				match self {
+					$(Self::$variant => $name),*
+				}
+			}
+
+			/// Other names accepted by [`Self::from_name`].
+			pub const fn aliases(self) -> &'static [&'static str] {
+				match self {
+					$(Self::$variant => &[$($alias),*]),*
+				}
+			}
+
+			/// The object identifier in dotted-decimal notation, if one is registered.
+			pub const fn oid(self) -> Option<&'static str> {
+				match self {
+					$(Self::$variant => $oid),*
+				}
+			}
+
+			/// The multicodec code used in multihashes, if one is registered.
+			pub const fn multihash(self) -> Option<u64> {
+				match self {
+					$(Self::$variant => $multihash),*
+				}
+			}
+
+			/// The digest length in bytes, which for XOFs is the length a [`DynHasher`] produces.
+			pub const fn output_len(self) -> usize {
+				match self {
+					$(Self::$variant => $output_len),*
+				}
+			}
+
+			/// The rate of the underlying sponge or duplex in bytes.
+			pub const fn block_len(self) -> usize {
+				match self {
+					$(Self::$variant => $block_len),*
+				}
+			}
+
+			pub const fn is_xof(self) -> bool {
+				match self {
+					$(Self::$variant => algorithms!(@is_xof $kind)),*
+				}
+			}
+
+			pub fn hasher(self) -> Box<dyn DynHasher> {
+				match self {
+					$(Self::$variant => Box::new(Dyn::<$hash>::new(self))),*
+				}
+			}
+
+			/// Returns `None` unless the algorithm [is an XOF](Self::is_xof).
+			pub fn xof(self) -> Option<Box<dyn DynXof>> {
+				match self {
+					$(Self::$variant => algorithms!(@xof $kind $hash, self)),*
+				}
+			}
+		}
+	};
+	(@is_xof Fixed) => { false };
+	(@is_xof Xof) => { true };
+	(@xof Fixed $hash:ty, $algorithm:expr) => { None };
+	(@xof Xof $hash:ty, $algorithm:expr) => { Some(Box::new(Dyn::<$hash>::new($algorithm))) };
+}
+
+algorithms! {
+	Sha3_224(Fixed Sha3_224<Lsbu64<25>>) {
+		name: "SHA3-224",
+		oid: Some("2.16.840.1.101.3.4.2.7"),
+		multihash: Some(0x17),
+		output_len: 28,
+		block_len: 144
+	}
+	Sha3_256(Fixed Sha3_256<Lsbu64<25>>) {
+		name: "SHA3-256",
+		oid: Some("2.16.840.1.101.3.4.2.8"),
+		multihash: Some(0x16),
+		output_len: 32,
+		block_len: 136
+	}
+	Sha3_384(Fixed Sha3_384<Lsbu64<25>>) {
+		name: "SHA3-384",
+		oid: Some("2.16.840.1.101.3.4.2.9"),
+		multihash: Some(0x15),
+		output_len: 48,
+		block_len: 104
+	}
+	Sha3_512(Fixed Sha3_512<Lsbu64<25>>) {
+		name: "SHA3-512",
+		oid: Some("2.16.840.1.101.3.4.2.10"),
+		multihash: Some(0x14),
+		output_len: 64,
+		block_len: 72
+	}
+	Shake128(Xof Shake128<Lsbu64<25>>) {
+		name: "SHAKE128",
+		oid: Some("2.16.840.1.101.3.4.2.11"),
+		multihash: Some(0x18),
+		output_len: 32,
+		block_len: 168
+	}
+	Shake256(Xof Shake256<Lsbu64<25>>) {
+		name: "SHAKE256",
+		oid: Some("2.16.840.1.101.3.4.2.12"),
+		multihash: Some(0x19),
+		output_len: 64,
+		block_len: 136
+	}
+	/// TurboSHAKE128 with the default domain separation byte 0x1F.
+	TurboShake128(Xof TurboShake128Domain<Lsbu64<25>>) {
+		name: "TurboSHAKE128",
+		oid: None,
+		multihash: None,
+		output_len: 32,
+		block_len: 168
+	}
+	/// TurboSHAKE256 with the default domain separation byte 0x1F.
+	TurboShake256(Xof TurboShake256Domain<Lsbu64<25>>) {
+		name: "TurboSHAKE256",
+		oid: None,
+		multihash: None,
+		output_len: 64,
+		block_len: 136
+	}
+	/// KangarooTwelve with an empty customization string.
+	Kt128(Xof KangarooTwelve<Lsbu64<25>>) {
+		name: "KT128" | "K12" | "KangarooTwelve",
+		oid: None,
+		multihash: Some(0x1d01),
+		output_len: 32,
+		block_len: 168
+	}
+	/// KT256 with an empty customization string.
+	Kt256(Xof Kt256<Lsbu64<25>>) {
+		name: "KT256",
+		oid: None,
+		multihash: None,
+		output_len: 64,
+		block_len: 136
+	}
+	/// MarsupilamiFourteen with an empty customization string.
+	MarsupilamiFourteen(Xof MarsupilamiFourteen<Lsbu64<25>>) {
+		name: "MarsupilamiFourteen" | "M14",
+		oid: None,
+		multihash: None,
+		output_len: 64,
+		block_len: 136
+	}
+	Keccak224(Fixed Keccak224<Lsbu64<25>>) {
+		name: "Keccak-224",
+		oid: None,
+		multihash: Some(0x1a),
+		output_len: 28,
+		block_len: 144
+	}
+	Keccak256(Fixed Keccak256<Lsbu64<25>>) {
+		name: "Keccak-256",
+		oid: None,
+		multihash: Some(0x1b),
+		output_len: 32,
+		block_len: 136
+	}
+	Keccak384(Fixed Keccak384<Lsbu64<25>>) {
+		name: "Keccak-384",
+		oid: None,
+		multihash: Some(0x1c),
+		output_len: 48,
+		block_len: 104
+	}
+	Keccak512(Fixed Keccak512<Lsbu64<25>>) {
+		name: "Keccak-512",
+		oid: None,
+		multihash: Some(0x1d),
+		output_len: 64,
+		block_len: 72
+	}
+	/// Xoodyak in hash mode, whose 32-byte output is Xoodyak-Hash from the NIST lightweight
+	/// cryptography submission.
+	XoodyakHash(Xof XoodyakHasher<Lsbu32<12>>) {
+		name: "Xoodyak-Hash" | "Xoodyak",
+		oid: None,
+		multihash: None,
+		output_len: 32,
+		block_len: 16
+	}
+}
+
+impl Algorithm {
+	/// Looks up an algorithm by its canonical name or an alias, ignoring ASCII case.
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::ALL.iter().copied().find(|algorithm| {
+			algorithm.name().eq_ignore_ascii_case(name)
+				|| algorithm.aliases().iter().any(|alias| alias.eq_ignore_ascii_case(name))
+		})
+	}
+
+	/// Looks up an algorithm by its object identifier in dotted-decimal notation.
+	pub fn from_oid(oid: &str) -> Option<Self> {
+		Self::ALL.iter().copied().find(|algorithm| algorithm.oid() == Some(oid))
+	}
+
+	pub fn from_multihash(code: u64) -> Option<Self> {
+		Self::ALL.iter().copied().find(|algorithm| algorithm.multihash() == Some(code))
+	}
+}
+
+impl Display for Algorithm {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.write_str(self.name())
+	}
+}
+
+impl FromStr for Algorithm {
+	type Err = UnknownAlgorithm;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_name(s).ok_or(UnknownAlgorithm)
+	}
+}
+
+/// The error returned when parsing an [`Algorithm`] from an unknown name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnknownAlgorithm;
+
+impl Display for UnknownAlgorithm {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.write_str("unknown hash algorithm")
+	}
+}
@@ -0,0 +1,139 @@
+use alloc::{boxed::Box, vec};
+
+use sponge::{Absorb, IntoSqueezer, Squeeze, Squeezer};
+
+use crate::Algorithm;
+
+/// An object-safe hash with the fixed output length of its [`Algorithm`], which is the default
+/// output length for XOFs.
+pub trait DynHasher {
+	fn algorithm(&self) -> Algorithm;
+
+	fn update(&mut self, buf: &[u8]);
+
+	fn reset(&mut self);
+
+	/// Writes the digest to `buf`, which must be [`Algorithm::output_len`] bytes long, and resets
+	/// the hash.
+	fn finalize_into_reset(&mut self, buf: &mut [u8]);
+
+	fn box_clone(&self) -> Box<dyn DynHasher>;
+
+	#[track_caller]
+	fn finalize_into(mut self: Box<Self>, buf: &mut [u8]) {
+		self.finalize_into_reset(buf);
+	}
+
+	fn finalize_boxed(mut self: Box<Self>) -> Box<[u8]> {
+		let mut buf = vec![0; self.algorithm().output_len()].into_boxed_slice();
+		self.finalize_into_reset(&mut buf);
+		buf
+	}
+}
+
+/// An object-safe extendable-output function.
+pub trait DynXof {
+	fn algorithm(&self) -> Algorithm;
+
+	fn update(&mut self, buf: &[u8]);
+
+	fn reset(&mut self);
+
+	/// Returns a reader for the output and resets the hash.
+	fn finalize_xof_reset(&mut self) -> Box<dyn DynXofReader>;
+
+	fn box_clone(&self) -> Box<dyn DynXof>;
+
+	fn finalize_xof(mut self: Box<Self>) -> Box<dyn DynXofReader> {
+		self.finalize_xof_reset()
+	}
+}
+
+/// The output of a [`DynXof`].
+pub trait DynXofReader {
+	fn read(&mut self, buf: &mut [u8]);
+
+	fn read_boxed(&mut self, len: usize) -> Box<[u8]> {
+		let mut buf = vec![0; len].into_boxed_slice();
+		self.read(&mut buf);
+		buf
+	}
+}
+
+impl<T> DynXofReader for T
+where
+	T: Squeezer
+{
+	fn read(&mut self, buf: &mut [u8]) {
+		self.squeeze_into(buf);
+	}
+}
+
+/// Adapts a hash from `keccak` or `xoodoo` to [`DynHasher`], and to [`DynXof`] if it is one.
+#[derive(Clone, Debug)]
+pub(crate) struct Dyn<H> {
+	hash: H,
+	algorithm: Algorithm
+}
+
+impl<H> Dyn<H>
+where
+	H: Default
+{
+	pub(crate) fn new(algorithm: Algorithm) -> Self {
+		Self { hash: H::default(), algorithm }
+	}
+}
+
+impl<H> DynHasher for Dyn<H>
+where
+	H: Absorb + Squeeze + Clone + Default + 'static
+{
+	fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	fn update(&mut self, buf: &[u8]) {
+		self.hash.absorb(buf);
+	}
+
+	fn reset(&mut self) {
+		self.hash = H::default();
+	}
+
+	#[track_caller]
+	fn finalize_into_reset(&mut self, buf: &mut [u8]) {
+		assert!(buf.len() == self.algorithm.output_len(), "output buffer has the wrong length");
+		core::mem::take(&mut self.hash).squeeze_into(buf);
+	}
+
+	fn box_clone(&self) -> Box<dyn DynHasher> {
+		Box::new(self.clone())
+	}
+}
+
+impl<H> DynXof for Dyn<H>
+where
+	H: Absorb + IntoSqueezer + Clone + Default + 'static,
+	H::Squeezer: 'static
+{
+	fn algorithm(&self) -> Algorithm {
+		self.algorithm
+	}
+
+	fn update(&mut self, buf: &[u8]) {
+		self.hash.absorb(buf);
+	}
+
+	fn reset(&mut self) {
+		self.hash = H::default();
+	}
+
+	fn finalize_xof_reset(&mut self) -> Box<dyn DynXofReader> {
+		Box::new(core::mem::take(&mut self.hash).into_squeezer())
+	}
+
+	fn box_clone(&self) -> Box<dyn DynXof> {
+		Box::new(self.clone())
+	}
+}
@@ -0,0 +1,56 @@
+//! Self-describing digests in the multihash format: the multicodec code of the algorithm and the
+//! digest length as unsigned varints, followed by the digest.
+
+use alloc::vec::Vec;
+
+use crate::Algorithm;
+
+/// Encodes `digest` as a multihash of `algorithm`, or returns `None` if the algorithm has no
+/// multicodec code.
+pub fn encode(algorithm: Algorithm, digest: &[u8]) -> Option<Vec<u8>> {
+	let code = algorithm.multihash()?;
+
+	let mut buf = Vec::with_capacity(digest.len() + 12);
+	encode_varint(&mut buf, code);
+	encode_varint(&mut buf, digest.len() as u64);
+	buf.extend_from_slice(digest);
+	Some(buf)
+}
+
+/// Splits a multihash into its algorithm and digest, or returns `None` if it is malformed, its
+/// code is unknown or its length does not match.
+pub fn decode(mut buf: &[u8]) -> Option<(Algorithm, &[u8])> {
+	let algorithm = Algorithm::from_multihash(decode_varint(&mut buf)?)?;
+	let len = usize::try_from(decode_varint(&mut buf)?).ok()?;
+
+	(buf.len() == len).then_some((algorithm, buf))
+}
+
+fn encode_varint(buf: &mut Vec<u8>, mut n: u64) {
+	while n >= 0x80 {
+		buf.push(n as u8 | 0x80);
+		n >>= 7;
+	}
+
+	buf.push(n as u8);
+}
+
+/// Reads an unsigned varint of at most 9 bytes in minimal form, as multiformats require.
+fn decode_varint(buf: &mut &[u8]) -> Option<u64> {
+	let mut n = 0;
+
+	for (i, &b) in buf.iter().enumerate().take(9) {
+		n |= u64::from(b & 0x7F) << (7 * i);
+
+		if b & 0x80 == 0 {
+			if b == 0 && i != 0 {
+				return None;
+			}
+
+			*buf = &buf[i + 1..];
+			return Some(n);
+		}
+	}
+
+	None
+}
@@ -0,0 +1,3 @@
+mod hex;
+mod multihash;
+mod registry;
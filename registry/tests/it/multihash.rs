@@ -0,0 +1,28 @@
+use registry::{multihash, Algorithm};
+
+#[test]
+fn round_trip() {
+	let digest = [0xAB; 32];
+
+	let encoded = multihash::encode(Algorithm::Sha3_256, &digest).unwrap();
+	assert_eq!(encoded[..2], [0x16, 0x20]);
+	assert_eq!(multihash::decode(&encoded), Some((Algorithm::Sha3_256, &digest[..])));
+
+	let encoded = multihash::encode(Algorithm::Kt128, &[0xCD; 200]).unwrap();
+	assert_eq!(encoded[..4], [0x81, 0x3A, 0xC8, 0x01]);
+	assert_eq!(multihash::decode(&encoded), Some((Algorithm::Kt128, &[0xCD; 200][..])));
+}
+
+#[test]
+fn unregistered() {
+	assert_eq!(multihash::encode(Algorithm::TurboShake128, &[0; 32]), None);
+}
+
+#[test]
+fn malformed() {
+	// Wrong length, unknown code, truncated varint and non-minimal varint.
+	assert_eq!(multihash::decode(&[0x16, 0x02, 0x00]), None);
+	assert_eq!(multihash::decode(&[0x12, 0x01, 0x00]), None);
+	assert_eq!(multihash::decode(&[0x81]), None);
+	assert_eq!(multihash::decode(&[0x96, 0x00, 0x01, 0x00]), None);
+}
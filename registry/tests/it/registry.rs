@@ -0,0 +1,175 @@
+use registry::{Algorithm, UnknownAlgorithm};
+
+const ABC: [(Algorithm, &str); 15] = [
+	(Algorithm::Sha3_224, "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"),
+	(Algorithm::Sha3_256, "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+	(
+		Algorithm::Sha3_384,
+		"ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+	),
+	(
+		Algorithm::Sha3_512,
+		"b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+	),
+	(Algorithm::Shake128, "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"),
+	(
+		Algorithm::Shake256,
+		"483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"
+	),
+	(Algorithm::TurboShake128, "dcf1646dfe993a8eb6b782d1faaca6d82416a5dcf1de98ee3c6dbc5e1dc63018"),
+	(
+		Algorithm::TurboShake256,
+		"63824b1431a7372e85edc022c9d7afdd027472fcfa33c887d6f5aaf8dc5d4db68afbcb5714b49b7ffd8dd115dd5bd5436f837236845a230d6969a4083a113617"
+	),
+	(Algorithm::Kt128, "ab174f328c55a5510b0b209791bf8b60e801a7cfc2aa42042dcb8f547fbe3a7d"),
+	(
+		Algorithm::Kt256,
+		"1b0f960f43e0384827a362330d724052a89f075c13cf496a51888a23b0075d6457071c7ea6f6f8c2274eef3804f623e4e0543cd2e2e7574de79a4fef735367de"
+	),
+	(
+		Algorithm::MarsupilamiFourteen,
+		"dda9bb40590ca08ac99a89eabac1d8b5b693e864261fcc896cdf2bc220cf89dfccd27a17d3878c4507f054d482c9987cc3b4710e98bd4c793c064d107a23ef4e"
+	),
+	(Algorithm::Keccak224, "c30411768506ebe1c2871b1ee2e87d38df342317300a9b97a95ec6a8"),
+	(Algorithm::Keccak256, "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+	(
+		Algorithm::Keccak384,
+		"f7df1165f033337be098e7d288ad6a2f74409d7a60b49c36642218de161b1f99f8c681e4afaf31a34db29fb763e3c28e"
+	),
+	(
+		Algorithm::Keccak512,
+		"18587dc2ea106b9a1563e32b3312421ca164c7f1f07bc922a9c83d77cea3a1e5d0c69910739025372dc14ac9642629379540c17e2a65b19d77aa511a9d00bb96"
+	)
+];
+
+#[test]
+fn hasher() {
+	for (algorithm, expected) in ABC {
+		let mut hasher = algorithm.hasher();
+		assert_eq!(hasher.algorithm(), algorithm);
+
+		hasher.update(b"a");
+		let clone = hasher.box_clone();
+		hasher.update(b"bc");
+
+		let mut digest = vec![0; algorithm.output_len()];
+		hasher.finalize_into_reset(&mut digest);
+		assert_eq!(crate::hex::encode(&digest), expected, "{algorithm}");
+
+		hasher.update(b"abc");
+		assert_eq!(crate::hex::encode(&hasher.finalize_boxed()), expected, "{algorithm}");
+
+		let mut clone = clone;
+		clone.update(b"bc");
+		assert_eq!(crate::hex::encode(&clone.finalize_boxed()), expected, "{algorithm}");
+	}
+}
+
+#[test]
+fn xoodyak_hash() {
+	let mut hasher = Algorithm::XoodyakHash.hasher();
+	hasher.update(b"");
+	assert_eq!(
+		crate::hex::encode(&hasher.finalize_boxed()),
+		"ea152f2b47bce24efb66c479d4adf17bd324d806e85ff75ee369ee50dc8f8bd1"
+	);
+}
+
+#[test]
+fn xof() {
+	for &algorithm in Algorithm::ALL {
+		let Some(mut xof) = algorithm.xof() else {
+			assert!(!algorithm.is_xof(), "{algorithm}");
+			continue;
+		};
+		assert!(algorithm.is_xof(), "{algorithm}");
+		assert_eq!(xof.algorithm(), algorithm);
+
+		xof.update(b"abc");
+		let mut reader = xof.box_clone().finalize_xof();
+		let mut output = reader.read_boxed(algorithm.output_len() + 300).into_vec();
+		reader.read(&mut output[algorithm.output_len()..]);
+
+		let mut hasher = algorithm.hasher();
+		hasher.update(b"abc");
+		assert_eq!(output[..algorithm.output_len()], *hasher.finalize_boxed(), "{algorithm}");
+
+		let mut reader = xof.finalize_xof_reset();
+		let mut long = vec![0; algorithm.output_len() + 600];
+		reader.read(&mut long[..7]);
+		reader.read(&mut long[7..]);
+		assert_eq!(long[..algorithm.output_len()], output[..algorithm.output_len()]);
+
+		xof.update(b"abc");
+		assert_eq!(xof.finalize_xof().read_boxed(7)[..], long[..7], "{algorithm}");
+	}
+}
+
+#[test]
+fn reset() {
+	let mut hasher = Algorithm::Sha3_256.hasher();
+	hasher.update(b"garbage");
+	hasher.reset();
+	hasher.update(b"abc");
+	assert_eq!(crate::hex::encode(&hasher.finalize_boxed()), ABC[1].1);
+}
+
+#[test]
+#[should_panic = "output buffer has the wrong length"]
+fn wrong_output_len() {
+	Algorithm::Sha3_256.hasher().finalize_into(&mut [0; 31]);
+}
+
+#[test]
+fn names() {
+	for &algorithm in Algorithm::ALL {
+		assert_eq!(algorithm.name().parse(), Ok(algorithm));
+		assert_eq!(algorithm.to_string().to_lowercase().parse(), Ok(algorithm));
+
+		for alias in algorithm.aliases() {
+			assert_eq!(Algorithm::from_name(alias), Some(algorithm));
+		}
+	}
+
+	assert_eq!("k12".parse(), Ok(Algorithm::Kt128));
+	assert_eq!("sha3-256".parse(), Ok(Algorithm::Sha3_256));
+	assert_eq!("SHA3_256".parse::<Algorithm>(), Err(UnknownAlgorithm));
+	assert_eq!("".parse::<Algorithm>(), Err(UnknownAlgorithm));
+}
+
+#[test]
+fn oids() {
+	assert_eq!(Algorithm::from_oid("2.16.840.1.101.3.4.2.8"), Some(Algorithm::Sha3_256));
+	assert_eq!(Algorithm::from_oid("2.16.840.1.101.3.4.2.12"), Some(Algorithm::Shake256));
+	assert_eq!(Algorithm::from_oid("2.16.840.1.101.3.4.2.1"), None);
+
+	for &algorithm in Algorithm::ALL {
+		if let Some(oid) = algorithm.oid() {
+			assert_eq!(Algorithm::from_oid(oid), Some(algorithm));
+		}
+	}
+}
+
+#[test]
+fn multihash_codes() {
+	assert_eq!(Algorithm::from_multihash(0x16), Some(Algorithm::Sha3_256));
+	assert_eq!(Algorithm::from_multihash(0x1d01), Some(Algorithm::Kt128));
+	assert_eq!(Algorithm::from_multihash(0x12), None);
+
+	for &algorithm in Algorithm::ALL {
+		if let Some(code) = algorithm.multihash() {
+			assert_eq!(Algorithm::from_multihash(code), Some(algorithm));
+		}
+	}
+}
+
+#[test]
+fn sizes() {
+	for (algorithm, expected) in ABC {
+		assert_eq!(algorithm.output_len() * 2, expected.len(), "{algorithm}");
+	}
+
+	let block_lens = Algorithm::ALL.iter().map(|algorithm| algorithm.block_len());
+	assert!(block_lens
+		.eq([144, 136, 104, 72, 168, 136, 168, 136, 168, 136, 136, 144, 136, 104, 72, 16]));
+}
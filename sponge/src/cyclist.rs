@@ -101,6 +101,14 @@ where
 		buf
 	}
 
+	/// Squeezes the rest of the output as a single stream, so that it no longer depends on how it
+	/// is split into reads.
+	pub fn into_reader(mut self) -> HashReader<S, P, RATE> {
+		let mut block = [0; RATE];
+		self.up(&mut block, 0x00);
+		HashReader { cyclist: self, block, index: 0 }
+	}
+
 	absorb_any!();
 
 	fn down(&mut self, buf: &[u8], color: u8) {
@@ -140,6 +148,232 @@ where
 	}
 }
 
+/// A [`Cyclist`] in hash mode that absorbs everything passed to [`Hasher::absorb`] as a single
+/// message, however it is split into calls.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Hasher<S, P, const RATE: usize> {
+	cyclist: Cyclist<S, P, Hash<RATE>>,
+	block: [u8; RATE],
+	len: usize,
+	color: u8
+}
+
+impl<S, P, const RATE: usize> Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	#[track_caller]
+	pub fn new() -> Self {
+		Self::from_state(S::default())
+	}
+
+	#[track_caller]
+	pub fn from_state(state: S) -> Self {
+		Self { cyclist: Cyclist::from_state(state), block: [0; RATE], len: 0, color: 0x03 }
+	}
+
+	pub fn absorb(&mut self, mut buf: &[u8]) {
+		while !buf.is_empty() {
+			// The last block is held back, since it has to be absorbed even if it is empty.
+			if self.len == RATE {
+				self.absorb_block();
+			}
+
+			let len = buf.len().min(RATE - self.len);
+			let (a, b) = buf.split_at(len);
+			buf = b;
+
+			self.block[self.len..][..len].copy_from_slice(a);
+			self.len += len;
+		}
+	}
+
+	pub fn into_reader(mut self) -> HashReader<S, P, RATE> {
+		self.absorb_block();
+		self.cyclist.into_reader()
+	}
+
+	pub fn squeeze_into(self, buf: &mut [u8]) {
+		self.into_reader().squeeze_into(buf);
+	}
+
+	pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+		self.into_reader().squeeze()
+	}
+
+	fn absorb_block(&mut self) {
+		if self.cyclist.phase != Phase::Up {
+			self.cyclist.state.permute::<P>();
+		}
+
+		self.cyclist.down(&self.block[..self.len], self.color);
+		self.len = 0;
+		self.color = 0x00;
+	}
+}
+
+impl<S, P, const RATE: usize> Default for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<S, P, const RATE: usize> crate::Absorb for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn absorb(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+impl<S, P, const RATE: usize> crate::IntoSqueezer for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Squeezer = HashReader<S, P, RATE>;
+
+	fn into_squeezer(self) -> Self::Squeezer {
+		self.into_reader()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::Update for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn update(&mut self, data: &[u8]) {
+		self.absorb(data);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::Reset for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn reset(&mut self) {
+		*self = Self::new();
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::ExtendableOutput for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	type Reader = HashReader<S, P, RATE>;
+
+	fn finalize_xof(self) -> Self::Reader {
+		self.into_reader()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::ExtendableOutputReset for Hasher<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn finalize_xof_reset(&mut self) -> Self::Reader {
+		mem::take(self).into_reader()
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::HashMarker for Hasher<S, P, RATE> {}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const RATE: usize> zeroize::Zeroize for Hasher<S, P, RATE>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.cyclist.zeroize();
+		self.block.zeroize();
+		self.len = 0;
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HashReader<S, P, const RATE: usize> {
+	cyclist: Cyclist<S, P, Hash<RATE>>,
+	block: [u8; RATE],
+	index: usize
+}
+
+impl<S, P, const RATE: usize> HashReader<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	pub fn squeeze_into(&mut self, mut buf: &mut [u8]) {
+		while !buf.is_empty() {
+			if self.index == RATE {
+				self.cyclist.down(&[], 0x00);
+				self.cyclist.up(&mut self.block, 0x00);
+				self.index = 0;
+			}
+
+			let len = buf.len().min(RATE - self.index);
+			let (a, b) = buf.split_at_mut(len);
+			buf = b;
+
+			a.copy_from_slice(&self.block[self.index..][..len]);
+			self.index += len;
+		}
+	}
+
+	pub fn squeeze<const LEN: usize>(&mut self) -> [u8; LEN] {
+		let mut buf = [0; LEN];
+		self.squeeze_into(&mut buf);
+		buf
+	}
+}
+
+impl<S, P, const RATE: usize> crate::Squeezer for HashReader<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn squeeze_into(&mut self, buf: &mut [u8]) {
+		self.squeeze_into(buf);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S, P, const RATE: usize> digest::XofReader for HashReader<S, P, RATE>
+where
+	S: State,
+	P: Permutation<S::Inner>
+{
+	fn read(&mut self, buf: &mut [u8]) {
+		self.squeeze_into(buf);
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const RATE: usize> zeroize::Zeroize for HashReader<S, P, RATE>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.cyclist.zeroize();
+		self.block.zeroize();
+	}
+}
+
 impl<S, P, const RATE_ABSORB: usize, const RATE_SQUEEZE: usize, const RATCHET: usize>
 	Cyclist<S, P, Keyed<RATE_ABSORB, RATE_SQUEEZE, RATCHET>>
 where
@@ -184,7 +418,7 @@ where
 		assert!(RATCHET != 0 && RATCHET <= S::LEN - 2);
 		assert!(key.len() < 256);
 		assert!(id.len() < 256);
-		assert!(key.len() + id.len() <= RATE_ABSORB - 1);
+		assert!(key.len() + id.len() < RATE_ABSORB);
 
 		state.xor_in_u8_slice(0, key);
 		state.xor_in_u8_slice(key.len(), id);
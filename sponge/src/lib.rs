@@ -24,20 +24,30 @@
 extern crate alloc;
 
 pub mod cyclist;
+pub mod multi;
+pub mod sakura;
+pub mod sample;
 pub mod sponge;
 pub mod state;
+pub mod stream;
 
 mod suffix;
 
 pub use crate::{
 	cyclist::Cyclist,
 	sponge::Sponge,
-	state::{SecretState, State},
+	state::{ByteState, SecretState, State},
 	suffix::*
 };
 
 pub trait Permutation<S> {
 	fn permute(state: &mut S);
+
+	/// Permutes `N` independent states, which implementations may do several at a time.
+	#[inline]
+	fn permute_multi<const N: usize>(states: &mut [S; N]) {
+		states.iter_mut().for_each(Self::permute);
+	}
 }
 
 pub trait Absorb {
@@ -47,6 +57,17 @@ pub trait Absorb {
 	fn absorb_u8(&mut self, b: u8) {
 		self.absorb(core::slice::from_ref(&b));
 	}
+
+	#[inline]
+	fn absorb_zeroes(&mut self, mut len: usize) {
+		const ZEROES: [u8; 64] = [0; 64];
+
+		while len != 0 {
+			let n = len.min(ZEROES.len());
+			self.absorb(&ZEROES[..n]);
+			len -= n;
+		}
+	}
 }
 
 pub trait IntoSqueezer {
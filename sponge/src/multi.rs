@@ -0,0 +1,207 @@
+//! Sponges over `N` independent states, permuted together in lockstep.
+//!
+//! Each lane behaves exactly like a [`Sponge`](crate::Sponge) of its own, and may absorb and
+//! squeeze a different number of bytes. Whenever some lanes need a permutation, all `N` states
+//! are permuted at once with [`State::permute_multi`] and the lanes that did not need it are
+//! restored afterwards.
+
+use core::{marker::PhantomData, mem};
+
+use crate::{Permutation, State, Suffix};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MultiSponge<S, P, const N: usize, const CAPACITY: usize>(Inner<S, P, N, CAPACITY>);
+
+impl<S, P, const N: usize, const CAPACITY: usize> MultiSponge<S, P, N, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner>
+{
+	pub const RATE: usize = S::LEN - CAPACITY;
+
+	#[track_caller]
+	pub fn from_states(states: [S; N]) -> Self {
+		Self(Inner::from_states(states))
+	}
+
+	/// Absorbs `bufs[i]` into lane `i`.
+	pub fn absorb(&mut self, mut bufs: [&[u8]; N]) {
+		loop {
+			for ((state, index), buf) in
+				self.0.states.iter_mut().zip(&mut self.0.indices).zip(&mut bufs)
+			{
+				let (a, b) = buf.split_at(buf.len().min(Self::RATE - *index));
+				*buf = b;
+
+				state.xor_in_u8_slice(*index, a);
+				*index += a.len();
+			}
+
+			let pending = bufs.map(|buf| !buf.is_empty());
+			if !pending.contains(&true) {
+				break;
+			}
+
+			self.0.permute(pending);
+		}
+	}
+
+	/// Pads every lane with `suffix` as in
+	/// [`Sponge::pad_multi_rate`](crate::Sponge::pad_multi_rate).
+	pub fn into_squeezer(mut self, suffix: Suffix) -> MultiSqueezer<S, P, N, CAPACITY> {
+		self.0.permute(self.0.indices.map(|index| index == Self::RATE));
+
+		for (state, index) in self.0.states.iter_mut().zip(&mut self.0.indices) {
+			state.xor_in_u8(*index, suffix.into());
+			state.xor_in_u8(Self::RATE - 1, 0b1000_0000);
+			*index = Self::RATE;
+		}
+
+		MultiSqueezer(self.0)
+	}
+
+	pub fn squeeze_into(self, suffix: Suffix, bufs: [&mut [u8]; N]) {
+		self.into_squeezer(suffix).squeeze_into(bufs);
+	}
+
+	pub fn squeeze<const LEN: usize>(self, suffix: Suffix) -> [[u8; LEN]; N] {
+		self.into_squeezer(suffix).squeeze()
+	}
+}
+
+impl<S, P, const N: usize, const CAPACITY: usize> Default for MultiSponge<S, P, N, CAPACITY>
+where
+	S: State
+{
+	#[track_caller]
+	fn default() -> Self {
+		Self(Inner::default())
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const N: usize, const CAPACITY: usize> zeroize::Zeroize
+	for MultiSponge<S, P, N, CAPACITY>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MultiSqueezer<S, P, const N: usize, const CAPACITY: usize>(Inner<S, P, N, CAPACITY>);
+
+impl<S, P, const N: usize, const CAPACITY: usize> MultiSqueezer<S, P, N, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner>
+{
+	pub const RATE: usize = S::LEN - CAPACITY;
+
+	/// Fills `bufs[i]` with the next output of lane `i`.
+	pub fn squeeze_into(&mut self, mut bufs: [&mut [u8]; N]) {
+		loop {
+			for ((state, index), buf) in
+				self.0.states.iter().zip(&mut self.0.indices).zip(&mut bufs)
+			{
+				let len = buf.len().min(Self::RATE - *index);
+				let (a, b) = mem::take(buf).split_at_mut(len);
+				*buf = b;
+
+				state.get_u8_slice(*index, a);
+				*index += a.len();
+			}
+
+			let pending = bufs.each_ref().map(|buf| !buf.is_empty());
+			if !pending.contains(&true) {
+				break;
+			}
+
+			self.0.permute(pending);
+		}
+	}
+
+	pub fn squeeze<const LEN: usize>(&mut self) -> [[u8; LEN]; N] {
+		let mut bufs = [[0; LEN]; N];
+		self.squeeze_into(bufs.each_mut().map(|buf| &mut buf[..]));
+		bufs
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const N: usize, const CAPACITY: usize> zeroize::Zeroize
+	for MultiSqueezer<S, P, N, CAPACITY>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Inner<S, P, const N: usize, const CAPACITY: usize> {
+	states: [S; N],
+	indices: [usize; N],
+	permutation: PhantomData<P>
+}
+
+impl<S, P, const N: usize, const CAPACITY: usize> Inner<S, P, N, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner>
+{
+	#[track_caller]
+	fn from_states(states: [S; N]) -> Self {
+		assert!(CAPACITY < S::LEN, "capacity must be less than the state length");
+
+		Self { states, indices: [0; N], permutation: PhantomData }
+	}
+
+	/// Permutes the lanes for which `lanes` is set, leaving the others as they were.
+	fn permute(&mut self, lanes: [bool; N]) {
+		if !lanes.contains(&true) {
+			return;
+		}
+
+		let saved = lanes.contains(&false).then(|| self.states.clone());
+		S::permute_multi::<P, N>(&mut self.states);
+
+		for (i, permuted) in lanes.into_iter().enumerate() {
+			if permuted {
+				self.indices[i] = 0;
+			} else if let Some(saved) = &saved {
+				self.states[i] = saved[i].clone();
+			}
+		}
+	}
+}
+
+impl<S, P, const N: usize, const CAPACITY: usize> Default for Inner<S, P, N, CAPACITY>
+where
+	S: State
+{
+	#[track_caller]
+	fn default() -> Self {
+		assert!(CAPACITY < S::LEN, "capacity must be less than the state length");
+
+		Self {
+			states: core::array::from_fn(|_| S::default()),
+			indices: [0; N],
+			permutation: PhantomData
+		}
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const N: usize, const CAPACITY: usize> zeroize::Zeroize for Inner<S, P, N, CAPACITY>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.states.iter_mut().for_each(S::zeroize);
+		self.indices.zeroize();
+	}
+}
@@ -0,0 +1,351 @@
+//! Tree hashing with the Sakura coding from "Sakura: a flexible coding for tree hashing", over any
+//! sponge.
+//!
+//! The message is cut into chunks of `CHUNK_LEN` bytes. A message of at most one chunk is hashed
+//! as a single node. Longer ones are hashed as leaves of `CV_LEN`-byte chaining values, which the
+//! final node absorbs either directly or through inner nodes, depending on the [`Hops`].
+
+use core::{marker::PhantomData, mem};
+
+use crate::{sponge::Squeezer, suffix, Permutation, Sponge, State, Suffix};
+
+/// The arrangement of the nodes above the leaves of a [`Tree`].
+pub trait Hops {
+	/// Whether the final node absorbs the first chunk itself, rather than as a leaf.
+	const KANGAROO: bool;
+
+	/// The number of leaves whose chaining values each inner node absorbs, with 0 meaning that the
+	/// final node absorbs them directly.
+	const FAN_OUT: u64;
+
+	/// Where a [`Tree`] keeps the inner node it is filling: `()` if `FAN_OUT` is 0, so that the
+	/// tree carries no state for one.
+	type Inner<T>: InnerNode<T>;
+}
+
+/// The storage of the inner node of a [`Tree`].
+pub trait InnerNode<T> {
+	fn new(node: T) -> Self;
+
+	/// The inner node, or `None` for layouts without inner nodes.
+	fn get(&mut self) -> Option<&mut T>;
+}
+
+impl<T> InnerNode<T> for () {
+	#[inline]
+	fn new(_: T) -> Self {}
+
+	#[inline]
+	fn get(&mut self) -> Option<&mut T> {
+		None
+	}
+}
+
+impl<T> InnerNode<T> for Option<T> {
+	#[inline]
+	fn new(node: T) -> Self {
+		Some(node)
+	}
+
+	#[inline]
+	fn get(&mut self) -> Option<&mut T> {
+		self.as_mut()
+	}
+}
+
+/// The layout of KangarooTwelve: the final node absorbs the first chunk, followed by the chaining
+/// values of the leaves holding the others.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Kangaroo;
+
+impl Hops for Kangaroo {
+	const KANGAROO: bool = true;
+	const FAN_OUT: u64 = 0;
+	type Inner<T> = ();
+}
+
+/// Every chunk is a leaf, and the final node absorbs only their chaining values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TwoLevel;
+
+impl Hops for TwoLevel {
+	const KANGAROO: bool = false;
+	const FAN_OUT: u64 = 0;
+	type Inner<T> = ();
+}
+
+/// Every chunk is a leaf, each run of `N` leaves is absorbed by an inner node, and the final node
+/// absorbs only the chaining values of the inner nodes.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FanOut<const N: u64>;
+
+impl<const N: u64> Hops for FanOut<N> {
+	const KANGAROO: bool = false;
+	const FAN_OUT: u64 = {
+		assert!(N != 0, "fan-out must be non-zero");
+		N
+	};
+	type Inner<T> = Option<T>;
+}
+
+/// Sakura tree hashing with `H` over a sponge with the given capacity.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Tree<S, P, H, const CAPACITY: usize, const CHUNK_LEN: usize, const CV_LEN: usize>
+where
+	H: Hops
+{
+	root: Sponge<S, P, CAPACITY, false>,
+	inner: H::Inner<Sponge<S, P, CAPACITY, false>>,
+	leaf: Sponge<S, P, CAPACITY, false>,
+	chunk: u64,
+	length: usize,
+	hops: PhantomData<H>
+}
+
+impl<S, P, H, const CAPACITY: usize, const CHUNK_LEN: usize, const CV_LEN: usize>
+	Tree<S, P, H, CAPACITY, CHUNK_LEN, CV_LEN>
+where
+	S: State,
+	P: Permutation<S::Inner>,
+	H: Hops
+{
+	/// Frame bits of a message that fits in a single node.
+	pub const SINGLE_NODE_SUFFIX: Suffix = suffix!(1, 1);
+
+	/// Frame bits of a leaf.
+	pub const LEAF_SUFFIX: Suffix = suffix!(1, 1, 0);
+
+	/// Frame bits of an inner node absorbing chaining values.
+	pub const INNER_SUFFIX: Suffix = suffix!(0, 0);
+
+	/// Frame bits of a final node absorbing chaining values.
+	pub const FINAL_SUFFIX: Suffix = suffix!(0, 1);
+
+	pub fn absorb_u8(&mut self, b: u8) {
+		debug_assert!(self.length <= CHUNK_LEN);
+
+		if self.chunk == 0 {
+			if self.length == CHUNK_LEN {
+				self.switch_to_chaining();
+			} else {
+				self.first_node().absorb_u8(b);
+				self.length += 1;
+				return;
+			}
+		}
+
+		if self.length == CHUNK_LEN {
+			self.finish_leaf();
+		}
+
+		self.leaf.absorb_u8(b);
+		self.length += 1;
+	}
+
+	pub fn absorb(&mut self, buf: &[u8]) {
+		self.absorb_with(buf, |leaves, sink| {
+			for leaf in leaves.chunks(CHUNK_LEN) {
+				sink(Self::hash_leaf(leaf));
+			}
+		});
+	}
+
+	/// Like [`Self::absorb`], but hands each run of whole chunks that become leaves to
+	/// `hash_leaves`, which must pass their chaining values to the sink in order.
+	///
+	/// This lets the leaves be hashed several at a time, or on other threads.
+	pub fn absorb_with<F>(&mut self, mut buf: &[u8], mut hash_leaves: F)
+	where
+		F: FnMut(&[u8], &mut dyn FnMut([u8; CV_LEN]))
+	{
+		if buf.is_empty() {
+			return;
+		}
+
+		debug_assert!(self.length <= CHUNK_LEN);
+
+		if self.chunk == 0 {
+			let (l, r) = buf.split_at(buf.len().min(CHUNK_LEN - self.length));
+			buf = r;
+
+			self.first_node().absorb(l);
+			self.length += l.len();
+
+			if !buf.is_empty() {
+				self.switch_to_chaining();
+			}
+		}
+
+		while !buf.is_empty() {
+			debug_assert!(self.chunk != 0);
+			debug_assert!(self.length <= CHUNK_LEN);
+
+			if self.length == CHUNK_LEN {
+				self.finish_leaf();
+			}
+
+			if self.length == 0 && buf.len() >= CHUNK_LEN {
+				let (l, r) = buf.split_at(buf.len() - buf.len() % CHUNK_LEN);
+				buf = r;
+
+				hash_leaves(l, &mut |cv| self.absorb_chaining_value(&cv));
+				continue;
+			}
+
+			let (l, r) = buf.split_at(buf.len().min(CHUNK_LEN - self.length));
+			buf = r;
+
+			self.leaf.absorb(l);
+			self.length += l.len();
+		}
+	}
+
+	pub fn into_squeezer(mut self) -> Squeezer<S, P, CAPACITY> {
+		if self.chunk == 0 {
+			let node = if H::KANGAROO { self.root } else { self.leaf };
+			return node.into_squeezer(Self::SINGLE_NODE_SUFFIX);
+		}
+
+		// Leaves hashed in bulk leave no partial leaf behind.
+		if self.length != 0 {
+			self.finish_leaf();
+		}
+
+		let leaves = self.leaves();
+		let nodes = if H::FAN_OUT == 0 {
+			leaves
+		} else {
+			if !leaves.is_multiple_of(H::FAN_OUT) {
+				self.finish_inner(leaves % H::FAN_OUT);
+			}
+
+			leaves.div_ceil(H::FAN_OUT)
+		};
+
+		let (encoded, len) = length_encode(nodes);
+		self.root.absorb(&encoded[..len]);
+		self.root.absorb(&[0xFF; 2]);
+		self.root.into_squeezer(Self::FINAL_SUFFIX)
+	}
+
+	pub fn squeeze_into(self, buf: &mut [u8]) {
+		self.into_squeezer().squeeze_into(buf);
+	}
+
+	pub fn squeeze<const LEN: usize>(self) -> [u8; LEN] {
+		self.into_squeezer().squeeze()
+	}
+
+	/// Hashes a chunk as a leaf, returning its chaining value.
+	pub fn hash_leaf(buf: &[u8]) -> [u8; CV_LEN] {
+		let mut leaf = Sponge::<S, P, CAPACITY, false>::default();
+		leaf.absorb(buf);
+		leaf.squeeze(Self::LEAF_SUFFIX)
+	}
+
+	/// The node absorbing the first chunk.
+	const fn first_node(&mut self) -> &mut Sponge<S, P, CAPACITY, false> {
+		if H::KANGAROO {
+			&mut self.root
+		} else {
+			&mut self.leaf
+		}
+	}
+
+	/// The number of leaves whose chaining values have been absorbed.
+	const fn leaves(&self) -> u64 {
+		self.chunk - H::KANGAROO as u64
+	}
+
+	fn switch_to_chaining(&mut self) {
+		debug_assert!(self.chunk == 0);
+		debug_assert!(self.length == CHUNK_LEN);
+
+		if H::KANGAROO {
+			self.root.absorb(&[suffix!(1).into(), 0, 0, 0, 0, 0, 0, 0]);
+			self.chunk = 1;
+			self.length = 0;
+		} else {
+			self.finish_leaf();
+		}
+	}
+
+	fn finish_leaf(&mut self) {
+		debug_assert!(self.length <= CHUNK_LEN);
+
+		let cv = mem::take(&mut self.leaf).squeeze::<CV_LEN>(Self::LEAF_SUFFIX);
+		self.length = 0;
+		self.absorb_chaining_value(&cv);
+	}
+
+	fn absorb_chaining_value(&mut self, cv: &[u8; CV_LEN]) {
+		self.chunk += 1;
+
+		let Some(inner) = self.inner.get() else {
+			self.root.absorb(cv);
+			return;
+		};
+
+		inner.absorb(cv);
+
+		if self.leaves().is_multiple_of(H::FAN_OUT) {
+			self.finish_inner(H::FAN_OUT);
+		}
+	}
+
+	fn finish_inner(&mut self, leaves: u64) {
+		let inner = self.inner.get().expect("layout has inner nodes");
+
+		let (encoded, len) = length_encode(leaves);
+		inner.absorb(&encoded[..len]);
+		inner.absorb(&[0xFF; 2]);
+
+		let cv = mem::take(inner).squeeze::<CV_LEN>(Self::INNER_SUFFIX);
+		self.root.absorb(&cv);
+	}
+}
+
+impl<S, P, H, const CAPACITY: usize, const CHUNK_LEN: usize, const CV_LEN: usize> Default
+	for Tree<S, P, H, CAPACITY, CHUNK_LEN, CV_LEN>
+where
+	S: State,
+	H: Hops
+{
+	fn default() -> Self {
+		Self {
+			root: Sponge::default(),
+			inner: H::Inner::new(Sponge::default()),
+			leaf: Sponge::default(),
+			chunk: 0,
+			length: 0,
+			hops: PhantomData
+		}
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, H, const CAPACITY: usize, const CHUNK_LEN: usize, const CV_LEN: usize> zeroize::Zeroize
+	for Tree<S, P, H, CAPACITY, CHUNK_LEN, CV_LEN>
+where
+	S: zeroize::Zeroize,
+	H: Hops
+{
+	fn zeroize(&mut self) {
+		self.root.zeroize();
+		if let Some(inner) = self.inner.get() {
+			inner.zeroize();
+		}
+		self.leaf.zeroize();
+	}
+}
+
+/// `n` in big-endian without leading zeroes, followed by its length in bytes.
+fn length_encode(n: u64) -> ([u8; 9], usize) {
+	let len = 8 - n.leading_zeros() as usize / 8;
+
+	let mut encoded = [0; 9];
+	encoded[..len].copy_from_slice(&n.to_be_bytes()[8 - len..]);
+	encoded[len] = len as u8;
+
+	(encoded, len + 1)
+}
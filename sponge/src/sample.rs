@@ -0,0 +1,144 @@
+//! Sampling uniform and binomial values from the output of a [`Squeezer`].
+//!
+//! Every sampler documents exactly which bytes it consumes, so that its results can be
+//! reproduced by other implementations reading the same output stream.
+
+use crate::Squeezer;
+
+macro_rules! uniform_below {
+	($fn:ident, $int:ty, $bytes:literal) => {
+		#[doc = concat!("Returns a uniformly distributed `", stringify!($int), "` in `0..n`.")]
+		///
+		#[doc = concat!("Each attempt reads ", $bytes, " bytes as a little-endian integer and masks it to")]
+		/// the bit length of `n - 1`, retrying while the result is at least `n`. Fewer than two
+		/// attempts are needed on average.
+		#[track_caller]
+		pub fn $fn<Q>(squeezer: &mut Q, n: $int) -> $int
+		where
+			Q: Squeezer + ?Sized
+		{
+			assert!(n != 0, "upper bound must be non-zero");
+
+			let mask = <$int>::MAX.checked_shr((n - 1).leading_zeros()).unwrap_or(0);
+
+			loop {
+				let x = <$int>::from_le_bytes(squeezer.squeeze()) & mask;
+				if x < n {
+					return x;
+				}
+			}
+		}
+	};
+}
+
+uniform_below!(uniform_below_u32, u32, 4);
+uniform_below!(uniform_below_u64, u64, 8);
+uniform_below!(uniform_below_u128, u128, 16);
+
+/// Fills `out` with values uniform in `0..q`, as in SampleNTT from FIPS 203 (ML-KEM).
+///
+/// Every 3 bytes `b` give the 12-bit candidates `b[0] | (b[1] & 0x0F) << 8` and
+/// `b[1] >> 4 | b[2] << 4`, in that order, and those below `q` are kept until `out` is full. The
+/// second candidate of the last 3 bytes is discarded if `out` is already full.
+#[track_caller]
+pub fn rejection_sample_12<Q>(squeezer: &mut Q, q: u16, out: &mut [u16])
+where
+	Q: Squeezer + ?Sized
+{
+	assert!((1..=1 << 12).contains(&q), "modulus must be in 1..=4096");
+
+	let mut i = 0;
+
+	while i < out.len() {
+		let [b0, b1, b2] = squeezer.squeeze().map(u16::from);
+
+		for d in [b0 | (b1 & 0x0F) << 8, b1 >> 4 | b2 << 4] {
+			if d < q && i < out.len() {
+				out[i] = d;
+				i += 1;
+			}
+		}
+	}
+}
+
+/// Fills `out` with values uniform in `0..q`, as in RejNTTPoly from FIPS 204 (ML-DSA).
+///
+/// Every 3 bytes give the 23-bit candidate `b[0] | b[1] << 8 | (b[2] & 0x7F) << 16`, which is
+/// kept if it is below `q`.
+#[track_caller]
+pub fn rejection_sample_23<Q>(squeezer: &mut Q, q: u32, out: &mut [u32])
+where
+	Q: Squeezer + ?Sized
+{
+	assert!((1..=1 << 23).contains(&q), "modulus must be in 1..=8388608");
+
+	let mut i = 0;
+
+	while i < out.len() {
+		let [b0, b1, b2] = squeezer.squeeze().map(u32::from);
+
+		let d = b0 | b1 << 8 | (b2 & 0x7F) << 16;
+		if d < q {
+			out[i] = d;
+			i += 1;
+		}
+	}
+}
+
+/// Fills `out` from the centered binomial distribution with parameter `eta`, in `-eta..=eta`,
+/// as in SamplePolyCBD from FIPS 203 (ML-KEM).
+///
+/// Exactly `(2 * eta * out.len()).div_ceil(8)` bytes are read as a little-endian bit string, and
+/// each value is the sum of its next `eta` bits minus the sum of the `eta` after those.
+#[track_caller]
+pub fn centered_binomial<Q>(squeezer: &mut Q, eta: u8, out: &mut [i8])
+where
+	Q: Squeezer + ?Sized
+{
+	assert!((1..=8).contains(&eta), "eta must be in 1..=8");
+
+	let mut bits = Bits { squeezer, byte: 0, len: 0 };
+
+	for x in out {
+		let a = (0..eta).map(|_| bits.next()).sum::<u8>();
+		let b = (0..eta).map(|_| bits.next()).sum::<u8>();
+		*x = a as i8 - b as i8;
+	}
+}
+
+/// Shuffles `slice` uniformly with the Fisher–Yates shuffle.
+///
+/// For each `i` from `slice.len() - 1` down to 1, `slice[i]` is swapped with `slice[j]`, where `j`
+/// is [`uniform_below_u64`] of `i + 1`.
+pub fn shuffle<Q, T>(squeezer: &mut Q, slice: &mut [T])
+where
+	Q: Squeezer + ?Sized
+{
+	for i in (1..slice.len()).rev() {
+		let j = uniform_below_u64(squeezer, i as u64 + 1);
+		slice.swap(i, j as usize);
+	}
+}
+
+struct Bits<'a, Q: ?Sized> {
+	squeezer: &'a mut Q,
+	byte: u8,
+	len: u8
+}
+
+impl<Q> Bits<'_, Q>
+where
+	Q: Squeezer + ?Sized
+{
+	fn next(&mut self) -> u8 {
+		if self.len == 0 {
+			[self.byte] = self.squeezer.squeeze();
+			self.len = 8;
+		}
+
+		let bit = self.byte & 1;
+		self.byte >>= 1;
+		self.len -= 1;
+		bit
+	}
+}
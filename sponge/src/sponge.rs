@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use crate::{Absorb, Permutation, State, Suffix};
+use crate::{state::ByteState, Absorb, Permutation, State, Suffix};
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Sponge<S, P, const CAPACITY: usize, const FULL_STATE: bool>(Inner<S, P, CAPACITY>);
@@ -115,6 +115,10 @@ where
 	fn absorb_u8(&mut self, b: u8) {
 		self.absorb_u8(b);
 	}
+
+	fn absorb_zeroes(&mut self, len: usize) {
+		self.absorb_zeroes(len);
+	}
 }
 
 #[cfg(feature = "digest")]
@@ -184,6 +188,54 @@ where
 		buf
 	}
 
+	/// XORs the next `buf.len()` bytes of output into `buf`.
+	pub fn squeeze_xor_into(&mut self, mut buf: &mut [u8]) {
+		while !buf.is_empty() {
+			self.check_permute();
+
+			let len = buf.len().min(Self::RATE - self.0.index);
+			let (a, b) = buf.split_at_mut(len);
+			buf = b;
+
+			self.0.state.xor_out_u8_slice(self.0.index, a);
+			self.0.index += a.len();
+		}
+	}
+
+	/// Fills `out[..n * RATE]` with the next `n` blocks' worth of output.
+	#[track_caller]
+	pub fn squeeze_blocks(&mut self, n: usize, out: &mut [u8]) {
+		assert!(out.len() / Self::RATE >= n, "output buffer is shorter than the requested blocks");
+		self.squeeze_into(&mut out[..n * Self::RATE]);
+	}
+
+	/// Borrows the rest of the current output block, or the whole next one if the current one has
+	/// been consumed, and skips past it.
+	///
+	/// The returned slice is exactly `RATE` bytes long whenever the output squeezed so far is a
+	/// multiple of `RATE` bytes long.
+	pub fn next_block(&mut self) -> &[u8]
+	where
+		S: ByteState
+	{
+		self.check_permute();
+
+		let index = self.0.index;
+		self.0.index = Self::RATE;
+		&self.0.state.as_u8_slice()[index..Self::RATE]
+	}
+
+	/// Discards the next `len` bytes of output.
+	pub fn skip(&mut self, mut len: u64) {
+		while len != 0 {
+			self.check_permute();
+
+			let skipped = len.min((Self::RATE - self.0.index) as u64);
+			self.0.index += skipped as usize;
+			len -= skipped;
+		}
+	}
+
 	fn check_permute(&mut self) {
 		debug_assert!(self.0.index <= Self::RATE);
 
@@ -16,6 +16,21 @@ pub trait State: Default {
 	fn permute<P>(&mut self)
 	where
 		P: Permutation<Self::Inner>;
+
+	/// Permutes `N` states with [`Permutation::permute_multi`] where the state can hand its lanes
+	/// over, and one at a time otherwise.
+	#[inline]
+	fn permute_multi<P, const N: usize>(states: &mut [Self; N])
+	where
+		P: Permutation<Self::Inner>
+	{
+		states.iter_mut().for_each(Self::permute::<P>);
+	}
+}
+
+/// A state storing its bytes in order, which can therefore be borrowed without copying.
+pub trait ByteState: State {
+	fn as_u8_slice(&self) -> &[u8];
 }
 
 macro_rules! state {
@@ -63,7 +78,7 @@ macro_rules! state {
 
 			#[allow(trivial_casts)]
 			#[inline]
-			fn as_mut_u8s(&mut self) -> &mut [u8] {
+			const fn as_mut_u8s(&mut self) -> &mut [u8] {
 				unsafe {
 					&mut *(slice::from_raw_parts_mut(self.0.as_mut_ptr() as *mut u8, Self::LEN))
 				}
@@ -80,6 +95,13 @@ macro_rules! state {
 		#[cfg(feature = "zeroize")]
 		impl<const LEN: usize> zeroize::DefaultIsZeroes for $name<LEN> {}
 
+		impl<const LEN: usize> ByteState for $name<LEN> {
+			#[inline]
+			fn as_u8_slice(&self) -> &[u8] {
+				self.as_u8s()
+			}
+		}
+
 		impl<const LEN: usize> State for $name<LEN> {
 			const LEN: usize = LEN * size_of::<$ty>();
 			type Inner = [$ty; LEN];
@@ -137,6 +159,16 @@ macro_rules! state {
 			{
 				self.with_ne(P::permute);
 			}
+
+			#[inline]
+			fn permute_multi<P, const N: usize>(states: &mut [Self; N])
+			where
+				P: Permutation<Self::Inner>
+			{
+				let mut inners = states.map(Self::into_ne);
+				P::permute_multi(&mut inners);
+				*states = inners.map(Self::from_ne);
+			}
 		}
 	};
 }
@@ -229,6 +261,16 @@ macro_rules! secret_state {
 				self.0.permute::<P>();
 			}
 		}
+
+		impl<S> ByteState for SecretState<S>
+		where
+			S: ByteState + $($tt)+
+		{
+			#[inline]
+			fn as_u8_slice(&self) -> &[u8] {
+				self.0.as_u8_slice()
+			}
+		}
 	};
 }
 
@@ -295,3 +337,14 @@ where
 		(**self).permute::<P>();
 	}
 }
+
+#[cfg(feature = "alloc")]
+impl<S> ByteState for alloc::boxed::Box<S>
+where
+	S: ByteState
+{
+	#[inline]
+	fn as_u8_slice(&self) -> &[u8] {
+		(**self).as_u8_slice()
+	}
+}
@@ -0,0 +1,123 @@
+//! A seekable stream cipher XORing the output of a [`Squeezer`] into the data.
+
+use crate::{sponge::Squeezer, Permutation, State};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Keystream<S, P, const CAPACITY: usize> {
+	initial: Squeezer<S, P, CAPACITY>,
+	squeezer: Squeezer<S, P, CAPACITY>,
+	/// The number of bytes `squeezer` has output, which lags behind `position` after a seek.
+	squeezed: u64,
+	position: u64
+}
+
+impl<S, P, const CAPACITY: usize> Keystream<S, P, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner> + Clone
+{
+	/// Creates a keystream starting at the first byte `squeezer` would output.
+	pub fn new(squeezer: Squeezer<S, P, CAPACITY>) -> Self {
+		Self { initial: squeezer.clone(), squeezer, squeezed: 0, position: 0 }
+	}
+
+	pub const fn position(&self) -> u64 {
+		self.position
+	}
+
+	#[track_caller]
+	pub fn apply_keystream(&mut self, buf: &mut [u8]) {
+		self.xor_keystream(buf).expect("end of keystream reached");
+	}
+
+	/// Moves to byte `position` of the keystream.
+	///
+	/// The squeezer catches up on the next use of the keystream: it skips forward from where it
+	/// is, which costs a permutation per rate of bytes skipped, or replays the keystream from the
+	/// start if `position` is behind it.
+	pub const fn seek(&mut self, position: u64) {
+		self.position = position;
+	}
+
+	fn xor_keystream(&mut self, buf: &mut [u8]) -> Option<()> {
+		let end = self.position.checked_add(buf.len() as u64)?;
+		if buf.is_empty() {
+			return Some(());
+		}
+
+		if self.position < self.squeezed {
+			self.squeezer = self.initial.clone();
+			self.squeezed = 0;
+		}
+
+		self.squeezer.skip(self.position - self.squeezed);
+		self.squeezer.squeeze_xor_into(buf);
+		(self.squeezed, self.position) = (end, end);
+		Some(())
+	}
+}
+
+#[cfg(feature = "cipher")]
+impl<S, P, const CAPACITY: usize> cipher::StreamCipher for Keystream<S, P, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner> + Clone
+{
+	fn try_apply_keystream_inout(
+		&mut self,
+		mut buf: cipher::inout::InOutBuf<'_, '_, u8>
+	) -> Result<(), cipher::StreamCipherError> {
+		if self.position.checked_add(buf.len() as u64).is_none() {
+			return Err(cipher::StreamCipherError);
+		}
+
+		for i in 0..buf.len() {
+			let mut b = buf.get(i);
+			*b.get_out() = *b.get_in();
+		}
+
+		self.xor_keystream(buf.get_out()).ok_or(cipher::StreamCipherError)
+	}
+
+	fn try_apply_keystream(&mut self, buf: &mut [u8]) -> Result<(), cipher::StreamCipherError> {
+		self.xor_keystream(buf).ok_or(cipher::StreamCipherError)
+	}
+}
+
+#[cfg(feature = "cipher")]
+/// Seeking is free until the keystream is next used, which then skips forward from the last byte
+/// output, or replays the keystream from the start after seeking backwards.
+impl<S, P, const CAPACITY: usize> cipher::StreamCipherSeek for Keystream<S, P, CAPACITY>
+where
+	S: State + Clone,
+	P: Permutation<S::Inner> + Clone
+{
+	fn try_current_pos<T>(&self) -> Result<T, cipher::OverflowError>
+	where
+		T: cipher::SeekNum
+	{
+		T::from_block_byte(self.position, 0, 1)
+	}
+
+	fn try_seek<T>(&mut self, pos: T) -> Result<(), cipher::StreamCipherError>
+	where
+		T: cipher::SeekNum
+	{
+		let (position, _) = pos.into_block_byte::<u64>(1).map_err(|_| cipher::StreamCipherError)?;
+		self.seek(position);
+		Ok(())
+	}
+}
+
+#[cfg(feature = "zeroize")]
+impl<S, P, const CAPACITY: usize> zeroize::Zeroize for Keystream<S, P, CAPACITY>
+where
+	S: zeroize::Zeroize
+{
+	fn zeroize(&mut self) {
+		self.initial.zeroize();
+		self.squeezer.zeroize();
+		self.squeezed.zeroize();
+		self.position.zeroize();
+	}
+}
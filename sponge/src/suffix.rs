@@ -2,6 +2,17 @@
 pub struct Suffix(u8);
 
 impl Suffix {
+	/// A suffix from its padded byte: the suffix bits from the least significant, followed by the
+	/// first padding bit. This is the domain separation byte of TurboSHAKE, for example.
+	#[inline]
+	pub const fn from_u8(b: u8) -> Option<Self> {
+		if b != 0 && b & 0x80 == 0 {
+			Some(Self(b))
+		} else {
+			None
+		}
+	}
+
 	#[inline]
 	pub const fn from_bits_1(a: bool) -> Self {
 		Self((a as u8) | 1 << 1)
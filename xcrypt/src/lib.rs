@@ -0,0 +1,357 @@
+#![warn(
+	deprecated_in_future,
+	future_incompatible,
+	macro_use_extern_crate,
+	meta_variable_misuse,
+	missing_copy_implementations,
+	missing_debug_implementations,
+	noop_method_call,
+	rust_2018_compatibility,
+	rust_2018_idioms,
+	rust_2021_compatibility,
+	single_use_lifetimes,
+	trivial_casts,
+	trivial_numeric_casts,
+	unreachable_pub,
+	unsafe_op_in_unsafe_fn,
+	unused_crate_dependencies,
+	unused_lifetimes,
+	unused_qualifications,
+	unused_results
+)]
+
+//! A chunked, streaming file encryption format on keyed Xoodyak.
+//!
+//! A file is a [`Header`] followed by chunks of ciphertext, each followed by a [`TAG_LEN`]-byte
+//! tag. Every chunk but the last holds exactly `chunk_len` bytes of plaintext, and the last one
+//! holds between 0 and `chunk_len` bytes, so that even an empty file has one chunk.
+//!
+//! A single keyed Xoodyak instance processes the whole file: it absorbs the header, then for each
+//! chunk absorbs a flag byte that is 1 for the last chunk and 0 otherwise, encrypts the chunk,
+//! squeezes its tag and ratchets. As the state carries over from chunk to chunk, the tag of a
+//! chunk authenticates the header and everything before it, so reordered chunks fail to verify,
+//! and the flag makes a file truncated at a chunk boundary fail too. Ratcheting after every chunk
+//! keeps an exposed state from revealing earlier chunks.
+//!
+//! Decryption only releases the plaintext of a chunk once its tag has been verified, and reports
+//! tampering as an [`io::Error`] of kind [`io::ErrorKind::InvalidData`].
+
+use std::io::{self, Read, Write};
+
+use sponge::state::Lsbu32;
+use xoodoo::XoodyakKeyed;
+
+pub const MAGIC: [u8; 4] = *b"XDYK";
+
+pub const VERSION: u8 = 1;
+
+pub const KEY_LEN: usize = 32;
+
+pub const NONCE_LEN: usize = 16;
+
+pub const TAG_LEN: usize = 16;
+
+pub const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + NONCE_LEN;
+
+pub const DEFAULT_CHUNK_LEN: u32 = 64 << 10;
+
+pub const MAX_CHUNK_LEN: u32 = 16 << 20;
+
+/// The parameters stored in front of the chunks: the magic bytes, the version, the chunk length
+/// in big-endian and the nonce.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Header {
+	pub chunk_len: u32,
+	pub nonce: [u8; NONCE_LEN]
+}
+
+impl Header {
+	#[track_caller]
+	pub fn new(chunk_len: u32, nonce: [u8; NONCE_LEN]) -> Self {
+		assert!(
+			chunk_len != 0 && chunk_len <= MAX_CHUNK_LEN,
+			"chunk length must be in 1..=MAX_CHUNK_LEN"
+		);
+
+		Self { chunk_len, nonce }
+	}
+
+	pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+		let mut buf = [0; HEADER_LEN];
+		buf[..4].copy_from_slice(&MAGIC);
+		buf[4] = VERSION;
+		buf[5..9].copy_from_slice(&self.chunk_len.to_be_bytes());
+		buf[9..].copy_from_slice(&self.nonce);
+		buf
+	}
+
+	pub fn from_bytes(buf: &[u8; HEADER_LEN]) -> io::Result<Self> {
+		if buf[..4] != MAGIC {
+			return Err(invalid_data("not an encrypted file"));
+		}
+
+		if buf[4] != VERSION {
+			return Err(invalid_data("unsupported version"));
+		}
+
+		let chunk_len = u32::from_be_bytes(buf[5..9].try_into().unwrap());
+		if chunk_len == 0 || chunk_len > MAX_CHUNK_LEN {
+			return Err(invalid_data("invalid chunk length"));
+		}
+
+		Ok(Self { chunk_len, nonce: buf[9..].try_into().unwrap() })
+	}
+
+	fn cyclist(&self, key: &[u8; KEY_LEN]) -> XoodyakKeyed<Lsbu32<12>> {
+		let mut cyclist = XoodyakKeyed::with_key(key);
+		cyclist.absorb(&self.to_bytes());
+		cyclist
+	}
+}
+
+/// Encrypts everything written to it into `W`. [`Self::finish`] must be called to write the last
+/// chunk, without which the file is rejected as truncated.
+#[derive(Debug)]
+pub struct Encryptor<W> {
+	writer: W,
+	cyclist: XoodyakKeyed<Lsbu32<12>>,
+	buf: Vec<u8>,
+	chunk_len: usize
+}
+
+impl<W> Encryptor<W>
+where
+	W: Write
+{
+	/// Writes the header to `writer`. The nonce must never be reused with the same key.
+	pub fn new(mut writer: W, key: &[u8; KEY_LEN], header: Header) -> io::Result<Self> {
+		writer.write_all(&header.to_bytes())?;
+
+		let chunk_len = header.chunk_len as usize;
+
+		Ok(Self {
+			writer,
+			cyclist: header.cyclist(key),
+			buf: Vec::with_capacity(chunk_len + TAG_LEN),
+			chunk_len
+		})
+	}
+
+	/// Writes the last chunk and returns the writer.
+	pub fn finish(mut self) -> io::Result<W> {
+		self.write_chunk(true)?;
+		self.writer.flush()?;
+		Ok(self.writer)
+	}
+
+	fn write_chunk(&mut self, last: bool) -> io::Result<()> {
+		debug_assert!(self.buf.len() <= self.chunk_len);
+
+		self.cyclist.absorb(&[last.into()]);
+		self.cyclist.encrypt(&mut self.buf);
+		self.buf.extend_from_slice(&self.cyclist.squeeze::<TAG_LEN>());
+		self.cyclist.ratchet();
+
+		self.writer.write_all(&self.buf)?;
+		self.buf.clear();
+		Ok(())
+	}
+}
+
+impl<W> Write for Encryptor<W>
+where
+	W: Write
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		// A full chunk is only written once more data arrives, as the last chunk may be full.
+		if self.buf.len() == self.chunk_len && !buf.is_empty() {
+			self.write_chunk(false)?;
+		}
+
+		let len = buf.len().min(self.chunk_len - self.buf.len());
+		self.buf.extend_from_slice(&buf[..len]);
+		Ok(len)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.writer.flush()
+	}
+}
+
+/// Decrypts and verifies a file read from `R`, yielding its plaintext chunk by chunk.
+#[derive(Debug)]
+pub struct Decryptor<R> {
+	reader: R,
+	cyclist: XoodyakKeyed<Lsbu32<12>>,
+	/// The chunk being read, followed by the first byte of the next one if there is one, or the
+	/// part of it read before an I/O error.
+	buf: Vec<u8>,
+	/// The verified plaintext of the current chunk not yet returned, as `buf[pos..end]`.
+	pos: usize,
+	end: usize,
+	chunk_len: usize,
+	done: bool,
+	/// Why the file failed to verify, after which every read fails the same way.
+	failed: Option<&'static str>
+}
+
+impl<R> Decryptor<R>
+where
+	R: Read
+{
+	/// Reads the header from `reader`.
+	pub fn new(mut reader: R, key: &[u8; KEY_LEN]) -> io::Result<Self> {
+		let mut header = [0; HEADER_LEN];
+		if read_full(&mut reader, &mut header)? < HEADER_LEN {
+			return Err(invalid_data("truncated file"));
+		}
+
+		let header = Header::from_bytes(&header)?;
+		let chunk_len = header.chunk_len as usize;
+
+		Ok(Self {
+			reader,
+			cyclist: header.cyclist(key),
+			buf: Vec::with_capacity(chunk_len + TAG_LEN + 1),
+			pos: 0,
+			end: 0,
+			chunk_len,
+			done: false,
+			failed: None
+		})
+	}
+
+	/// Reads and verifies the next chunk into `buf`.
+	fn read_chunk(&mut self) -> io::Result<()> {
+		// Drop the previous chunk and its tag, keeping the byte read ahead of it. After an I/O
+		// error `end` is already 0 and `buf` holds what was read of this chunk, so a retry
+		// resumes where the failed read stopped.
+		if self.end != 0 {
+			let _ = self.buf.drain(..self.end + TAG_LEN);
+			self.pos = 0;
+			self.end = 0;
+		}
+
+		let want = self.chunk_len + TAG_LEN + 1;
+		while self.buf.len() < want {
+			let start = self.buf.len();
+			self.buf.resize(want, 0);
+			match self.reader.read(&mut self.buf[start..]) {
+				Ok(0) => {
+					self.buf.truncate(start);
+					break;
+				}
+				Ok(len) => self.buf.truncate(start + len),
+				Err(err) => {
+					self.buf.truncate(start);
+					if err.kind() != io::ErrorKind::Interrupted {
+						return Err(err);
+					}
+				}
+			}
+		}
+
+		// Only the last chunk may be followed by nothing.
+		let last = self.buf.len() < want;
+		let len = if last {
+			let Some(len) = self.buf.len().checked_sub(TAG_LEN) else {
+				return Err(self.fail("truncated file"));
+			};
+
+			len
+		} else {
+			self.chunk_len
+		};
+
+		self.cyclist.absorb(&[last.into()]);
+		self.cyclist.decrypt(&mut self.buf[..len]);
+		let tag = self.cyclist.squeeze::<TAG_LEN>();
+		self.cyclist.ratchet();
+
+		let diff =
+			tag.iter().zip(&self.buf[len..len + TAG_LEN]).fold(0, |acc, (a, b)| acc | (a ^ b));
+		if diff != 0 {
+			return Err(self.fail("authentication failed"));
+		}
+
+		self.pos = 0;
+		self.end = len;
+		self.done = last;
+		Ok(())
+	}
+
+	/// Discards the chunk being read and makes every later read fail with `msg`.
+	fn fail(&mut self, msg: &'static str) -> io::Error {
+		self.buf.clear();
+		self.failed = Some(msg);
+		invalid_data(msg)
+	}
+}
+
+impl<R> Read for Decryptor<R>
+where
+	R: Read
+{
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		if let Some(msg) = self.failed {
+			return Err(invalid_data(msg));
+		}
+
+		while self.pos == self.end {
+			if self.done || buf.is_empty() {
+				return Ok(0);
+			}
+
+			self.read_chunk()?;
+		}
+
+		let len = buf.len().min(self.end - self.pos);
+		buf[..len].copy_from_slice(&self.buf[self.pos..self.pos + len]);
+		self.pos += len;
+		Ok(len)
+	}
+}
+
+/// Encrypts all of `reader` into `writer`, returning the number of plaintext bytes.
+pub fn encrypt(
+	key: &[u8; KEY_LEN],
+	header: Header,
+	mut reader: impl Read,
+	writer: impl Write
+) -> io::Result<u64> {
+	let mut encryptor = Encryptor::new(writer, key, header)?;
+	let len = io::copy(&mut reader, &mut encryptor)?;
+	let _ = encryptor.finish()?;
+	Ok(len)
+}
+
+/// Decrypts all of `reader` into `writer`, returning the number of plaintext bytes.
+///
+/// On error, `writer` may already hold the verified plaintext of the chunks before the failing
+/// one.
+pub fn decrypt(key: &[u8; KEY_LEN], reader: impl Read, mut writer: impl Write) -> io::Result<u64> {
+	let mut decryptor = Decryptor::new(reader, key)?;
+	let len = io::copy(&mut decryptor, &mut writer)?;
+	writer.flush()?;
+	Ok(len)
+}
+
+/// Reads until `buf` is full or the end of `reader`, returning the number of bytes read.
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+	let mut read = 0;
+
+	while read < buf.len() {
+		match reader.read(&mut buf[read..]) {
+			Ok(0) => break,
+			Ok(len) => read += len,
+			Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+			Err(err) => return Err(err)
+		}
+	}
+
+	Ok(read)
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, msg)
+}
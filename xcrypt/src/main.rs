@@ -0,0 +1,189 @@
+use std::{
+	ffi::OsString,
+	fs,
+	io::{self, BufReader, BufWriter, Read, Write},
+	path::{Path, PathBuf},
+	process::ExitCode
+};
+
+use xcrypt::{Header, DEFAULT_CHUNK_LEN, KEY_LEN};
+
+const USAGE: &str = "\
+Usage: xcrypt keygen KEYFILE
+       xcrypt encrypt -k KEYFILE [-s CHUNK_LEN] [-o OUTPUT] [INPUT]
+       xcrypt decrypt -k KEYFILE [-o OUTPUT] [INPUT]
+
+Encrypt or decrypt INPUT, or standard input, with a 32-byte key read from KEYFILE, writing to
+OUTPUT or standard output. A decrypted OUTPUT file is only created once the whole input has been
+verified.
+
+  -k, --key KEYFILE       the key file, as created by keygen
+  -s, --chunk-len BYTES   the plaintext length of each chunk, 65536 by default
+  -o, --output OUTPUT     the output file instead of standard output
+  -h, --help              display this help and exit
+";
+
+#[derive(Debug, Default)]
+struct Args {
+	key: Option<PathBuf>,
+	chunk_len: Option<u32>,
+	output: Option<PathBuf>,
+	input: Option<PathBuf>
+}
+
+fn main() -> ExitCode {
+	match run(std::env::args_os().skip(1).collect()) {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(err) => {
+			eprintln!("xcrypt: {err}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+fn run(mut args: Vec<OsString>) -> Result<(), String> {
+	if args.is_empty() {
+		return Err(format!("missing command\n{USAGE}"));
+	}
+
+	let command = args.remove(0);
+	match command.to_str() {
+		Some("-h" | "--help") => {
+			print!("{USAGE}");
+			Ok(())
+		}
+		Some("keygen") => {
+			match &args[..] {
+				[path] => keygen(Path::new(path)),
+				_ => Err(format!("keygen takes exactly one file\n{USAGE}"))
+			}
+		}
+		Some("encrypt") => {
+			let args = parse(args)?;
+			let key = read_key(&args)?;
+			let chunk_len = args.chunk_len.unwrap_or(DEFAULT_CHUNK_LEN);
+			let header = Header::new(chunk_len, random()?);
+
+			with_io(&args, |reader, writer| xcrypt::encrypt(&key, header, reader, writer))
+		}
+		Some("decrypt") => {
+			let args = parse(args)?;
+			let key = read_key(&args)?;
+
+			if args.chunk_len.is_some() {
+				return Err("the chunk length is read from the encrypted file".into());
+			}
+
+			with_io(&args, |reader, writer| xcrypt::decrypt(&key, reader, writer))
+		}
+		_ => Err(format!("unknown command: {}\n{USAGE}", command.to_string_lossy()))
+	}
+}
+
+fn parse(args: Vec<OsString>) -> Result<Args, String> {
+	let mut parsed = Args::default();
+	let mut args = args.into_iter();
+
+	while let Some(arg) = args.next() {
+		let mut value = |name: &str| args.next().ok_or_else(|| format!("{name} requires a value"));
+
+		match arg.to_str() {
+			Some("-k" | "--key") => parsed.key = Some(value("--key")?.into()),
+			Some("-o" | "--output") => parsed.output = Some(value("--output")?.into()),
+			Some("-s" | "--chunk-len") => {
+				let value = value("--chunk-len")?;
+				let chunk_len = value
+					.to_str()
+					.and_then(|s| s.parse().ok())
+					.filter(|&len| len != 0 && len <= xcrypt::MAX_CHUNK_LEN)
+					.ok_or_else(|| format!("invalid chunk length: {}", value.to_string_lossy()))?;
+				parsed.chunk_len = Some(chunk_len);
+			}
+			Some("-") => parsed.input = None,
+			Some(s) if s.starts_with('-') => return Err(format!("unknown option: {s}")),
+			_ if parsed.input.is_some() => return Err("only one input file is allowed".into()),
+			_ => parsed.input = Some(arg.into())
+		}
+	}
+
+	Ok(parsed)
+}
+
+fn read_key(args: &Args) -> Result<[u8; KEY_LEN], String> {
+	let path = args.key.as_ref().ok_or("missing --key")?;
+	let key = fs::read(path).map_err(|err| format!("{}: {err}", path.display()))?;
+
+	key.try_into().map_err(|_| format!("{}: key file must hold {KEY_LEN} bytes", path.display()))
+}
+
+fn keygen(path: &Path) -> Result<(), String> {
+	let key: [u8; KEY_LEN] = random()?;
+
+	let mut options = fs::OpenOptions::new();
+	let _ = options.write(true).create_new(true);
+	#[cfg(unix)]
+	let _ = std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+	options
+		.open(path)
+		.and_then(|mut file| file.write_all(&key))
+		.map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Runs `f` on the input and output of `args`. An output file is written under a temporary name
+/// and only renamed into place if `f` succeeds.
+fn with_io<F>(args: &Args, f: F) -> Result<(), String>
+where
+	F: FnOnce(&mut dyn Read, &mut dyn Write) -> io::Result<u64>
+{
+	let name = args.input.as_deref().unwrap_or(Path::new("-"));
+	let mut reader: Box<dyn Read> = match &args.input {
+		None => Box::new(io::stdin().lock()),
+		Some(path) => {
+			Box::new(BufReader::new(
+				fs::File::open(path).map_err(|err| format!("{}: {err}", path.display()))?
+			))
+		}
+	};
+
+	let Some(output) = &args.output else {
+		let mut stdout = io::stdout().lock();
+		return f(&mut reader, &mut stdout)
+			.map(drop)
+			.map_err(|err| format!("{}: {err}", name.display()));
+	};
+
+	let mut tmp = output.clone().into_os_string();
+	tmp.push(".partial");
+	let tmp = PathBuf::from(tmp);
+
+	let file = fs::OpenOptions::new()
+		.write(true)
+		.create_new(true)
+		.open(&tmp)
+		.map_err(|err| format!("{}: {err}", tmp.display()))?;
+
+	let mut writer = BufWriter::new(file);
+	let result = f(&mut reader, &mut writer)
+		.and_then(|_| writer.into_inner().map_err(io::IntoInnerError::into_error))
+		.and_then(|file| file.sync_all())
+		.map_err(|err| format!("{}: {err}", name.display()))
+		.and_then(|()| {
+			fs::rename(&tmp, output).map_err(|err| format!("{}: {err}", output.display()))
+		});
+
+	if result.is_err() {
+		let _ = fs::remove_file(&tmp);
+	}
+
+	result
+}
+
+/// Reads random bytes from the operating system.
+fn random<const LEN: usize>() -> Result<[u8; LEN], String> {
+	let mut buf = [0; LEN];
+	fs::File::open("/dev/urandom")
+		.and_then(|mut file| file.read_exact(&mut buf))
+		.map_err(|err| format!("failed to read random bytes: {err}"))?;
+	Ok(buf)
+}
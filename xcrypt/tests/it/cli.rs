@@ -0,0 +1,137 @@
+use std::{
+	fs,
+	io::Write,
+	path::{Path, PathBuf},
+	process::{Command, Output, Stdio},
+	sync::atomic::{AtomicUsize, Ordering}
+};
+
+const XCRYPT: &str = env!("CARGO_BIN_EXE_xcrypt");
+
+/// A directory under the system temporary directory, removed on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+	fn new() -> Self {
+		static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+		let name =
+			format!("xcrypt-{}-{}", std::process::id(), COUNT.fetch_add(1, Ordering::Relaxed));
+		let path = std::env::temp_dir().join(name);
+		fs::create_dir_all(&path).unwrap();
+		Self(path)
+	}
+
+	fn path(&self) -> &Path {
+		&self.0
+	}
+}
+
+impl Drop for TempDir {
+	fn drop(&mut self) {
+		let _ = fs::remove_dir_all(&self.0);
+	}
+}
+
+fn run(dir: &Path, args: &[&str], stdin: &[u8]) -> Output {
+	let mut child = Command::new(XCRYPT)
+		.args(args)
+		.current_dir(dir)
+		.stdin(Stdio::piped())
+		.stdout(Stdio::piped())
+		.stderr(Stdio::piped())
+		.spawn()
+		.unwrap();
+
+	child.stdin.take().unwrap().write_all(stdin).unwrap();
+	child.wait_with_output().unwrap()
+}
+
+fn setup() -> TempDir {
+	let dir = TempDir::new();
+	assert!(run(dir.path(), &["keygen", "key"], b"").status.success());
+	dir
+}
+
+#[test]
+fn keygen() {
+	let dir = setup();
+	assert_eq!(fs::read(dir.path().join("key")).unwrap().len(), 32);
+
+	#[cfg(unix)]
+	{
+		use std::os::unix::fs::PermissionsExt;
+		let mode = fs::metadata(dir.path().join("key")).unwrap().permissions().mode();
+		assert_eq!(mode & 0o777, 0o600);
+	}
+
+	// An existing key is never overwritten.
+	assert!(!run(dir.path(), &["keygen", "key"], b"").status.success());
+}
+
+#[test]
+fn files() {
+	let dir = setup();
+	let msg: Vec<u8> = (0..200_000).map(|i| (i % 251) as u8).collect();
+	fs::write(dir.path().join("plain"), &msg).unwrap();
+
+	let output =
+		run(dir.path(), &["encrypt", "-k", "key", "-s", "4096", "-o", "sealed", "plain"], b"");
+	assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+	let output = run(dir.path(), &["decrypt", "--key", "key", "--output", "opened", "sealed"], b"");
+	assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+	assert_eq!(fs::read(dir.path().join("opened")).unwrap(), msg);
+
+	// Encrypting twice uses fresh nonces.
+	let _ = run(dir.path(), &["encrypt", "-k", "key", "-o", "sealed2", "plain"], b"");
+	assert_ne!(
+		fs::read(dir.path().join("sealed")).unwrap(),
+		fs::read(dir.path().join("sealed2")).unwrap()
+	);
+}
+
+#[test]
+fn pipes() {
+	let dir = setup();
+
+	let sealed = run(dir.path(), &["encrypt", "-k", "key"], b"through a pipe");
+	assert!(sealed.status.success());
+
+	let opened = run(dir.path(), &["decrypt", "-k", "key", "-"], &sealed.stdout);
+	assert!(opened.status.success());
+	assert_eq!(opened.stdout, b"through a pipe");
+}
+
+#[test]
+fn tampered() {
+	let dir = setup();
+	fs::write(dir.path().join("plain"), vec![0; 10_000]).unwrap();
+	let _ = run(dir.path(), &["encrypt", "-k", "key", "-s", "1024", "-o", "sealed", "plain"], b"");
+
+	let mut sealed = fs::read(dir.path().join("sealed")).unwrap();
+	let last = sealed.len() - 1;
+	sealed[last] ^= 1;
+	fs::write(dir.path().join("sealed"), &sealed).unwrap();
+
+	let output = run(dir.path(), &["decrypt", "-k", "key", "-o", "opened", "sealed"], b"");
+	assert!(!output.status.success());
+	assert_eq!(String::from_utf8_lossy(&output.stderr), "xcrypt: sealed: authentication failed\n");
+
+	// Nothing is left behind.
+	assert!(!dir.path().join("opened").exists());
+	assert!(!dir.path().join("opened.partial").exists());
+}
+
+#[test]
+fn bad_key() {
+	let dir = setup();
+	fs::write(dir.path().join("short"), [0; 16]).unwrap();
+
+	let output = run(dir.path(), &["encrypt", "-k", "short"], b"");
+	assert!(!output.status.success());
+	assert_eq!(
+		String::from_utf8_lossy(&output.stderr),
+		"xcrypt: short: key file must hold 32 bytes\n"
+	);
+}
@@ -0,0 +1,252 @@
+use std::io::{self, Read, Write};
+
+use xcrypt::{
+	decrypt, encrypt, Decryptor, Encryptor, Header, HEADER_LEN, KEY_LEN, NONCE_LEN, TAG_LEN
+};
+
+const KEY: [u8; KEY_LEN] = [0x42; KEY_LEN];
+const CHUNK_LEN: usize = 64;
+
+fn header() -> Header {
+	Header::new(CHUNK_LEN as u32, [7; NONCE_LEN])
+}
+
+fn message(len: usize) -> Vec<u8> {
+	(0..len).map(|i| (i * 31 + 3) as u8).collect()
+}
+
+fn seal(msg: &[u8]) -> Vec<u8> {
+	let mut out = Vec::new();
+	let _ = encrypt(&KEY, header(), msg, &mut out).unwrap();
+	out
+}
+
+fn open(buf: &[u8]) -> io::Result<Vec<u8>> {
+	let mut out = Vec::new();
+	let _ = decrypt(&KEY, buf, &mut out)?;
+	Ok(out)
+}
+
+fn assert_invalid(result: io::Result<Vec<u8>>, msg: &str) {
+	let err = result.unwrap_err();
+	assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	assert_eq!(err.to_string(), msg);
+}
+
+#[test]
+fn round_trip() {
+	for len in [0, 1, CHUNK_LEN - 1, CHUNK_LEN, CHUNK_LEN + 1, 3 * CHUNK_LEN, 3 * CHUNK_LEN + 5] {
+		let msg = message(len);
+		let sealed = seal(&msg);
+
+		let chunks = len.div_ceil(CHUNK_LEN).max(1);
+		assert_eq!(sealed.len(), HEADER_LEN + len + chunks * TAG_LEN, "len = {len}");
+		assert_eq!(open(&sealed).unwrap(), msg, "len = {len}");
+	}
+}
+
+#[test]
+fn streaming() {
+	let msg = message(1000);
+
+	let mut encryptor = Encryptor::new(Vec::new(), &KEY, header()).unwrap();
+	for piece in msg.chunks(37) {
+		encryptor.write_all(piece).unwrap();
+	}
+	let sealed = encryptor.finish().unwrap();
+	assert_eq!(sealed, seal(&msg));
+
+	let mut decryptor = Decryptor::new(&sealed[..], &KEY).unwrap();
+	let mut out = Vec::new();
+	let mut buf = [0; 23];
+	loop {
+		let len = decryptor.read(&mut buf).unwrap();
+		if len == 0 {
+			break;
+		}
+		out.extend_from_slice(&buf[..len]);
+	}
+	assert_eq!(out, msg);
+}
+
+/// Pins the format, so that existing files stay readable.
+#[test]
+fn known_answer() {
+	let key = core::array::from_fn(|i| i as u8);
+	let header = Header::new(4, core::array::from_fn(|i| 0xF0 | i as u8));
+
+	let mut out = Vec::new();
+	let _ = encrypt(&key, header, &b"hello"[..], &mut out).unwrap();
+	assert_eq!(
+		crate::hex::encode(&out[..HEADER_LEN]),
+		"5844594b0100000004f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff"
+	);
+	// "hell" with its tag, then "o" with its tag.
+	assert_eq!(
+		crate::hex::encode(&out[HEADER_LEN..]),
+		"ef794f421d5ec28b7b34526ba1f6350b5200d67eb8d9d8d4355ac73fe7a276f764ce75179b"
+	);
+}
+
+#[test]
+fn tampering() {
+	let msg = message(2 * CHUNK_LEN + 10);
+	let sealed = seal(&msg);
+
+	for i in 0..sealed.len() {
+		let mut tampered = sealed.clone();
+		tampered[i] ^= 0x01;
+		assert!(open(&tampered).is_err(), "byte {i}");
+	}
+}
+
+#[test]
+fn reordering() {
+	let msg = message(3 * CHUNK_LEN);
+	let sealed = seal(&msg);
+
+	let chunk = CHUNK_LEN + TAG_LEN;
+	let (header, chunks) = sealed.split_at(HEADER_LEN);
+	let reordered = [header, &chunks[chunk..2 * chunk], &chunks[..chunk], &chunks[2 * chunk..]];
+	assert_invalid(open(&reordered.concat()), "authentication failed");
+}
+
+#[test]
+fn truncation() {
+	let msg = message(3 * CHUNK_LEN + 5);
+	let sealed = seal(&msg);
+	let chunk = CHUNK_LEN + TAG_LEN;
+
+	// At a chunk boundary, where the new last chunk was not sealed as the last one.
+	assert_invalid(open(&sealed[..HEADER_LEN + 3 * chunk]), "authentication failed");
+	assert_invalid(open(&sealed[..HEADER_LEN + chunk]), "authentication failed");
+
+	// Within a chunk, and within a tag.
+	assert_invalid(open(&sealed[..sealed.len() - 1]), "authentication failed");
+	assert_invalid(open(&sealed[..HEADER_LEN + 10]), "truncated file");
+
+	// Without any chunk, or within the header.
+	assert_invalid(open(&sealed[..HEADER_LEN]), "truncated file");
+	assert_invalid(open(&sealed[..HEADER_LEN - 1]), "truncated file");
+}
+
+#[test]
+fn verified_prefix() {
+	let msg = message(3 * CHUNK_LEN);
+	let mut sealed = seal(&msg);
+	let last = sealed.len() - 1;
+	sealed[last] ^= 0x80;
+
+	// Only the chunks before the tampered one are released.
+	let mut out = Vec::new();
+	assert!(decrypt(&KEY, &sealed[..], &mut out).is_err());
+	assert_eq!(out, msg[..2 * CHUNK_LEN]);
+}
+
+#[test]
+fn read_after_failure() {
+	let msg = message(3 * CHUNK_LEN);
+	let sealed = seal(&msg);
+	let chunk = CHUNK_LEN + TAG_LEN;
+
+	let mut tampered = sealed.clone();
+	tampered[HEADER_LEN + 2 * chunk] ^= 0x01;
+
+	for (sealed, msg) in [
+		(&tampered[..], "authentication failed"),
+		(&sealed[..HEADER_LEN + 2 * chunk + 3], "truncated file")
+	] {
+		let mut decryptor = Decryptor::new(sealed, &KEY).unwrap();
+		let mut buf = [0; 3 * CHUNK_LEN];
+		assert_eq!(decryptor.read(&mut buf).unwrap(), CHUNK_LEN);
+		assert_eq!(decryptor.read(&mut buf).unwrap(), CHUNK_LEN);
+
+		// The failure is reported again rather than as the end of the file.
+		for _ in 0..3 {
+			assert_invalid(decryptor.read(&mut buf).map(|_| Vec::new()), msg);
+		}
+	}
+}
+
+/// Reads a few bytes at a time and fails once, on the `fail_at`th call.
+struct Flaky<'a> {
+	data: &'a [u8],
+	calls: usize,
+	fail_at: usize
+}
+
+impl Read for Flaky<'_> {
+	fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+		self.calls += 1;
+		if self.calls == self.fail_at {
+			return Err(io::Error::other("flaky"));
+		}
+
+		let len = buf.len().min(self.data.len()).min(7);
+		buf[..len].copy_from_slice(&self.data[..len]);
+		self.data = &self.data[len..];
+		Ok(len)
+	}
+}
+
+#[test]
+fn retry_after_io_error() {
+	let msg = message(3 * CHUNK_LEN + 5);
+	let sealed = seal(&msg);
+
+	for fail_at in 1..30 {
+		let reader = Flaky { data: &sealed[HEADER_LEN..], calls: 0, fail_at };
+		let mut decryptor = Decryptor::new((&sealed[..HEADER_LEN]).chain(reader), &KEY).unwrap();
+
+		let mut out = Vec::new();
+		let mut failures = 0;
+		loop {
+			let mut buf = [0; 50];
+			match decryptor.read(&mut buf) {
+				Ok(0) => break,
+				Ok(len) => out.extend_from_slice(&buf[..len]),
+				Err(err) => {
+					assert_eq!(err.to_string(), "flaky");
+					failures += 1;
+				}
+			}
+		}
+
+		assert_eq!(failures, 1, "fail_at = {fail_at}");
+		assert_eq!(out, msg, "fail_at = {fail_at}");
+	}
+}
+
+#[test]
+fn wrong_key() {
+	let sealed = seal(b"secret");
+
+	let mut out = Vec::new();
+	let err = decrypt(&[0x43; KEY_LEN], &sealed[..], &mut out).unwrap_err();
+	assert_eq!(err.to_string(), "authentication failed");
+	assert!(out.is_empty());
+}
+
+#[test]
+fn header_bytes() {
+	let bytes = header().to_bytes();
+	assert_eq!(Header::from_bytes(&bytes).unwrap(), header());
+
+	let mut bad = bytes;
+	bad[0] = b'x';
+	assert_eq!(Header::from_bytes(&bad).unwrap_err().to_string(), "not an encrypted file");
+
+	let mut bad = bytes;
+	bad[4] = 2;
+	assert_eq!(Header::from_bytes(&bad).unwrap_err().to_string(), "unsupported version");
+
+	let mut bad = bytes;
+	bad[5..9].copy_from_slice(&0_u32.to_be_bytes());
+	assert_eq!(Header::from_bytes(&bad).unwrap_err().to_string(), "invalid chunk length");
+}
+
+#[test]
+#[should_panic = "chunk length must be in 1..=MAX_CHUNK_LEN"]
+fn zero_chunk_len() {
+	let _ = Header::new(0, [0; NONCE_LEN]);
+}
@@ -0,0 +1,3 @@
+mod cli;
+mod format;
+mod hex;
@@ -0,0 +1,125 @@
+use sponge::{state::Lsbu32, Absorb, Permutation, Squeeze, State};
+
+use crate::{Xoodoo, XoodyakHasher};
+
+/// Xoodyak in hash mode with the fixed 32-byte digest of the NIST lightweight cryptography
+/// submission.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Xoodyak256<S>(XoodyakHasher<S>);
+
+impl Xoodyak256<Lsbu32<12>> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl<S> Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	pub fn absorb(&mut self, buf: &[u8]) {
+		self.0.absorb(buf);
+	}
+
+	#[track_caller]
+	pub fn squeeze_into(self, buf: &mut [u8]) {
+		assert!(buf.len() == 32);
+		self.0.squeeze_into(buf);
+	}
+
+	pub fn squeeze(self) -> [u8; 32] {
+		let mut buf = [0; 32];
+		self.squeeze_into(&mut buf);
+		buf
+	}
+}
+
+impl<S> Default for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn default() -> Self {
+		Self(XoodyakHasher::new())
+	}
+}
+
+impl<S> Absorb for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn absorb(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+impl<S> Squeeze for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn squeeze_into(self, buf: &mut [u8]) {
+		self.squeeze_into(buf);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::Update for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn update(&mut self, buf: &[u8]) {
+		self.absorb(buf);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::OutputSizeUser for Xoodyak256<S> {
+	type OutputSize = digest::consts::U32;
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::FixedOutput for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn finalize_into(self, out: &mut digest::Output<Self>) {
+		self.squeeze_into(out);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::FixedOutputReset for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+		core::mem::take(self).squeeze_into(out);
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::Reset for Xoodyak256<S>
+where
+	S: State,
+	Xoodoo: Permutation<S::Inner>
+{
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+}
+
+#[cfg(feature = "digest")]
+impl<S> digest::HashMarker for Xoodyak256<S> {}
+
+#[cfg(feature = "digest")]
+impl<S> digest::core_api::AlgorithmName for Xoodyak256<S> {
+	fn write_alg_name(f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str("Xoodyak")
+	}
+}
@@ -22,14 +22,19 @@
 	unused_results
 )]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod hash;
 mod xoodoo;
 
 use sponge::{
-	cyclist::{Hash, Keyed},
+	cyclist::{Hash, Hasher, Keyed},
 	Cyclist
 };
 
-pub use crate::xoodoo::*;
+pub use crate::{hash::*, xoodoo::*};
 
 pub type XoodyakHash<S> = Cyclist<S, Xoodoo, Hash<16>>;
+pub type XoodyakHasher<S> = Hasher<S, Xoodoo, 16>;
 pub type XoodyakKeyed<S> = Cyclist<S, Xoodoo, Keyed<44, 24, 16>>;
@@ -1,5 +1,11 @@
 use sponge::Permutation;
 
+pub use self::backend::Backend;
+
+mod backend;
+#[cfg(target_arch = "x86_64")]
+mod planes;
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Xoodoo;
 
@@ -13,7 +19,7 @@ impl Xoodoo {
 impl Permutation<[u32; 12]> for Xoodoo {
 	#[inline]
 	fn permute(state: &mut [u32; 12]) {
-		xoodoo(state, &Self::RCS);
+		Backend::current().xoodoo(state, &Self::RCS);
 	}
 }
 
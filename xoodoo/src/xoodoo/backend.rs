@@ -0,0 +1,127 @@
+//! Runtime selection of the Xoodoo implementation behind [`Xoodoo`](super::Xoodoo).
+//!
+//! The CPU is probed once, on the first permutation, and the fastest available backend is used
+//! from then on. With the `std` feature the `XOODOO_BACKEND` environment variable can name the
+//! backend to use instead, unless it is unknown or unsupported, and [`Backend::force`] overrides
+//! the choice at any time.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use super::xoodoo;
+
+#[cfg(target_arch = "x86_64")]
+#[allow(deprecated_in_future)]
+mod cpuid {
+	cpufeatures::new!(avx512, "avx512f", "avx512vl");
+
+	pub(super) fn has_avx512() -> bool {
+		avx512::get()
+	}
+}
+
+const UNSELECTED: u8 = u8::MAX;
+
+static SELECTED: AtomicU8 = AtomicU8::new(UNSELECTED);
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Backend {
+	/// The portable reference, [`xoodoo`].
+	Generic,
+	/// One plane per SSE2 register.
+	#[cfg(target_arch = "x86_64")]
+	Sse2,
+	/// One plane per register, rotating lanes with the AVX-512VL `vprold`.
+	#[cfg(target_arch = "x86_64")]
+	Avx512
+}
+
+impl Backend {
+	/// Every backend compiled for this target, whether the CPU supports it or not.
+	pub const ALL: &'static [Self] = &[
+		Self::Generic,
+		#[cfg(target_arch = "x86_64")]
+		Self::Sse2,
+		#[cfg(target_arch = "x86_64")]
+		Self::Avx512
+	];
+
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Generic => "generic",
+			#[cfg(target_arch = "x86_64")]
+			Self::Sse2 => "sse2",
+			#[cfg(target_arch = "x86_64")]
+			Self::Avx512 => "avx512"
+		}
+	}
+
+	pub fn from_name(name: &str) -> Option<Self> {
+		Self::ALL.iter().copied().find(|backend| backend.name() == name)
+	}
+
+	pub fn is_available(self) -> bool {
+		match self {
+			Self::Generic => true,
+			#[cfg(target_arch = "x86_64")]
+			Self::Sse2 => true,
+			#[cfg(target_arch = "x86_64")]
+			Self::Avx512 => cpuid::has_avx512()
+		}
+	}
+
+	/// The fastest backend the CPU supports.
+	pub fn best() -> Self {
+		#[cfg(target_arch = "x86_64")]
+		return if Self::Avx512.is_available() { Self::Avx512 } else { Self::Sse2 };
+
+		#[cfg(not(target_arch = "x86_64"))]
+		Self::Generic
+	}
+
+	/// The backend used by [`Xoodoo`](super::Xoodoo), selecting it first if needed.
+	#[inline]
+	pub fn current() -> Self {
+		match SELECTED.load(Ordering::Relaxed) {
+			UNSELECTED => Self::select(),
+			i => Self::ALL[usize::from(i)]
+		}
+	}
+
+	/// Makes [`Xoodoo`](super::Xoodoo) use this backend from now on.
+	#[track_caller]
+	pub fn force(self) {
+		assert!(self.is_available(), "the {} Xoodoo backend is not supported", self.name());
+		SELECTED.store(self as u8, Ordering::Relaxed);
+	}
+
+	/// Applies Xoodoo with the round constants `rcs` to `s` with this backend.
+	#[inline]
+	#[track_caller]
+	pub fn xoodoo(self, s: &mut [u32; 12], rcs: &[u32]) {
+		match self {
+			Self::Generic => xoodoo(s, rcs),
+			// SAFETY: SSE2 is part of the x86_64 baseline.
+			#[cfg(target_arch = "x86_64")]
+			Self::Sse2 => unsafe { super::planes::xoodoo_sse2(s, rcs) },
+			#[cfg(target_arch = "x86_64")]
+			Self::Avx512 => {
+				assert!(cpuid::has_avx512(), "the avx512 Xoodoo backend is not supported");
+				// SAFETY: AVX-512F and AVX-512VL support was just checked.
+				unsafe { super::planes::xoodoo_avx512(s, rcs) }
+			}
+		}
+	}
+
+	#[cold]
+	fn select() -> Self {
+		#[cfg(feature = "std")]
+		let requested = std::env::var("XOODOO_BACKEND").ok().and_then(|name| Self::from_name(&name));
+		#[cfg(not(feature = "std"))]
+		let requested = None;
+
+		let backend = requested.filter(|backend| backend.is_available()).unwrap_or_else(Self::best);
+		SELECTED.store(backend as u8, Ordering::Relaxed);
+		backend
+	}
+}
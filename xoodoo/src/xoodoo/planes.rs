@@ -0,0 +1,82 @@
+//! Xoodoo with each plane held in an SSE register.
+
+use core::arch::x86_64::*;
+
+macro_rules! xoodoo_planes {
+	($(#[$attr:meta])* $name:ident, $feature:literal, $rotate_left:ident) => {
+		$(#[$attr])*
+		#[target_feature(enable = $feature)]
+		pub(super) unsafe fn $name(s: &mut [u32; 12], rcs: &[u32]) {
+			let ptr = s.as_mut_ptr().cast::<__m128i>();
+
+			// SAFETY: `s` holds three 16-byte planes and unaligned loads have no alignment
+			// requirement.
+			let (mut a0, mut a1, mut a2) = unsafe {
+				(_mm_loadu_si128(ptr), _mm_loadu_si128(ptr.add(1)), _mm_loadu_si128(ptr.add(2)))
+			};
+
+			for &rc in rcs {
+				// theta
+				let p = _mm_shuffle_epi32::<0x93>(_mm_xor_si128(_mm_xor_si128(a0, a1), a2));
+				let e = _mm_xor_si128($rotate_left::<5, 27>(p), $rotate_left::<14, 18>(p));
+				a0 = _mm_xor_si128(a0, e);
+				a1 = _mm_xor_si128(a1, e);
+				a2 = _mm_xor_si128(a2, e);
+
+				// rho-west
+				a1 = _mm_shuffle_epi32::<0x93>(a1);
+				a2 = $rotate_left::<11, 21>(a2);
+
+				// iota
+				a0 = _mm_xor_si128(a0, _mm_cvtsi32_si128(rc as i32));
+
+				// chi
+				let b0 = _mm_andnot_si128(a1, a2);
+				let b1 = _mm_andnot_si128(a2, a0);
+				let b2 = _mm_andnot_si128(a0, a1);
+				a0 = _mm_xor_si128(a0, b0);
+				a1 = _mm_xor_si128(a1, b1);
+				a2 = _mm_xor_si128(a2, b2);
+
+				// rho-east
+				a1 = $rotate_left::<1, 31>(a1);
+				a2 = _mm_shuffle_epi32::<0x4E>($rotate_left::<8, 24>(a2));
+			}
+
+			// SAFETY: As above.
+			unsafe {
+				_mm_storeu_si128(ptr, a0);
+				_mm_storeu_si128(ptr.add(1), a1);
+				_mm_storeu_si128(ptr.add(2), a2);
+			}
+		}
+	};
+}
+
+/// Rotates each lane of `x` left by `N` bits, `M` being `32 - N`.
+#[inline(always)]
+fn rotate_left_sse2<const N: i32, const M: i32>(x: __m128i) -> __m128i {
+	// SAFETY: SSE2 is part of the x86_64 baseline.
+	unsafe { _mm_or_si128(_mm_slli_epi32::<N>(x), _mm_srli_epi32::<M>(x)) }
+}
+
+/// Rotates each lane of `x` left by `N` bits with a single `vprold`.
+#[inline]
+#[target_feature(enable = "avx512f,avx512vl")]
+fn rotate_left_avx512<const N: i32, const M: i32>(x: __m128i) -> __m128i {
+	_mm_rol_epi32::<N>(x)
+}
+
+xoodoo_planes!(
+	/// Applies Xoodoo with the round constants `rcs` to `s` using SSE2.
+	xoodoo_sse2,
+	"sse2",
+	rotate_left_sse2
+);
+
+xoodoo_planes!(
+	/// Applies Xoodoo with the round constants `rcs` to `s` using the AVX-512 lane rotations.
+	xoodoo_avx512,
+	"avx512f,avx512vl",
+	rotate_left_avx512
+);
@@ -0,0 +1,48 @@
+use sponge::state::Lsbu32;
+use xoodoo::{xoodoo, Backend, Xoodoo, XoodyakHash};
+
+#[test]
+fn backends() {
+	let mut x = 0x0123_4567_u32;
+
+	for &backend in Backend::ALL.iter().filter(|backend| backend.is_available()) {
+		assert_eq!(Backend::from_name(backend.name()), Some(backend));
+
+		for _ in 0..16 {
+			let mut state: [u32; 12] = core::array::from_fn(|_| {
+				x = x.wrapping_mul(747_796_405).wrapping_add(2_891_336_453);
+				x
+			});
+
+			for rounds in 1..=12 {
+				let rcs = &Xoodoo::RCS[12 - rounds..];
+
+				let mut expected = state;
+				xoodoo(&mut expected, rcs);
+				backend.xoodoo(&mut state, rcs);
+				assert_eq!(state, expected, "backend = {backend:?}, rounds = {rounds}");
+			}
+		}
+	}
+}
+
+#[test]
+fn force() {
+	assert!(Backend::best().is_available());
+	assert!(Backend::current().is_available());
+
+	let mut hash = XoodyakHash::<Lsbu32<12>>::new();
+	hash.absorb(b"abc");
+	let expected: [u8; 32] = hash.squeeze();
+
+	for &backend in Backend::ALL.iter().filter(|backend| backend.is_available()) {
+		backend.force();
+		assert_eq!(Backend::current(), backend);
+
+		let mut hash = XoodyakHash::<Lsbu32<12>>::new();
+		hash.absorb(b"abc");
+		assert_eq!(hash.squeeze::<32>(), expected, "backend = {backend:?}");
+	}
+
+	Backend::best().force();
+}
@@ -0,0 +1,82 @@
+//! Checks that Xoodyak works through the generic `digest` traits, agreeing with its own API.
+
+#![cfg(feature = "digest")]
+
+use digest::{Digest, DynDigest, ExtendableOutput, ExtendableOutputReset, Update, XofReader};
+use sponge::state::Lsbu32;
+use xoodoo::{Xoodyak256, XoodyakHash, XoodyakHasher};
+
+fn message() -> Vec<u8> {
+	(0..1000).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn xoodyak256() {
+	let msg = message();
+
+	let mut hash = XoodyakHash::<Lsbu32<12>>::new();
+	hash.absorb(&msg);
+	let expected: [u8; 32] = hash.squeeze();
+
+	let mut hash = Xoodyak256::new();
+	hash.absorb(&msg);
+	assert_eq!(hash.squeeze(), expected);
+
+	assert_eq!(Xoodyak256::<Lsbu32<12>>::digest(&msg)[..], expected);
+
+	let mut hash = Xoodyak256::<Lsbu32<12>>::new();
+	for chunk in msg.chunks(7) {
+		Digest::update(&mut hash, chunk);
+	}
+	assert_eq!(hash.finalize_reset()[..], expected);
+
+	Digest::update(&mut hash, &msg);
+	assert_eq!(hash.finalize()[..], expected);
+
+	let mut hash = Xoodyak256::<Lsbu32<12>>::new();
+	let dyn_hash: &mut dyn DynDigest = &mut hash;
+	assert_eq!(dyn_hash.output_size(), 32);
+
+	let mut out = [0; 32];
+	dyn_hash.update(&msg);
+	dyn_hash.finalize_into_reset(&mut out).unwrap();
+	assert_eq!(out, expected);
+
+	dyn_hash.update(b"discarded");
+	dyn_hash.reset();
+	dyn_hash.update(&msg);
+	dyn_hash.finalize_into_reset(&mut out).unwrap();
+	assert_eq!(out, expected);
+}
+
+#[test]
+fn xoodyak_hasher() {
+	for len in [0, 1, 15, 16, 17, 32, 1000] {
+		let msg = &message()[..len];
+
+		let mut hash = XoodyakHash::<Lsbu32<12>>::new();
+		hash.absorb(msg);
+		let mut expected = [0; 100];
+		hash.squeeze_into(&mut expected);
+
+		let mut out = [0; 100];
+		let mut hash = XoodyakHasher::<Lsbu32<12>>::new();
+		for chunk in msg.chunks(7) {
+			hash.update(chunk);
+		}
+		let mut reader = hash.finalize_xof();
+		for chunk in out.chunks_mut(7) {
+			reader.read(chunk);
+		}
+		assert_eq!(out, expected, "len = {len}");
+
+		let mut hash = XoodyakHasher::<Lsbu32<12>>::new();
+		hash.update(msg);
+		hash.finalize_xof_reset_into(&mut out);
+		assert_eq!(out, expected, "len = {len}");
+
+		hash.update(msg);
+		hash.finalize_xof_reset_into(&mut out);
+		assert_eq!(out, expected, "len = {len}");
+	}
+}
@@ -1,3 +1,5 @@
+mod backend;
+mod conformance;
 mod hex;
 mod kat;
 mod xoodyak;
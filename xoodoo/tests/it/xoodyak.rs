@@ -70,7 +70,7 @@ fn gen_data(len: usize, seed1: u8, seed2: u8) -> Vec<u8> {
 
 	(0..len)
 		.map(|i| {
-			let rolled = ((i as u8) << seed2) | ((i as u8) >> (8 - seed2));
+			let rolled = (i as u8).rotate_left(seed2.into());
 
 			seed1
 				.wrapping_add(161_u8.wrapping_mul(len as u8))